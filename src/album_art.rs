@@ -2,9 +2,12 @@ use anyhow::{Context, Result};
 use image::DynamicImage;
 use lru::LruCache;
 use ratatui_image::{picker::Picker, protocol::StatefulProtocol};
+use std::future::Future;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
+use crate::service::CoverArt;
+
 /// Get album cover URL from Tidal cover ID
 /// Size can be: 80, 160, 320, 640, 1280
 fn get_tidal_cover_url(cover_id: &str, size: u32) -> String {
@@ -12,6 +15,13 @@ fn get_tidal_cover_url(cover_id: &str, size: u32) -> String {
     format!("https://resources.tidal.com/images/{}/{}x{}.jpg", path, size, size)
 }
 
+/// Art loaded by [`AlbumArtCache::fetch`], ready to be added with
+/// [`AlbumArtCache::insert_fetched`]
+pub struct FetchedArt {
+    cache_key: String,
+    image: DynamicImage,
+}
+
 /// Handles downloading and caching album art
 pub struct AlbumArtCache {
     cache_dir: PathBuf,
@@ -26,9 +36,8 @@ pub struct AlbumArtCache {
 impl AlbumArtCache {
     /// Create a new album art cache with specified capacity
     pub fn new(capacity: usize) -> Result<Self> {
-        let cache_dir = dirs::cache_dir()
+        let cache_dir = crate::config::drift_cache_dir()
             .context("Failed to get cache directory")?
-            .join("drift")
             .join("album-art");
 
         // Create cache directory if it doesn't exist
@@ -105,53 +114,48 @@ impl AlbumArtCache {
         Ok(self.images.get(&cache_key).expect("just inserted"))
     }
 
-    /// Download and cache album art from a URL (for YouTube/Bandcamp)
-    pub async fn get_album_art_from_url(&mut self, url: &str, size: u32) -> Result<&DynamicImage> {
-        // Create a sanitized cache key from the URL
-        let url_hash = Self::hash_url(url);
-        let cache_key = format!("url_{}_{}", url_hash, size);
-
-        // Check if already in memory
-        if self.images.contains(&cache_key) {
-            return Ok(self.images.get(&cache_key).expect("key exists after contains check"));
-        }
-
-        let cache_path = self.cache_dir.join(format!("{}.jpg", cache_key));
-
-        // Check if file exists on disk
-        let image = if cache_path.exists() {
-            image::open(&cache_path)
-                .context("Failed to load cached album art")?
-        } else {
-            // Download from URL
-            let response = reqwest::get(url)
-                .await
-                .context("Failed to download album art from URL")?;
-
-            let bytes = response.bytes()
-                .await
-                .context("Failed to read album art bytes")?;
-
-            // Save to disk cache
-            std::fs::write(&cache_path, &bytes)
-                .context("Failed to write album art to cache")?;
-
-            // Load and optionally resize the image
-            let mut img = image::load_from_memory(&bytes)
-                .context("Failed to decode album art")?;
-
-            // Resize if needed (YouTube thumbnails can be large)
-            if img.width() > size || img.height() > size {
-                img = img.thumbnail(size, size);
+    /// Load `cover` at `size` from disk or the network without borrowing the
+    /// cache, so it can run on its own task and be aborted. None for
+    /// [`CoverArt::None`].
+    pub fn fetch(&self, cover: &CoverArt, size: u32) -> Option<impl Future<Output = Result<FetchedArt>> + Send + 'static> {
+        let (cache_key, cache_path, url) = match cover {
+            CoverArt::ServiceId { id, .. } => (
+                format!("{}_{}", id, size),
+                self.get_cache_path(id, size),
+                get_tidal_cover_url(id, size),
+            ),
+            CoverArt::Url(url) => {
+                let cache_key = format!("url_{}_{}", Self::hash_url(url), size);
+                let cache_path = self.cache_dir.join(format!("{}.jpg", cache_key));
+                (cache_key, cache_path, url.clone())
             }
-
-            img
+            CoverArt::None => return None,
         };
 
-        // Store in memory cache (LRU will evict oldest if at capacity)
-        self.images.put(cache_key.clone(), image);
+        Some(async move {
+            let mut image = if cache_path.exists() {
+                image::open(&cache_path).context("Failed to load cached album art")?
+            } else {
+                let bytes = reqwest::get(&url)
+                    .await
+                    .context("Failed to download album art")?
+                    .bytes()
+                    .await
+                    .context("Failed to read album art bytes")?;
+                std::fs::write(&cache_path, &bytes).context("Failed to write album art to cache")?;
+                image::load_from_memory(&bytes).context("Failed to decode album art")?
+            };
+            // URL art (YouTube thumbnails) can be large
+            if image.width() > size || image.height() > size {
+                image = image.thumbnail(size, size);
+            }
+            Ok(FetchedArt { cache_key, image })
+        })
+    }
 
-        Ok(self.images.get(&cache_key).expect("just inserted"))
+    /// Add art loaded by [`AlbumArtCache::fetch`] to the in-memory cache
+    pub fn insert_fetched(&mut self, art: FetchedArt) {
+        self.images.put(art.cache_key, art.image);
     }
 
     /// Simple hash function for URLs to create safe filenames
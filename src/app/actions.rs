@@ -0,0 +1,737 @@
+use super::App;
+use super::state::{DialogMode, RadioSeed, SelectedItem, ViewMode};
+use crate::mpd::RepeatMode;
+use crate::search::subsequence_score;
+use crate::ui::library::LibraryTab;
+
+/// Seconds moved by the short seeks (Left/Right, ,/.) and the long ones
+/// (Shift+Left/Right, </>)
+const SEEK_SHORT_SECS: i64 = 5;
+const SEEK_LONG_SECS: i64 = 30;
+
+/// Volume points +/- move by
+const VOLUME_STEP: i16 = 5;
+
+/// Something a key or the command palette can do. Both go through
+/// [`App::run_action`], so a palette entry behaves exactly like its key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionKind {
+    /// Handled by the caller, which owns the event loop
+    Quit,
+    MoveLeft,
+    MoveDown,
+    MoveUp,
+    MoveRight,
+    JumpTop,
+    JumpEnd,
+    Activate,
+    Back,
+    NextTab,
+    PreviousTab,
+    AltTab,
+    Filter,
+    CommandPalette,
+    SeekForward,
+    SeekBackward,
+    SeekForwardLong,
+    SeekBackwardLong,
+    TogglePlayback,
+    NextTrack,
+    PreviousTrack,
+    RestartTrack,
+    PlayRandom,
+    PlaySelected,
+    AddToQueue,
+    AddAllToQueue,
+    QueuePrevious,
+    RemoveSelected,
+    MoveQueueDown,
+    MoveQueueUp,
+    ClearQueue,
+    ToggleQueue,
+    Sort,
+    CycleRepeat,
+    ToggleShuffle,
+    StopAfterTrack,
+    VolumeUp,
+    VolumeDown,
+    ToggleCrossfade,
+    SleepTimer,
+    ToggleRadio,
+    MyStation,
+    RefreshFavorites,
+    RetryDownload,
+    LoveTrack,
+    Favorite,
+    FavoriteAlbum,
+    ViewDetail,
+    TogglePreview,
+    CreatePlaylist,
+    RenamePlaylist,
+    DuplicatePlaylist,
+    DeletePlaylist,
+    AddToPlaylist,
+    RemoveFromSourcePlaylist,
+    Download,
+    DownloadAll,
+    DeleteDownload,
+    Search,
+    Browse,
+    Library,
+    Downloads,
+    ToggleOffline,
+    ToggleVideo,
+    ToggleVisualizer,
+    ToggleDebug,
+    FocusDebug,
+    ClearDebugLog,
+    ExportDebugLog,
+    CopyQueue,
+    CopyStreamUrl,
+    ReloadConfig,
+    ReloadPlaylists,
+    Help,
+}
+
+/// An action's names and default keys
+pub struct ActionEntry {
+    /// Name in `[keybindings]`
+    pub id: &'static str,
+    /// Name in the command palette
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Default keys, in `[keybindings]` syntax
+    pub keys: &'static [&'static str],
+    pub action: ActionKind,
+    /// Listed in the command palette; movement and other keys that only
+    /// make sense pressed aren't
+    pub palette: bool,
+    /// The only view the keys work in, where they win over other actions'
+    /// (None = everywhere)
+    pub view: Option<ViewMode>,
+}
+
+const fn entry(id: &'static str, name: &'static str, description: &'static str, keys: &'static [&'static str], action: ActionKind) -> ActionEntry {
+    ActionEntry { id, name, description, keys, action, palette: true, view: None }
+}
+
+const fn key_only(id: &'static str, description: &'static str, keys: &'static [&'static str], action: ActionKind) -> ActionEntry {
+    ActionEntry { id, name: id, description, keys, action, palette: false, view: None }
+}
+
+const fn in_view(view: ViewMode, id: &'static str, name: &'static str, description: &'static str, keys: &'static [&'static str], action: ActionKind) -> ActionEntry {
+    ActionEntry { id, name, description, keys, action, palette: true, view: Some(view) }
+}
+
+/// Every action, in the order the command palette (`:`) lists them before
+/// anything is typed
+pub const ACTIONS: &[ActionEntry] = &[
+    key_only("move_left", "Move left / previous panel", &["h"], ActionKind::MoveLeft),
+    key_only("move_down", "Move down", &["j"], ActionKind::MoveDown),
+    key_only("move_up", "Move up", &["k"], ActionKind::MoveUp),
+    key_only("move_right", "Move right / next panel", &["l"], ActionKind::MoveRight),
+    key_only("jump_top", "Jump to the top of the list", &["g g"], ActionKind::JumpTop),
+    key_only("jump_end", "Jump to the end of the list", &["g e"], ActionKind::JumpEnd),
+    key_only("activate", "Load playlist / play track / queue album", &["enter"], ActionKind::Activate),
+    key_only("back", "Cancel search, clear the filter or go back", &["esc"], ActionKind::Back),
+    key_only("next_tab", "Next panel or tab", &["tab"], ActionKind::NextTab),
+    key_only("previous_tab", "Previous panel or tab", &["shift+tab"], ActionKind::PreviousTab),
+    key_only("alt_tab", "In search, cycle what Tab doesn't (tabs or service filter)", &["`"], ActionKind::AltTab),
+    key_only("filter", "Filter search results or the track list", &["ctrl+f"], ActionKind::Filter),
+    key_only("command_palette", "Open the command palette", &[":"], ActionKind::CommandPalette),
+    key_only("seek_forward", "Seek forward 5s", &["right", ".", "]"], ActionKind::SeekForward),
+    key_only("seek_backward", "Seek back 5s", &["left", ",", "["], ActionKind::SeekBackward),
+    key_only("seek_forward_long", "Seek forward 30s", &["shift+right", ">"], ActionKind::SeekForwardLong),
+    key_only("seek_backward_long", "Seek back 30s", &["shift+left", "<"], ActionKind::SeekBackwardLong),
+    entry("toggle_playback", "Play / pause", "Toggle playback of the current track", &["space p"], ActionKind::TogglePlayback),
+    entry("next_track", "Next track", "Skip to the next track in the queue", &["space n"], ActionKind::NextTrack),
+    entry("previous_track", "Previous track", "Go back a track, or restart this one past the threshold", &["space b"], ActionKind::PreviousTrack),
+    entry("restart_track", "Replay track", "Restart the playing track from the beginning", &["space a"], ActionKind::RestartTrack),
+    entry("play_random", "Surprise me", "Play a random track from the list", &["space s"], ActionKind::PlayRandom),
+    entry("play_selected", "Play selection", "Play the selected track, album or artist", &["p"], ActionKind::PlaySelected),
+    entry("add_to_queue", "Add to queue", "Queue the selected track, album or artist", &["y"], ActionKind::AddToQueue),
+    entry("add_all_to_queue", "Add all to queue", "Queue the whole list, album or discography", &["Y"], ActionKind::AddAllToQueue),
+    entry("queue_previous", "Play previous again", "Queue the previously played track to play next", &["u"], ActionKind::QueuePrevious),
+    entry("remove_from_queue", "Remove from queue", "Remove the selected track from the queue", &["d"], ActionKind::RemoveSelected),
+    entry("move_queue_down", "Move queue track down", "Move the selected queue track down", &["J"], ActionKind::MoveQueueDown),
+    entry("move_queue_up", "Move queue track up", "Move the selected queue track up", &["K"], ActionKind::MoveQueueUp),
+    entry("clear_queue", "Clear queue", "Remove every track from the queue", &["D"], ActionKind::ClearQueue),
+    entry("toggle_queue", "Toggle queue panel", "Show or hide the queue", &["w"], ActionKind::ToggleQueue),
+    entry("sort", "Sort", "Sort the queue or track list, or filter an artist's albums by type", &["t"], ActionKind::Sort),
+    entry("cycle_repeat", "Cycle repeat", "Repeat off, all, one", &["r"], ActionKind::CycleRepeat),
+    entry("toggle_shuffle", "Toggle shuffle", "Play the queue in random order", &["s"], ActionKind::ToggleShuffle),
+    entry("stop_after_track", "Stop after current track", "Stop once the playing track ends", &["1"], ActionKind::StopAfterTrack),
+    entry("volume_up", "Volume up", "Raise the volume 5%", &["+", "="], ActionKind::VolumeUp),
+    entry("volume_down", "Volume down", "Lower the volume 5%", &["-", "_"], ActionKind::VolumeDown),
+    entry("toggle_crossfade", "Toggle crossfade", "Fade between tracks (not across stream formats)", &["space X"], ActionKind::ToggleCrossfade),
+    entry("sleep_timer", "Sleep timer", "Pause playback after N minutes", &["space t"], ActionKind::SleepTimer),
+    entry("toggle_radio", "Toggle radio", "Endless radio from the selection or playing track", &["R"], ActionKind::ToggleRadio),
+    entry("my_station", "My station", "Radio mixed from your favorites", &["M"], ActionKind::MyStation),
+    entry("love_track", "Love playing track", "Add the playing track to favorites", &["space f"], ActionKind::LoveTrack),
+    entry("favorite", "Favorite selection", "Favorite the selection (in Library, unfavorite it)", &["f"], ActionKind::Favorite),
+    in_view(ViewMode::Library, "refresh_favorites", "Refresh favorites", "Fetch your favorites again", &["r"], ActionKind::RefreshFavorites),
+    entry("favorite_album", "Favorite album", "Favorite the album of the selected or playing track", &["F"], ActionKind::FavoriteAlbum),
+    entry("view_detail", "Open artist / album", "Open the selected artist or album", &["v"], ActionKind::ViewDetail),
+    entry("toggle_preview", "Toggle preview", "Show or hide the search preview (in Downloads, pause downloads)", &["P"], ActionKind::TogglePreview),
+    entry("create_playlist", "Create playlist", "Make a new empty playlist", &["C"], ActionKind::CreatePlaylist),
+    entry("rename_playlist", "Rename playlist", "Rename the selected playlist", &["e"], ActionKind::RenamePlaylist),
+    entry("duplicate_playlist", "Duplicate playlist", "Copy the selected playlist", &["c"], ActionKind::DuplicatePlaylist),
+    entry("delete_playlist", "Delete playlist / remove track", "Delete the selected playlist, or remove the selected track from it", &["X"], ActionKind::DeletePlaylist),
+    entry("add_to_playlist", "Add to playlist", "Add the selected track to a playlist", &["a"], ActionKind::AddToPlaylist),
+    entry("remove_from_source", "Remove from source playlist", "Remove the playing track from the playlist it was queued from", &["space x"], ActionKind::RemoveFromSourcePlaylist),
+    entry("download", "Download track", "Download the selected track", &["O"], ActionKind::Download),
+    entry("download_all", "Download all / sync", "Download the album, or sync the selected playlist", &["S"], ActionKind::DownloadAll),
+    in_view(ViewMode::Downloads, "retry_download", "Retry download", "Retry the selected failed download", &["R"], ActionKind::RetryDownload),
+    entry("delete_download", "Delete download", "Delete the selected download and its file", &["x"], ActionKind::DeleteDownload),
+    entry("search", "Search", "Search every enabled service", &["/"], ActionKind::Search),
+    entry("browse", "Browse playlists", "Go to your playlists", &["b"], ActionKind::Browse),
+    entry("library", "Library", "Go to your favorite tracks, albums and artists", &["L"], ActionKind::Library),
+    entry("downloads", "Downloads", "Go to the download queue", &["W"], ActionKind::Downloads),
+    entry("toggle_offline", "Toggle offline mode", "Only play downloaded tracks", &["o"], ActionKind::ToggleOffline),
+    entry("toggle_video", "Toggle video mode", "Play YouTube videos in mpv", &["V"], ActionKind::ToggleVideo),
+    entry("toggle_visualizer", "Toggle visualizer", "Show or hide the audio visualizer", &["space v"], ActionKind::ToggleVisualizer),
+    entry("toggle_debug", "Toggle debug log", "Show or hide the debug panel", &["space d"], ActionKind::ToggleDebug),
+    entry("focus_debug", "Focus debug log", "Move through the debug panel instead of the list", &["space D"], ActionKind::FocusDebug),
+    entry("clear_debug_log", "Clear debug log", "Empty the debug panel", &["space c"], ActionKind::ClearDebugLog),
+    entry("export_debug_log", "Export debug log", "Write the debug log to /tmp/drift-export.log", &["space e"], ActionKind::ExportDebugLog),
+    entry("copy_queue", "Copy queue", "Copy the queue to the clipboard as text", &["space y"], ActionKind::CopyQueue),
+    key_only("copy_stream_url", "Copy the playing track's stream URL (debug.enabled)", &["space U"], ActionKind::CopyStreamUrl),
+    entry("reload_config", "Reload config", "Re-read config.toml", &["space r"], ActionKind::ReloadConfig),
+    entry("reload_playlists", "Reload playlists", "Fetch your playlists again", &["space l"], ActionKind::ReloadPlaylists),
+    entry("help", "Help", "List every keybinding", &["?"], ActionKind::Help),
+    entry("quit", "Quit", "Save the queue and exit", &["space q"], ActionKind::Quit),
+];
+
+/// Palette entries matching `query` as a fuzzy subsequence of their name or
+/// description, best first; all of them, in order, for an empty query
+pub fn matching_actions(query: &str) -> Vec<&'static ActionEntry> {
+    let query = query.trim().to_lowercase();
+    let palette = ACTIONS.iter().filter(|entry| entry.palette);
+    if query.is_empty() {
+        return palette.collect();
+    }
+
+    let mut matches: Vec<(&ActionEntry, u32)> = palette
+        .filter_map(|entry| {
+            // A hit in the name outranks one that needs the description
+            let name = subsequence_score(&entry.name.to_lowercase(), &query).map(|score| score + 1000);
+            let description = subsequence_score(&entry.description.to_lowercase(), &query);
+            name.or(description).map(|score| (entry, score))
+        })
+        .collect();
+    matches.sort_by(|a, b| b.1.cmp(&a.1));
+    matches.into_iter().map(|(entry, _)| entry).collect()
+}
+
+impl App {
+    /// Open the command palette
+    pub fn open_command_palette(&mut self) {
+        self.dialog.mode = DialogMode::CommandPalette;
+        self.dialog.input_text.clear();
+        self.dialog.selected_index = 0;
+    }
+
+    /// The palette entry under the cursor for the current query
+    pub fn selected_palette_action(&self) -> Option<ActionKind> {
+        matching_actions(&self.dialog.input_text)
+            .get(self.dialog.selected_index)
+            .map(|entry| entry.action)
+    }
+
+    /// Move the palette selection by `delta`, wrapping around the matches
+    pub fn move_palette_selection(&mut self, delta: isize) {
+        let count = matching_actions(&self.dialog.input_text).len();
+        if count == 0 {
+            return;
+        }
+        let index = self.dialog.selected_index as isize + delta;
+        self.dialog.selected_index = index.rem_euclid(count as isize) as usize;
+    }
+
+    /// Do `action`, as its key would
+    pub async fn run_action(&mut self, action: ActionKind) {
+        match action {
+            ActionKind::Quit => {}
+            ActionKind::MoveLeft => self.move_left(),
+            ActionKind::MoveDown => self.move_down(),
+            ActionKind::MoveUp => self.move_up(),
+            ActionKind::MoveRight => self.move_right(),
+            ActionKind::JumpTop => self.jump_to_top(),
+            ActionKind::JumpEnd => self.jump_to_end(),
+            ActionKind::Activate => self.activate_selection().await,
+            ActionKind::Back => {
+                if self.cancel_search() {
+                    self.set_status_info("Search cancelled".to_string());
+                } else if self.view_mode == ViewMode::Browse && self.browse.is_filtered() {
+                    self.clear_track_filter();
+                } else if self.view_mode == ViewMode::ArtistDetail || self.view_mode == ViewMode::AlbumDetail {
+                    self.pop_view();
+                    self.add_debug("Back to previous view".to_string());
+                }
+            }
+            ActionKind::NextTab => self.cycle_tab(true),
+            ActionKind::PreviousTab => self.cycle_tab(false),
+            ActionKind::AltTab => {
+                if self.view_mode == ViewMode::Search {
+                    if self.tab_cycles_search_filter() {
+                        self.cycle_search_tab(true);
+                    } else {
+                        self.cycle_search_service_filter(true);
+                    }
+                }
+            }
+            ActionKind::Filter => {
+                if self.view_mode == ViewMode::Search {
+                    self.search.filter_active = !self.search.filter_active;
+                    if !self.search.filter_active {
+                        self.search.filter_query.clear();
+                    }
+                    self.add_debug(format!("Filter mode {}", if self.search.filter_active { "ON" } else { "OFF" }));
+                } else if self.view_mode == ViewMode::Browse && !self.tracks.is_empty() {
+                    self.browse.selected_tab = 1;
+                    self.browse.filter_active = true;
+                }
+            }
+            ActionKind::CommandPalette => self.open_command_palette(),
+            ActionKind::SeekForward => self.seek_by(SEEK_SHORT_SECS).await,
+            ActionKind::SeekBackward => self.seek_by(-SEEK_SHORT_SECS).await,
+            ActionKind::SeekForwardLong => self.seek_by(SEEK_LONG_SECS).await,
+            ActionKind::SeekBackwardLong => self.seek_by(-SEEK_LONG_SECS).await,
+            ActionKind::TogglePlayback => {
+                if let Err(e) = self.toggle_playback().await {
+                    self.set_status_error(format!("Error toggling playback: {}", e));
+                }
+            }
+            ActionKind::NextTrack => {
+                self.add_debug("Next track".to_string());
+                if let Err(e) = self.mpd_controller.next(&mut self.debug_log).await {
+                    self.set_status_error(format!("Next failed: {}", e));
+                }
+            }
+            ActionKind::PreviousTrack => {
+                if let Err(e) = self.previous_track().await {
+                    self.set_status_error(format!("Previous failed: {}", e));
+                }
+            }
+            ActionKind::RestartTrack => {
+                if let Err(e) = self.restart_current_track().await {
+                    self.set_status_error(format!("Restart failed: {}", e));
+                }
+            }
+            ActionKind::PlayRandom => {
+                if let Err(e) = self.play_random_track().await {
+                    self.set_status_error(format!("Random play failed: {}", e));
+                }
+            }
+            ActionKind::PlaySelected => {
+                if !self.play_selection().await {
+                    if let Err(e) = self.toggle_playback().await {
+                        self.set_status_error(format!("Error toggling playback: {}", e));
+                    }
+                }
+            }
+            ActionKind::AddToQueue => self.queue_selection().await,
+            ActionKind::AddAllToQueue => {
+                let result = match self.view_mode {
+                    ViewMode::AlbumDetail => self.add_album_detail_tracks_to_queue().await,
+                    ViewMode::ArtistDetail => self.add_artist_discography_to_queue().await,
+                    _ => self.add_all_tracks_to_queue().await,
+                };
+                match result {
+                    Ok(()) => self.playback.queue_dirty = true,
+                    Err(e) if self.view_mode == ViewMode::ArtistDetail => {
+                        self.set_status_error(format!("Failed to add discography: {}", e));
+                    }
+                    Err(e) => self.set_status_error(format!("Failed to add tracks: {}", e)),
+                }
+            }
+            ActionKind::QueuePrevious => {
+                if let Err(e) = self.queue_previously_played().await {
+                    self.set_status_error(format!("Failed to re-queue track: {}", e));
+                }
+            }
+            ActionKind::RemoveSelected => self.remove_selected_from_queue().await,
+            ActionKind::MoveQueueDown => self.move_selected_in_queue(false).await,
+            ActionKind::MoveQueueUp => self.move_selected_in_queue(true).await,
+            ActionKind::ClearQueue => {
+                if let Err(e) = self.mpd_controller.clear_queue(&mut self.debug_log).await {
+                    self.set_status_error(format!("Failed to clear queue: {}", e));
+                } else {
+                    self.queue.clear();
+                    self.local_queue.clear();
+                    self.add_debug("Queue cleared".to_string());
+                    self.playback.queue_dirty = true;
+                    self.refresh_queue_after_edit().await;
+                }
+            }
+            ActionKind::ToggleQueue => {
+                self.playback.show_queue = !self.playback.show_queue;
+                if self.playback.show_queue {
+                    match self.mpd_controller.get_queue().await {
+                        Ok(queue) => {
+                            self.queue = queue;
+                            self.playback.queue_refreshed_at = Some(std::time::Instant::now());
+                            self.add_debug(format!("Queue loaded: {} tracks", self.queue.len()));
+                        }
+                        Err(e) => {
+                            self.set_status_error(format!("Failed to load queue: {}", e));
+                        }
+                    }
+                }
+                self.add_debug(format!("Queue {}", if self.playback.show_queue { "shown" } else { "hidden" }));
+            }
+            ActionKind::Sort => {
+                if self.playback.show_queue {
+                    if let Err(e) = self.sort_queue().await {
+                        self.set_status_error(format!("Failed to sort queue: {}", e));
+                    }
+                } else if self.view_mode == ViewMode::Browse && self.browse.selected_tab == 1 {
+                    self.cycle_track_sort();
+                } else if self.view_mode == ViewMode::ArtistDetail {
+                    self.cycle_album_filter();
+                }
+            }
+            ActionKind::CycleRepeat => self.set_repeat_mode(self.playback.repeat_mode.cycle()).await,
+            ActionKind::ToggleShuffle => self.toggle_shuffle().await,
+            ActionKind::StopAfterTrack => {
+                let mode = if self.playback.repeat_mode == RepeatMode::StopAfterTrack {
+                    RepeatMode::Off
+                } else {
+                    RepeatMode::StopAfterTrack
+                };
+                self.set_repeat_mode(mode).await;
+            }
+            ActionKind::VolumeUp => self.change_volume(VOLUME_STEP).await,
+            ActionKind::VolumeDown => self.change_volume(-VOLUME_STEP).await,
+            ActionKind::ToggleCrossfade => self.toggle_crossfade().await,
+            ActionKind::SleepTimer => self.open_sleep_timer_dialog(),
+            ActionKind::ToggleRadio => self.toggle_radio(),
+            ActionKind::RefreshFavorites => {
+                self.library.loaded = false;
+                self.add_debug("Refreshing favorites...".to_string());
+            }
+            ActionKind::RetryDownload => self.retry_selected_download(),
+            ActionKind::MyStation => {
+                if matches!(self.playback.radio_seed, Some(RadioSeed::Favorites)) {
+                    self.playback.radio_seed = None;
+                    self.add_debug("My Station OFF".to_string());
+                } else {
+                    self.start_favorites_station().await;
+                }
+            }
+            ActionKind::LoveTrack => self.love_current_track().await,
+            ActionKind::Favorite => self.toggle_favorite_selection().await,
+            ActionKind::FavoriteAlbum => {
+                if let Some(track) = self.get_selected_track().or_else(|| self.current_track.clone()) {
+                    self.add_favorite_album_of(track).await;
+                } else {
+                    self.add_debug("No track selected to favorite its album".to_string());
+                }
+            }
+            ActionKind::ViewDetail => self.open_selected_detail().await,
+            ActionKind::TogglePreview => {
+                if self.view_mode == ViewMode::Search {
+                    self.search.show_preview = !self.search.show_preview;
+                    self.add_debug(format!("Preview panel {}", if self.search.show_preview { "ON" } else { "OFF" }));
+                } else if self.view_mode == ViewMode::Downloads {
+                    self.toggle_download_pause();
+                }
+            }
+            ActionKind::CreatePlaylist => self.open_create_playlist_dialog(),
+            ActionKind::RenamePlaylist => {
+                if let Some(playlist) = self.selected_browse_playlist() {
+                    if playlist.id.starts_with("demo-") {
+                        self.add_debug("Cannot rename demo playlists".to_string());
+                    } else {
+                        self.open_rename_playlist_dialog(&playlist);
+                    }
+                }
+            }
+            ActionKind::DuplicatePlaylist => {
+                if self.view_mode == ViewMode::Browse && self.browse.selected_tab == 0 {
+                    self.duplicate_selected_playlist().await;
+                }
+            }
+            ActionKind::DeletePlaylist => {
+                if self.view_mode == ViewMode::Browse && self.browse.selected_tab == 1 {
+                    // Remove track from current playlist
+                    self.remove_track_from_current_playlist().await;
+                } else if let Some(playlist) = self.selected_browse_playlist() {
+                    if playlist.id.starts_with("demo-") {
+                        self.add_debug("Cannot delete demo playlists".to_string());
+                    } else {
+                        self.open_delete_playlist_dialog(&playlist);
+                    }
+                }
+            }
+            ActionKind::AddToPlaylist => {
+                if let Some(track) = self.get_selected_track() {
+                    self.open_add_to_playlist_dialog(&track);
+                } else {
+                    self.add_debug("No track selected to add to playlist".to_string());
+                }
+            }
+            ActionKind::RemoveFromSourcePlaylist => self.remove_current_from_source_playlist().await,
+            ActionKind::Download => self.download_selected_track(),
+            ActionKind::DownloadAll => {
+                if self.view_mode == ViewMode::AlbumDetail {
+                    self.open_download_all_dialog();
+                } else {
+                    self.open_sync_playlist_dialog();
+                }
+            }
+            ActionKind::DeleteDownload => {
+                if self.view_mode == ViewMode::Downloads {
+                    self.delete_selected_download();
+                }
+            }
+            ActionKind::Search => {
+                self.open_search();
+                self.show_trending_if_empty().await;
+            }
+            ActionKind::Browse => {
+                self.view_mode = ViewMode::Browse;
+                self.add_debug("Browse mode activated".to_string());
+            }
+            ActionKind::Library => {
+                self.view_mode = ViewMode::Library;
+                if !self.library.loaded {
+                    self.add_debug("Loading favorites...".to_string());
+                }
+                self.add_debug("Library view".to_string());
+            }
+            ActionKind::Downloads => {
+                self.view_mode = ViewMode::Downloads;
+                self.refresh_download_list();
+                self.add_debug("Downloads view".to_string());
+            }
+            ActionKind::ToggleOffline => {
+                self.downloads.offline_mode = !self.downloads.offline_mode;
+                self.add_debug(format!("Offline mode: {}", if self.downloads.offline_mode { "ON" } else { "OFF" }));
+            }
+            ActionKind::ToggleVideo => {
+                if self.video_controller.is_some() {
+                    self.playback.video_mode = !self.playback.video_mode;
+                    let status = if self.playback.video_mode { "ON" } else { "OFF" };
+                    self.add_debug(format!("Video mode: {}", status));
+                    self.set_status_info(format!("Video mode: {}", status));
+                } else {
+                    self.set_status_error("mpv not available for video mode".to_string());
+                }
+            }
+            ActionKind::ToggleVisualizer => {
+                self.show_visualizer = !self.show_visualizer;
+                self.add_debug(format!("Visualizer {}", if self.show_visualizer { "enabled" } else { "disabled" }));
+            }
+            ActionKind::ToggleDebug => {
+                self.show_debug = !self.show_debug;
+                if !self.show_debug {
+                    self.debug_panel.focused = false;
+                }
+                self.add_debug(format!("Debug log {}", if self.show_debug { "shown" } else { "hidden" }));
+            }
+            ActionKind::FocusDebug => {
+                // Focusing also opens the panel so j/k have something to scroll
+                self.debug_panel.focused = !self.debug_panel.focused;
+                if self.debug_panel.focused {
+                    self.show_debug = true;
+                } else {
+                    self.debug_panel.scroll_offset = 0;
+                }
+            }
+            ActionKind::ClearDebugLog => {
+                self.debug_log.clear();
+                self.debug_panel.scroll_offset = 0;
+                self.add_debug("Debug log cleared".to_string());
+            }
+            ActionKind::ExportDebugLog => {
+                let export_path = "/tmp/drift-export.log";
+                let mut content = String::new();
+                for line in &self.debug_log {
+                    content.push_str(line);
+                    content.push('\n');
+                }
+                if let Err(e) = std::fs::write(export_path, content) {
+                    self.set_status_error(format!("Failed to export log: {}", e));
+                } else {
+                    self.add_debug(format!("Debug log exported to {}", export_path));
+                }
+            }
+            ActionKind::CopyQueue => self.copy_queue_to_clipboard(),
+            ActionKind::CopyStreamUrl => {
+                if self.config.debug.enabled {
+                    self.copy_stream_url().await;
+                }
+            }
+            ActionKind::ReloadConfig => self.reload_config(),
+            ActionKind::ReloadPlaylists => self.reload_playlists().await,
+            ActionKind::Help => {
+                self.show_help = true;
+                self.help.scroll_offset = 0;
+            }
+        }
+    }
+
+    /// The playlist selected on the Browse playlists panel
+    fn selected_browse_playlist(&self) -> Option<crate::service::Playlist> {
+        if self.view_mode == ViewMode::Browse && self.browse.selected_tab == 0 {
+            self.playlists.get(self.browse.selected_playlist).cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Enter: load the selected playlist, play the selected track (on
+    /// through the album in album detail) or queue position, or queue the
+    /// selected album or artist
+    pub async fn activate_selection(&mut self) {
+        match self.get_selected_item() {
+            Some(SelectedItem::QueueItem(position, _)) => {
+                self.add_debug(format!("Playing from queue position {}", position + 1));
+                if let Err(e) = self.mpd_controller.play_position(position, &mut self.debug_log).await {
+                    self.set_status_error(format!("Failed to play from queue: {}", e));
+                }
+            }
+            Some(SelectedItem::Playlist(_)) => {
+                if let Err(e) = self.load_playlist(self.browse.selected_playlist).await {
+                    self.set_status_error(format!("Error loading playlist: {}", e));
+                }
+            }
+            Some(SelectedItem::Track(_)) if self.view_mode == ViewMode::AlbumDetail && self.config.playback.play_album_from_track => {
+                // Play the album from the selected track on
+                if let Err(e) = self.play_album_from(self.album_detail.selected_track).await {
+                    self.set_status_error(format!("Error playing album: {}", e));
+                }
+            }
+            Some(SelectedItem::Track(track)) => {
+                if let Err(e) = self.play_track(track).await {
+                    self.set_status_error(format!("Error playing track: {}", e));
+                }
+            }
+            Some(SelectedItem::Album(album)) => {
+                self.add_debug(format!("Adding album to queue: {}", album.title));
+                match self.add_album_by_id(&album.id).await {
+                    Ok(()) => self.playback.queue_dirty = true,
+                    Err(e) => self.set_status_error(format!("Error adding album: {}", e)),
+                }
+            }
+            Some(SelectedItem::Artist(_)) => match self.add_artist_to_queue().await {
+                Ok(()) => self.playback.queue_dirty = true,
+                Err(e) => self.set_status_error(format!("Error adding artist: {}", e)),
+            },
+            None => {}
+        }
+    }
+
+    /// Play, or queue and play, the selected queue item, track, album or
+    /// artist. Returns false when nothing playable is selected (an empty
+    /// list, a playlist rather than a track, a view with no tracks).
+    async fn play_selection(&mut self) -> bool {
+        match self.get_selected_item() {
+            Some(SelectedItem::Playlist(_)) | None => false,
+            // Just this track, even where Enter would play on from it
+            Some(SelectedItem::Track(track)) => {
+                if let Err(e) = self.play_track(track).await {
+                    self.set_status_error(format!("Error playing track: {}", e));
+                }
+                true
+            }
+            Some(_) => {
+                self.activate_selection().await;
+                true
+            }
+        }
+    }
+
+    /// Add the selected track, album or artist's top tracks to the queue
+    async fn queue_selection(&mut self) {
+        let (kind, result) = match self.get_selected_item() {
+            Some(SelectedItem::Track(track)) => ("track", self.add_track_to_queue(track).await),
+            Some(SelectedItem::Album(album)) => ("album", self.add_album_by_id(&album.id).await),
+            Some(SelectedItem::Artist(_)) => ("artist", self.add_artist_to_queue().await),
+            _ => return,
+        };
+
+        match result {
+            Ok(()) => self.playback.queue_dirty = true,
+            Err(e) => self.set_status_error(format!("Failed to add {}: {}", kind, e)),
+        }
+    }
+
+    /// Favorite the selected track, album or artist; in Library, unfavorite
+    /// the selected item instead
+    async fn toggle_favorite_selection(&mut self) {
+        let in_library = self.view_mode == ViewMode::Library
+            && !matches!(self.get_selected_item(), Some(SelectedItem::QueueItem(..)));
+        if in_library && self.library.tab == LibraryTab::Tracks {
+            // Remove from favorites
+            if self.library.selected_track < self.favorite_tracks.len() {
+                self.remove_favorite_track(self.library.selected_track).await;
+            }
+        } else if in_library && self.library.tab == LibraryTab::Albums {
+            self.remove_favorite_album(self.library.selected_album).await;
+        } else if in_library && self.library.tab == LibraryTab::Artists {
+            self.remove_favorite_artist(self.library.selected_artist).await;
+        } else if let Some(track) = self.get_selected_track() {
+            self.add_favorite_track(track).await;
+        } else if let Some(album) = self.get_selected_album() {
+            self.add_favorite_album(album).await;
+        } else if let Some(artist) = self.get_selected_artist() {
+            self.add_favorite_artist(artist).await;
+        } else {
+            self.add_debug("Nothing selected to favorite".to_string());
+        }
+    }
+
+    /// Turn radio off, or on seeded from the detail view's artist or album,
+    /// else the selected artist, album or playlist, else the playing track
+    fn toggle_radio(&mut self) {
+        if self.playback.radio_mode() {
+            self.playback.radio_seed = None;
+            self.add_debug("Radio mode OFF".to_string());
+            return;
+        }
+
+        if self.view_mode == ViewMode::ArtistDetail {
+            // Artist detail view - use the viewed artist
+            if let Some(ref artist) = self.artist_detail.artist {
+                self.playback.radio_seed = Some(RadioSeed::Artist(artist.id.clone()));
+                self.add_debug(format!("Artist Radio ON ({})", artist.name));
+            } else {
+                self.add_debug("No artist loaded for Radio".to_string());
+            }
+        } else if self.view_mode == ViewMode::AlbumDetail {
+            // Album detail view - use the viewed album
+            if let Some(ref album) = self.album_detail.album {
+                self.playback.radio_seed = Some(RadioSeed::Album(album.id.clone()));
+                self.add_debug(format!("Album Radio ON ({})", album.title));
+            } else {
+                self.add_debug("No album loaded for Radio".to_string());
+            }
+        } else {
+            match self.get_selected_item() {
+                Some(SelectedItem::Artist(artist)) => {
+                    self.playback.radio_seed = Some(RadioSeed::Artist(artist.id.clone()));
+                    self.add_debug(format!("Artist Radio ON ({})", artist.name));
+                }
+                Some(SelectedItem::Album(album)) => {
+                    self.playback.radio_seed = Some(RadioSeed::Album(album.id.clone()));
+                    self.add_debug(format!("Album Radio ON ({})", album.title));
+                }
+                Some(SelectedItem::Playlist(playlist)) => {
+                    self.playback.radio_seed = Some(RadioSeed::Playlist(playlist.id.clone()));
+                    self.add_debug(format!("Mix Radio ON (playlist: {})", playlist.title));
+                }
+                _ => {
+                    if let Some(ref track) = self.current_track {
+                        self.playback.radio_seed = Some(RadioSeed::Track(track.id.clone()));
+                        self.add_debug(format!("Radio ON (seed: {})", track.title));
+                    } else {
+                        self.add_debug("No track playing for Radio seed".to_string());
+                    }
+                }
+            }
+        }
+    }
+}
+
@@ -1,77 +1,66 @@
 use super::App;
-use super::state::ViewMode;
+use super::state::{DialogMode, ViewMode};
 use crate::download_db::DownloadStatus;
-use crate::downloads::{DownloadEvent, sanitize_filename};
+use crate::downloads::{DownloadEvent, QueueOutcome, format_bytes, sanitize_filename};
 use crate::service::{MusicService, Track};
 use crate::ui::library::LibraryTab;
 use crate::ui::search::SearchTab;
 
 impl App {
     pub fn download_selected_track(&mut self) {
-        let track = match self.view_mode {
-            ViewMode::Browse => {
-                if self.browse.selected_tab == 1 && self.browse.selected_track < self.tracks.len() {
-                    Some(self.tracks[self.browse.selected_track].clone())
-                } else {
-                    None
-                }
-            }
-            ViewMode::Search => {
-                if let Some(ref results) = self.search_results {
-                    match self.search.tab {
-                        SearchTab::Tracks if self.search.selected_track < results.tracks.len() => {
-                            Some(results.tracks[self.search.selected_track].clone())
-                        }
-                        _ => None,
-                    }
-                } else {
-                    None
-                }
+        if let Some(track) = self.get_selected_track() {
+            if let Some(ref dm) = self.download_manager {
+                let result = dm.queue_track(&track);
+                self.handle_queue_outcome(result, vec![track]);
             }
-            ViewMode::Library => {
-                if self.library.tab == LibraryTab::Tracks && self.library.selected_track < self.favorite_tracks.len() {
-                    Some(self.favorite_tracks[self.library.selected_track].clone())
-                } else {
-                    None
+        }
+    }
+
+    /// Report how queueing `tracks` went, asking first if some are already
+    /// downloaded and `downloads.on_existing = "ask"`
+    fn handle_queue_outcome(&mut self, result: anyhow::Result<QueueOutcome>, tracks: Vec<Track>) {
+        match result {
+            Ok(QueueOutcome::Queued { queued, skipped }) => {
+                match (queued, tracks.as_slice()) {
+                    (1, [track]) => self.add_debug(format!("Queued download: {} - {}", track.artist, track.title)),
+                    _ => self.add_debug(format!("Queued {} tracks for download", queued)),
+                }
+                if skipped > 0 {
+                    self.set_status_info(format!(
+                        "Skipped {} already downloaded track{}",
+                        skipped,
+                        if skipped == 1 { "" } else { "s" }
+                    ));
                 }
+                self.refresh_download_list();
             }
-            ViewMode::AlbumDetail => {
-                if self.album_detail.selected_track < self.album_detail.tracks.len() {
-                    Some(self.album_detail.tracks[self.album_detail.selected_track].clone())
-                } else {
-                    None
-                }
+            Ok(QueueOutcome::NeedsConfirmation { existing }) => {
+                self.dialog.mode = DialogMode::ConfirmOverwriteDownloads {
+                    track_count: tracks.len(),
+                    existing,
+                };
+                self.dialog.pending_downloads = tracks;
             }
-            ViewMode::ArtistDetail => {
-                if self.artist_detail.selected_panel == 0
-                    && self.artist_detail.selected_track < self.artist_detail.top_tracks.len()
-                {
-                    Some(self.artist_detail.top_tracks[self.artist_detail.selected_track].clone())
-                } else {
-                    None
-                }
+            Err(e) => {
+                self.set_status_error(format!("Failed to queue downloads: {}", e));
             }
-            ViewMode::Downloads => None,
-        };
+        }
+    }
 
-        if let Some(track) = track {
-            if let Some(ref dm) = self.download_manager {
-                match dm.queue_track(&track) {
-                    Ok(_) => {
-                        self.add_debug(format!("Queued download: {} - {}", track.artist, track.title));
-                        self.refresh_download_list();
-                    }
-                    Err(e) => {
-                        self.add_debug(format!("Failed to queue download: {}", e));
-                    }
-                }
-            }
+    /// Answer the ConfirmOverwriteDownloads dialog: re-download the tracks
+    /// already downloaded, or queue only the rest
+    pub fn resolve_overwrite_dialog(&mut self, overwrite: bool) {
+        let tracks = std::mem::take(&mut self.dialog.pending_downloads);
+        self.close_dialog();
+        if let Some(ref dm) = self.download_manager {
+            let result = dm.queue_tracks_with(&tracks, overwrite);
+            self.handle_queue_outcome(result, tracks);
         }
     }
 
-    #[allow(dead_code)]
-    pub fn download_all_tracks(&mut self) {
-        let tracks: Vec<Track> = match self.view_mode {
+    /// Tracks that a "download all" in the current view would queue.
+    fn download_all_candidates(&self) -> Vec<Track> {
+        match self.view_mode {
             ViewMode::Browse => self.tracks.clone(),
             ViewMode::Search => {
                 if let Some(ref results) = self.search_results {
@@ -99,19 +88,49 @@ impl App {
                 }
             }
             ViewMode::Downloads => Vec::new(),
+        }
+    }
+
+    /// Open a confirmation dialog showing the estimated size of downloading
+    /// every track in the current view.
+    pub fn open_download_all_dialog(&mut self) {
+        let tracks = self.download_all_candidates();
+        if tracks.is_empty() {
+            self.add_debug("No tracks to download in this view".to_string());
+            return;
+        }
+
+        let estimated_bytes = match self.download_manager {
+            Some(ref dm) => dm.estimate_size(&tracks),
+            None => return,
+        };
+
+        let title = match self.view_mode {
+            ViewMode::AlbumDetail => self.album_detail.album.as_ref()
+                .map(|a| a.title.clone())
+                .unwrap_or_else(|| "Album".to_string()),
+            _ => "Current list".to_string(),
+        };
+
+        self.dialog.mode = DialogMode::ConfirmDownloadAll {
+            title,
+            track_count: tracks.len(),
+            estimated_bytes,
         };
+        self.add_debug(format!(
+            "Download all dialog: {} tracks (~{})",
+            tracks.len(),
+            format_bytes(estimated_bytes)
+        ));
+    }
+
+    pub fn download_all_tracks(&mut self) {
+        let tracks = self.download_all_candidates();
 
         if !tracks.is_empty() {
             if let Some(ref dm) = self.download_manager {
-                match dm.queue_tracks(&tracks) {
-                    Ok(count) => {
-                        self.add_debug(format!("Queued {} tracks for download", count));
-                        self.refresh_download_list();
-                    }
-                    Err(e) => {
-                        self.add_debug(format!("Failed to queue downloads: {}", e));
-                    }
-                }
+                let result = dm.queue_tracks(&tracks);
+                self.handle_queue_outcome(result, tracks);
             }
         }
     }
@@ -161,7 +180,7 @@ impl App {
         }
 
         let record = &self.download_records[self.downloads.selected];
-        if record.status != DownloadStatus::Failed {
+        if !matches!(record.status, DownloadStatus::Failed | DownloadStatus::Corrupt) {
             return;
         }
 
@@ -195,6 +214,48 @@ impl App {
         }
     }
 
+    /// Open a confirmation dialog showing how many new tracks a sync of the
+    /// selected playlist would download and roughly how much disk they need.
+    pub fn open_sync_playlist_dialog(&mut self) {
+        if self.view_mode != ViewMode::Browse || self.browse.selected_tab != 0 {
+            self.add_debug("Select a playlist to sync (browse mode, playlists tab)".to_string());
+            return;
+        }
+
+        if self.playlists.is_empty() || self.browse.selected_playlist >= self.playlists.len() {
+            return;
+        }
+
+        let playlist = &self.playlists[self.browse.selected_playlist];
+        let (track_count, estimated_bytes) = match self.download_manager {
+            Some(ref dm) => {
+                let new_tracks = dm
+                    .get_playlist_new_tracks(&playlist.id, &self.tracks)
+                    .unwrap_or_else(|_| self.tracks.clone());
+                let estimate = dm.estimate_size(&new_tracks);
+                (new_tracks.len(), estimate)
+            }
+            None => return,
+        };
+
+        // Nothing new to fetch — sync immediately to refresh metadata
+        if track_count == 0 {
+            self.sync_selected_playlist();
+            return;
+        }
+
+        self.dialog.mode = DialogMode::ConfirmSyncPlaylist {
+            playlist_title: playlist.title.clone(),
+            track_count,
+            estimated_bytes,
+        };
+        self.add_debug(format!(
+            "Sync dialog: {} new tracks (~{})",
+            track_count,
+            format_bytes(estimated_bytes)
+        ));
+    }
+
     pub fn sync_selected_playlist(&mut self) {
         if self.view_mode != ViewMode::Browse || self.browse.selected_tab != 0 {
             self.add_debug("Select a playlist to sync (browse mode, playlists tab)".to_string());
@@ -225,7 +286,7 @@ impl App {
                     self.refresh_download_list();
                 }
                 Err(e) => {
-                    self.add_debug(format!("Failed to sync playlist: {}", e));
+                    self.set_status_error(format!("Failed to sync playlist: {}", e));
                 }
             }
         }
@@ -323,7 +384,7 @@ impl App {
 
         // Fall back to downloading from the music service
         if let Some(ref dm) = self.download_manager {
-            match dm.process_next_download(&mut self.music_service, &mut self.debug_log).await {
+            match dm.process_next_download(&self.music_service, &mut self.tasks, &mut self.debug_log) {
                 Ok(processed) => {
                     if processed {
                         self.refresh_download_list();
@@ -353,13 +414,22 @@ impl App {
                 DownloadEvent::Started { title, .. } => {
                     self.add_debug(format!("Started downloading: {}", title));
                 }
-                DownloadEvent::Completed { .. } => {
+                DownloadEvent::Completed { path, .. } => {
+                    self.add_debug(format!("Download complete: {}", path));
                     needs_refresh = true;
                 }
                 DownloadEvent::Failed { error, .. } => {
                     self.add_debug(format!("Download failed: {}", error));
                     needs_refresh = true;
                 }
+                DownloadEvent::Corrupt { title, error, .. } => {
+                    self.add_debug(format!("Download of '{}' is corrupt: {}", title, error));
+                    self.set_status_error(format!(
+                        "'{}' downloaded corrupt ({}); press R in Downloads to re-download",
+                        title, error
+                    ));
+                    needs_refresh = true;
+                }
                 DownloadEvent::QueueUpdated => {
                     needs_refresh = true;
                 }
@@ -1,4 +1,5 @@
 pub mod state;
+mod actions;
 mod playback;
 mod navigation;
 mod downloads;
@@ -6,8 +7,11 @@ mod queue;
 mod sync;
 
 use std::collections::VecDeque;
+use std::sync::Arc;
 
 use anyhow::Result;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use ratatui::layout::Rect;
 
 use crate::album_art::AlbumArtCache;
@@ -15,19 +19,25 @@ use crate::cava::CavaVisualizer;
 use crate::config::Config;
 use crate::download_db::DownloadRecord;
 use crate::history_db::HistoryEntry;
+use crate::keymap::Keymap;
+use crate::metadata::MusicBrainz;
 use crate::mpd::{CurrentSong, MpdController, QueueItem};
+use crate::playlist_view_state::{PlaylistViewState, PlaylistViewStore};
 use crate::queue_persistence::PersistedQueue;
+use crate::scrobble::Scrobbler;
 use crate::search::{ResultScorer, SearchHistory};
-use crate::service::{Album, Artist, CoverArt, MixedPlaylistStorage, MultiServiceManager, MusicService, Playlist, SearchResults, Track};
+use crate::service::{parse_service_url, Album, AlbumType, Artist, CoverArt, MixedPlaylistStorage, MultiServiceManager, MusicService, Playlist, ResourceKind, SearchResults, ServiceType, Track};
 use crate::storage::DriftStorage;
 use crate::storage::metadata_cache::MetadataCache;
 use crate::downloads::{DownloadEvent, DownloadManager};
+use crate::tasks::TaskManager;
 use crate::video::MpvController;
 
+pub use actions::{matching_actions, ActionKind, ACTIONS};
 pub use state::{
-    AlbumDetailState, ArtistDetailState, BrowseState, ClickableAreas, DialogMode, DialogState,
-    DownloadsState, HelpState, KeyState, LibraryState, PlaybackState, SearchState, StatusMessage,
-    ViewMode,
+    AlbumDetailState, ArtistDetailState, BrowseState, ClickableAreas, DebugPanelState, DialogMode,
+    DialogState, DownloadsState, HelpState, KeyState, LibraryState, PendingEnrichment, PendingSearch, PlaybackState, SearchState, SelectedItem,
+    StatusMessage, ViewMode,
 };
 
 pub struct App {
@@ -38,6 +48,9 @@ pub struct App {
     pub playlists: Vec<Playlist>,
     pub tracks: Vec<Track>,
     pub browse: BrowseState,
+    pub playlist_views: PlaylistViewStore,
+    /// Randomness for "surprise me" picks; seedable so picks are reproducible
+    pub rng: StdRng,
 
     // Search mode data
     pub search: SearchState,
@@ -59,10 +72,16 @@ pub struct App {
     pub debug_log: VecDeque<String>,
     pub visualizer: Option<CavaVisualizer>,
     pub show_visualizer: bool,
+    /// Full-screen visualizer shown after `ui.screensaver_timeout` idle
+    pub screensaver: bool,
+    /// Last key, mouse or paste event, for the screensaver timeout
+    pub last_input: std::time::Instant,
     pub album_art_cache: AlbumArtCache,
 
     // Helix-style key command state
     pub key_state: KeyState,
+    /// Keys → actions, from the defaults and `[keybindings]`
+    pub keymap: Keymap,
 
     // Queue persistence
     pub pending_restore: Option<PersistedQueue>,
@@ -79,6 +98,14 @@ pub struct App {
     // Blob uploads pending (track_id, file_path) — drained async in main loop
     pub pending_blob_uploads: Vec<(String, String)>,
 
+    // Background jobs shown in the status bar — polled in main loop
+    pub tasks: TaskManager,
+
+    // Listen submission (`[listenbrainz]`), None when not configured
+    pub scrobbler: Option<Arc<dyn Scrobbler>>,
+    // Track lookups (`[musicbrainz]`), None when disabled
+    pub musicbrainz: Option<Arc<MusicBrainz>>,
+
     // Library/Favorites
     pub library: LibraryState,
     pub favorite_tracks: Vec<Track>,
@@ -109,6 +136,7 @@ pub struct App {
 
     // Debug log visibility (hidden by default)
     pub show_debug: bool,
+    pub debug_panel: DebugPanelState,
 
     // Playlist dialogs
     pub dialog: DialogState,
@@ -129,13 +157,20 @@ impl App {
         debug_log.push_back("Starting Drift...".to_string());
 
         // Load configuration
-        let config = match Config::load() {
-            Ok(cfg) => {
+        let config = match Config::load_with_warnings() {
+            Ok((cfg, warnings)) => {
                 debug_log.push_back("Configuration loaded".to_string());
+                for warning in warnings {
+                    debug_log.push_back(format!("  Config warning: {}", warning));
+                }
                 debug_log.push_back(format!("  MPD: {}:{}", cfg.mpd.host, cfg.mpd.port));
                 debug_log.push_back(format!("  Audio quality: {}", cfg.playback.audio_quality));
                 cfg
             }
+            // A bad path is reported at startup rather than failing later
+            Err(e) if e.downcast_ref::<crate::config::InvalidPathError>().is_some() => {
+                return Err(e.context("Invalid config.toml"));
+            }
             Err(e) => {
                 debug_log.push_back(format!("Failed to load config: {}, using defaults", e));
                 Config::default()
@@ -167,11 +202,27 @@ impl App {
 
         // Initialize MPD controller with config
         debug_log.push_back("Connecting to MPD...".to_string());
-        let mpd_controller = MpdController::with_config(
+        let mut mpd_controller = MpdController::with_config(
             &config.mpd.host,
             config.mpd.port,
             &mut debug_log
         ).await?;
+        if let Some(ref mode) = config.playback.replay_gain {
+            if let Err(e) = mpd_controller.set_replay_gain(mode, &mut debug_log).await {
+                debug_log.push_back(format!("Failed to set replay gain: {}", e));
+            }
+        }
+        if config.playback.crossfade_seconds > 0 {
+            if let Err(e) = mpd_controller.set_crossfade(config.playback.crossfade_seconds, &mut debug_log).await {
+                debug_log.push_back(format!("Failed to set crossfade: {}", e));
+            }
+        }
+        if let Err(e) = mpd_controller
+            .set_mixramp(config.playback.mixramp_db, config.playback.mixramp_delay, &mut debug_log)
+            .await
+        {
+            debug_log.push_back(format!("Failed to set MixRamp: {}", e));
+        }
 
         // Initialize metadata cache (for offline access to playlists, favorites, etc.)
         let metadata_cache_ttl = std::time::Duration::from_secs(
@@ -220,7 +271,9 @@ impl App {
         };
 
         // Load tracks from first playlist — cache-first
-        let tracks = if !playlists.is_empty() {
+        let autoloaded_playlist = (!playlists.is_empty() && config.ui.autoload_first_playlist)
+            .then(|| playlists[0].id.clone());
+        let tracks = if autoloaded_playlist.is_some() {
             let pid = &playlists[0].id;
             match metadata_cache.get_playlist_tracks(pid) {
                 Ok(Some(hit)) => {
@@ -251,14 +304,29 @@ impl App {
             match CavaVisualizer::new() {
                 Ok(mut v) => {
                     debug_log.push_back("Visualizer initialized".to_string());
-                    match v.start() {
-                        Ok(_) => {
-                            debug_log.push_back("Cava process started".to_string());
-                            Some(v)
-                        }
-                        Err(e) => {
-                            debug_log.push_back(format!("Could not start cava: {}", e));
-                            None
+                    let httpd_started = config.ui.visualizer_source == "httpd"
+                        && match v.start_httpd(&config.ui.visualizer_httpd_url) {
+                            Ok(()) => {
+                                debug_log.push_back(format!("Visualizer reading {}", config.ui.visualizer_httpd_url));
+                                true
+                            }
+                            Err(e) => {
+                                debug_log.push_back(format!("Visualizer stream unavailable ({:#}), falling back to cava", e));
+                                false
+                            }
+                        };
+                    if httpd_started {
+                        Some(v)
+                    } else {
+                        match v.start() {
+                            Ok(_) => {
+                                debug_log.push_back("Cava process started".to_string());
+                                Some(v)
+                            }
+                            Err(e) => {
+                                debug_log.push_back(format!("Could not start cava: {}", e));
+                                None
+                            }
                         }
                     }
                 }
@@ -329,7 +397,8 @@ impl App {
         // Initialize download manager
         let (download_manager, download_event_rx, download_records, initial_download_counts, initial_synced_ids) =
             match DownloadManager::with_config(&config.downloads) {
-                Ok((dm, rx)) => {
+                Ok((mut dm, rx)) => {
+                    dm.set_audio_quality(&config.playback.audio_quality);
                     let records = dm.get_all_downloads().unwrap_or_default();
                     let counts = dm.get_download_counts().unwrap_or((0, 0, 0));
                     let mut synced_ids = std::collections::HashSet::new();
@@ -372,6 +441,26 @@ impl App {
             None
         };
 
+        let scrobbler = match crate::scrobble::from_config(&config.listenbrainz, &config.network) {
+            Ok(Some(scrobbler)) => {
+                debug_log.push_back(format!("Submitting listens to {}", scrobbler.name()));
+                Some(scrobbler)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                debug_log.push_back(format!("Could not set up listen submission: {}", e));
+                None
+            }
+        };
+
+        let musicbrainz = match crate::metadata::from_config(&config.musicbrainz, &config.network) {
+            Ok(client) => client.map(Arc::new),
+            Err(e) => {
+                debug_log.push_back(format!("Could not set up MusicBrainz lookups: {}", e));
+                None
+            }
+        };
+
         let default_volume = config.playback.default_volume;
         let show_visualizer = config.ui.show_visualizer;
 
@@ -389,16 +478,37 @@ impl App {
             }
         };
 
-        Ok(Self {
+        // Bring back the last query, with its results if still cached
+        let mut search = SearchState::new();
+        search.tab = crate::ui::SearchTab::from_config(&config.search.default_tab);
+        let mut search_results = None;
+        if config.search.restore_last {
+            if let Some(last) = search_history.entries.front() {
+                search.query = last.query.clone();
+                if config.search.cache_enabled {
+                    search_results = storage.get_cached_search(&last.query, None).await.ok().flatten();
+                }
+                debug_log.push_back(format!(
+                    "Restored last search '{}'{}",
+                    last.query,
+                    if search_results.is_some() { " (cached results)" } else { "" }
+                ));
+            }
+        }
+
+        let mut app = Self {
             view_mode: ViewMode::Browse,
             playlists,
             tracks,
             browse: BrowseState::default(),
-            search: SearchState::new(),
-            search_results: None,
+            playlist_views: PlaylistViewStore::load(),
+            rng: StdRng::from_entropy(),
+            search,
+            search_results,
             search_history,
             playback: PlaybackState {
                 volume: default_volume,
+                base_volume: default_volume,
                 ..Default::default()
             },
             current_track: None,
@@ -411,8 +521,11 @@ impl App {
             debug_log,
             visualizer,
             show_visualizer,
+            screensaver: false,
+            last_input: std::time::Instant::now(),
             album_art_cache,
             key_state: KeyState::default(),
+            keymap: Keymap::new(&config.keybindings),
             pending_restore,
             clickable_areas: ClickableAreas::default(),
             download_manager,
@@ -424,6 +537,9 @@ impl App {
                 ..DownloadsState::default()
             },
             pending_blob_uploads: Vec::new(),
+            tasks: TaskManager::new(),
+            scrobbler,
+            musicbrainz,
             library: LibraryState::default(),
             favorite_tracks: Vec::new(),
             favorite_albums: Vec::new(),
@@ -439,11 +555,18 @@ impl App {
             show_help: false,
             help: HelpState::default(),
             show_debug: false,
+            debug_panel: DebugPanelState::default(),
             dialog: DialogState::default(),
             status_message: None,
             video_controller,
             last_playlist_sync: std::time::Instant::now(),
-        })
+        };
+
+        // The first playlist's saved sort and selection, as load_playlist restores them
+        if let Some(playlist_id) = autoloaded_playlist {
+            app.restore_playlist_view(playlist_id);
+        }
+        Ok(app)
     }
 
     /// Resolve a track to a playable URL, preferring local files when available.
@@ -457,15 +580,16 @@ impl App {
     /// API calls and provides instant playback for downloaded tracks.
     pub(crate) async fn resolve_play_url(&mut self, track: &Track) -> Result<Option<String>> {
         // Check local files first (always, not just in offline mode)
-        if self.config.storage.prefer_local_files {
-            if let Some(ref dm) = self.download_manager {
-                if let Some(local_path) = dm.get_local_path(&track.id) {
-                    // Verify the file actually exists on disk
-                    if std::path::Path::new(&local_path).exists() {
-                        return Ok(Some(local_path));
-                    }
-                }
-            }
+        if let Some(local_path) = self.local_play_path(track) {
+            return Ok(Some(local_path));
+        }
+
+        // Don't enqueue a dead entry for a track the service won't stream
+        if !track.available && self.config.playback.skip_unavailable {
+            return Err(anyhow::anyhow!(
+                "'{}' isn't available for streaming (region-locked or removed)",
+                track.title
+            ));
         }
 
         // Fall back to streaming URL
@@ -475,7 +599,13 @@ impl App {
             Ok(None)
         } else {
             match self.music_service.get_stream_url(&track.id).await {
-                Ok(url) => Ok(Some(url)),
+                Ok(url) => {
+                    // Entries old enough to be refreshed anyway aren't worth keeping
+                    let resolved = &mut self.playback.stream_resolved_at;
+                    resolved.retain(|_, at| at.elapsed() < playback::STREAM_URL_MAX_AGE);
+                    resolved.insert(url.clone(), std::time::Instant::now());
+                    Ok(Some(url))
+                }
                 Err(e) => {
                     self.add_debug(format!("Failed to get URL for {}: {}", track.title, e));
                     Err(e)
@@ -484,6 +614,17 @@ impl App {
         }
     }
 
+    /// Downloaded copy of `track` to play instead of streaming, per
+    /// `storage.prefer_local_files`
+    pub(crate) fn local_play_path(&self, track: &Track) -> Option<String> {
+        if !self.config.storage.prefer_local_files {
+            return None;
+        }
+        let local_path = self.download_manager.as_ref()?.get_local_path(&track.id)?;
+        // Verify the file actually exists on disk
+        std::path::Path::new(&local_path).exists().then_some(local_path)
+    }
+
     pub fn add_debug(&mut self, msg: String) {
         if let Ok(mut file) = std::fs::OpenOptions::new()
             .create(true)
@@ -496,11 +637,28 @@ impl App {
         }
 
         self.debug_log.push_back(msg);
-        while self.debug_log.len() > 100 {
-            self.debug_log.pop_front();
+        self.trim_debug_log();
+    }
+
+    /// Drop the oldest debug messages beyond `debug.memory_lines`. The MPD,
+    /// mpv and download helpers push onto `debug_log` directly, so the main
+    /// loop also calls this each tick.
+    pub fn trim_debug_log(&mut self) {
+        let excess = self.debug_log.len().saturating_sub(self.config.debug.memory_lines.max(1));
+        if excess > 0 {
+            self.debug_log.drain(..excess);
+            self.scroll_debug(0);
         }
     }
 
+    /// Scroll the debug panel back (positive) or forward (negative) through
+    /// the log, keeping the last page on screen
+    pub fn scroll_debug(&mut self, delta: isize) {
+        let max_offset = self.debug_log.len().saturating_sub(self.config.ui.debug_lines);
+        let offset = self.debug_panel.scroll_offset as isize + delta;
+        self.debug_panel.scroll_offset = offset.clamp(0, max_offset as isize) as usize;
+    }
+
     pub fn set_status_error(&mut self, msg: String) {
         self.status_message = Some(StatusMessage {
             message: msg.clone(),
@@ -518,6 +676,19 @@ impl App {
         });
     }
 
+    /// Say so while a service or MusicBrainz is backing off after a 429
+    pub fn show_rate_limit(&mut self) {
+        let backoff = self
+            .music_service
+            .rate_limit_backoff()
+            .into_iter()
+            .chain(self.musicbrainz.as_ref().and_then(|mb| mb.rate_limit_backoff()))
+            .max();
+        if let Some(wait) = backoff {
+            self.set_status_info(format!("Rate limited, retrying in {}s", wait.as_secs_f64().ceil()));
+        }
+    }
+
     pub fn clear_expired_status(&mut self) {
         if let Some(ref msg) = self.status_message {
             if msg.timestamp.elapsed() > std::time::Duration::from_secs(5) {
@@ -529,29 +700,70 @@ impl App {
     pub fn check_config_reload(&mut self) {
         let current_mtime = Config::get_mtime();
         if current_mtime != self.config_mtime {
-            match Config::load() {
-                Ok(new_config) => {
-                    self.apply_config_changes(new_config);
-                    self.config_mtime = current_mtime;
+            self.config_mtime = current_mtime;
+            self.reload_config();
+        }
+    }
+
+    /// Re-read config.toml and apply whatever can change without a restart.
+    pub fn reload_config(&mut self) {
+        match Config::load_with_warnings() {
+            Ok((new_config, warnings)) => {
+                self.config_mtime = Config::get_mtime();
+                let warning_count = warnings.len();
+                for warning in warnings {
+                    self.add_debug(format!("Config warning: {}", warning));
                 }
-                Err(e) => {
-                    self.add_debug(format!("Config reload failed: {}", e));
+                self.apply_config_changes(new_config);
+                if warning_count > 0 {
+                    self.set_status_error(format!(
+                        "Config reloaded with {} warning(s), see debug log",
+                        warning_count
+                    ));
                 }
             }
+            Err(e) => {
+                self.set_status_error(format!("Config reload failed: {:#}", e));
+            }
         }
     }
 
     fn apply_config_changes(&mut self, new_config: Config) {
+        let needs_restart = self.config.restart_required_changes(&new_config);
+
         // UI settings that can be hot-reloaded
         self.show_visualizer = new_config.ui.show_visualizer;
+        self.keymap = Keymap::new(&new_config.keybindings);
+        if new_config.ui.mouse != self.config.ui.mouse {
+            set_mouse_capture(new_config.ui.mouse);
+        }
 
-        // Theme and search settings are applied automatically
-        // since they're read from self.config on each use
+        if new_config.playback.audio_quality != self.config.playback.audio_quality {
+            self.music_service.set_audio_quality(&new_config.playback.audio_quality);
+            if let Some(ref mut dm) = self.download_manager {
+                dm.set_audio_quality(&new_config.playback.audio_quality);
+            }
+        }
+        if let Some(ref mut dm) = self.download_manager {
+            dm.set_on_existing(&new_config.downloads.on_existing);
+            if new_config.downloads.max_bytes_per_sec != self.config.downloads.max_bytes_per_sec {
+                dm.set_max_bytes_per_sec(new_config.downloads.max_bytes_per_sec);
+            }
+        }
 
-        self.add_debug("Config reloaded".to_string());
-        self.set_status_info("Config reloaded".to_string());
+        // Theme, search and sync settings are applied automatically
+        // since they're read from self.config on each use
 
         self.config = new_config;
+
+        if needs_restart.is_empty() {
+            self.add_debug("Config reloaded".to_string());
+            self.set_status_info("Config reloaded".to_string());
+        } else {
+            let msg = format!("Config reloaded; restart to apply: {}", needs_restart.join(", "));
+            self.add_debug(msg.clone());
+            self.set_status_info(msg);
+        }
     }
 
     #[allow(dead_code)]
@@ -568,11 +780,32 @@ impl App {
         self.clickable_areas.progress_bar = progress;
     }
 
+    /// Re-fetch the playlist list from the service, bypassing the cache, and
+    /// keep the same playlist selected if it's still there
+    pub async fn reload_playlists(&mut self) {
+        let selected_id = self.playlists.get(self.browse.selected_playlist).map(|p| p.id.clone());
+        match self.music_service.get_playlists().await {
+            Ok(playlists) => {
+                if let Err(e) = self.metadata_cache.set_playlists(&playlists) {
+                    self.add_debug(format!("Failed to cache playlists: {}", e));
+                }
+                self.browse.selected_playlist = selected_id
+                    .and_then(|id| playlists.iter().position(|p| p.id == id))
+                    .unwrap_or(0)
+                    .min(playlists.len().saturating_sub(1));
+                self.set_status_info(format!("Reloaded {} playlists", playlists.len()));
+                self.playlists = playlists;
+            }
+            Err(e) => self.set_status_error(format!("Failed to reload playlists: {}", e)),
+        }
+    }
+
     pub async fn load_playlist(&mut self, index: usize) -> Result<()> {
         if index < self.playlists.len() {
             let playlist_title = self.playlists[index].title.clone();
             let playlist_id = self.playlists[index].id.clone();
             self.add_debug(format!("Loading playlist: {}", playlist_title));
+            self.remember_playlist_view();
 
             // Try cache first, then API
             self.tracks = match self.metadata_cache.get_playlist_tracks(&playlist_id) {
@@ -587,24 +820,111 @@ impl App {
                     t
                 }
             };
-            self.browse.selected_track = 0;
+
+            self.restore_playlist_view(playlist_id);
         }
         Ok(())
     }
 
+    /// Mark `self.tracks` as `playlist_id`'s and restore its sort order and
+    /// selection
+    fn restore_playlist_view(&mut self, playlist_id: String) {
+        self.clear_track_filter();
+        let view = self.playlist_views.get(&playlist_id);
+        self.browse.loaded_playlist = Some(playlist_id);
+        self.browse.track_positions = (0..self.tracks.len()).collect();
+        self.browse.sort = view.sort;
+        self.browse.sort.apply(&mut self.tracks, &mut self.browse.track_positions);
+        self.browse.selected_track = view.selected_track.min(self.tracks.len().saturating_sub(1));
+    }
+
+    /// Cycle the sort order of the loaded playlist, keeping the selected track selected
+    pub fn cycle_track_sort(&mut self) {
+        if self.browse.loaded_playlist.is_none() || self.tracks.is_empty() {
+            return;
+        }
+
+        let selected_position = self.browse.track_positions.get(self.browse.selected_track).copied();
+        self.browse.sort = self.browse.sort.next();
+        self.browse.sort.apply(&mut self.tracks, &mut self.browse.track_positions);
+        if let Some(pos) = selected_position {
+            if let Some(idx) = self.browse.track_positions.iter().position(|&p| p == pos) {
+                self.browse.selected_track = idx;
+            }
+        }
+
+        self.refilter_tracks();
+
+        self.set_status_info(format!("Sorted by {}", self.browse.sort.label()));
+        self.remember_playlist_view();
+    }
+
+    /// Re-run the track filter after its query changed, selecting the best match
+    pub fn track_filter_changed(&mut self) {
+        self.refilter_tracks();
+        if let Some(&best) = self.browse.filtered_indices.first() {
+            self.browse.selected_track = best;
+        }
+    }
+
+    /// Recompute `filtered_indices` for the loaded tracks, moving the
+    /// selection to the best match if the filter now hides it
+    fn refilter_tracks(&mut self) {
+        if !self.browse.is_filtered() {
+            self.browse.filtered_indices.clear();
+            return;
+        }
+        self.browse.filtered_indices = ResultScorer::filter_tracks(&self.tracks, &self.browse.filter_query);
+        if self.browse.selected_row().is_none() {
+            if let Some(&best) = self.browse.filtered_indices.first() {
+                self.browse.selected_track = best;
+            }
+        }
+    }
+
+    /// Drop the track filter, showing the whole list with the selection kept
+    pub fn clear_track_filter(&mut self) {
+        self.browse.filter_query.clear();
+        self.browse.filter_active = false;
+        self.browse.filtered_indices.clear();
+    }
+
+    /// Save the loaded playlist's sort order and selection
+    pub fn remember_playlist_view(&mut self) {
+        let Some(ref playlist_id) = self.browse.loaded_playlist else {
+            return;
+        };
+        let view = PlaylistViewState {
+            sort: self.browse.sort,
+            selected_track: self.browse.selected_track,
+        };
+        if self.playlist_views.set(playlist_id, view) {
+            if let Err(e) = self.playlist_views.save() {
+                self.add_debug(format!("Failed to save playlist view state: {}", e));
+            }
+        }
+    }
+
     pub async fn search(&mut self) -> Result<()> {
         if self.search.query.trim().is_empty() {
             self.add_debug("Search query is empty".to_string());
             return Ok(());
         }
 
+        if let Some((service, kind, id)) = parse_service_url(&self.search.query) {
+            return self.open_service_url(service, kind, id).await;
+        }
+
         let query = self.search.query.clone();
         let max_results = self.config.search.max_results;
         let page = self.search.page;
         let service_filter = self.search.service_filter;
 
         self.add_debug(format!("Searching for: {} (page {}, limit {})", query, page + 1, max_results));
-        self.search.is_active = true;
+
+        // A newer search replaces one still in flight, so stale results
+        // can't land after it
+        self.cancel_search();
 
         // Check cache first
         if self.config.search.cache_enabled {
@@ -622,20 +942,47 @@ impl App {
                     || artist_count >= max_results;
 
                 self.search_results = Some(cached_results);
+                self.search.showing_trending = false;
+                self.reset_search_tab();
                 self.search.selected_track = 0;
                 self.search.selected_album = 0;
                 self.search.selected_artist = 0;
 
                 self.search_history.add(&query, total_count);
                 let _ = self.storage.save_search_history(&self.search_history).await;
-
-                self.search.is_active = false;
                 return Ok(());
             }
         }
 
-        // Cache miss - call API
-        match self.music_service.search(&query, max_results).await {
+        // Cache miss - call the API on its own task so Esc can abort it
+        let mut service = self.music_service.clone();
+        let task_query = query.clone();
+        let task = tokio::spawn(async move { service.search(&task_query, max_results).await });
+        self.search.pending = Some(PendingSearch { query, service_filter, task });
+        Ok(())
+    }
+
+    /// Abort the search in flight, if any. Returns whether one was running.
+    pub fn cancel_search(&mut self) -> bool {
+        let Some(pending) = self.search.pending.take() else {
+            return false;
+        };
+        pending.task.abort();
+        self.add_debug(format!("Search cancelled: {}", pending.query));
+        true
+    }
+
+    /// Show the results of the search in flight once its task finishes
+    pub async fn poll_search(&mut self) {
+        if !self.search.pending.as_ref().is_some_and(|p| p.task.is_finished()) {
+            return;
+        }
+        let Some(PendingSearch { query, service_filter, task }) = self.search.pending.take() else {
+            return;
+        };
+        let max_results = self.config.search.max_results;
+
+        match task.await.map_err(anyhow::Error::from).and_then(|r| r) {
             Ok(mut results) => {
                 let track_count = results.tracks.len();
                 let album_count = results.albums.len();
@@ -659,6 +1006,8 @@ impl App {
                     || artist_count >= max_results;
 
                 self.search_results = Some(results);
+                self.search.showing_trending = false;
+                self.reset_search_tab();
                 self.search.selected_track = 0;
                 self.search.selected_album = 0;
                 self.search.selected_artist = 0;
@@ -672,9 +1021,119 @@ impl App {
                 self.set_status_error(format!("Search failed: {}", e));
             }
         }
+    }
 
-        self.search.is_active = false;
-        Ok(())
+    /// Open the search box, pre-filled with the last query when
+    /// `search.restore_last` is set so Enter re-runs it
+    pub fn open_search(&mut self) {
+        self.view_mode = ViewMode::Search;
+        self.search.is_active = true;
+        self.search.query.clear();
+        if self.config.search.restore_last {
+            if let Some(last) = self.search_history.entries.front() {
+                self.search.query = last.query.clone();
+            }
+        }
+        self.search.show_suggestions = false;
+        self.search.history_index = -1;
+        self.reset_search_tab();
+        self.add_debug("Search mode activated".to_string());
+    }
+
+    /// Fill the empty search screen with trending tracks when
+    /// `search.show_trending` is set. Fetched once, then kept until a search
+    /// replaces them.
+    pub async fn show_trending_if_empty(&mut self) {
+        if !self.config.search.show_trending || !self.search.query.is_empty() {
+            return;
+        }
+        if self.search_results.is_some() {
+            if self.search.showing_trending {
+                self.search.tab = crate::ui::SearchTab::Tracks;
+            }
+            return;
+        }
+
+        match self.music_service.get_trending(self.config.search.max_results).await {
+            Ok(tracks) if !tracks.is_empty() => {
+                self.add_debug(format!("Loaded {} trending tracks", tracks.len()));
+                self.search_results = Some(SearchResults {
+                    tracks,
+                    albums: Vec::new(),
+                    artists: Vec::new(),
+                });
+                self.search.showing_trending = true;
+                self.search.tab = crate::ui::SearchTab::Tracks;
+                self.search.selected_track = 0;
+            }
+            Ok(_) => self.add_debug("No trending tracks available".to_string()),
+            Err(e) => self.add_debug(format!("Failed to load trending tracks: {}", e)),
+        }
+    }
+
+    /// Go back to `search.default_tab`, unless `search.remember_tab` keeps
+    /// the user on the tab they last picked
+    fn reset_search_tab(&mut self) {
+        if !self.config.search.remember_tab {
+            self.search.tab = crate::ui::SearchTab::from_config(&self.config.search.default_tab);
+        }
+    }
+
+    /// Whether the visualizer panel is drawn, honoring `ui.visualizer_on_pause = "hide"`
+    pub fn visualizer_visible(&self) -> bool {
+        self.show_visualizer
+            && self.visualizer.is_some()
+            && (self.playback.is_playing || self.config.ui.visualizer_on_pause != "hide")
+    }
+
+    /// Bring up the screensaver once playback has gone `ui.screensaver_timeout`
+    /// seconds without input
+    pub fn check_screensaver(&mut self) {
+        let timeout = self.config.ui.screensaver_timeout;
+        if timeout == 0 || self.screensaver || !self.playback.is_playing || self.visualizer.is_none() {
+            return;
+        }
+        if self.last_input.elapsed() >= std::time::Duration::from_secs(timeout) {
+            self.screensaver = true;
+        }
+    }
+
+    /// Record user input. Returns true when it only dismissed the
+    /// screensaver and shouldn't be handled any further.
+    pub fn note_input(&mut self) -> bool {
+        self.last_input = std::time::Instant::now();
+        std::mem::take(&mut self.screensaver)
+    }
+
+    /// Whether Tab in search cycles the service filter rather than result tabs
+    pub fn tab_cycles_search_filter(&self) -> bool {
+        self.config.search.tab_action == "filter"
+    }
+
+    /// Step the search result tab: Tracks, Albums, Artists (reversed when
+    /// `forward` is false)
+    pub fn cycle_search_tab(&mut self, forward: bool) {
+        use crate::ui::SearchTab;
+        self.search.tab = match (self.search.tab, forward) {
+            (SearchTab::Tracks, true) | (SearchTab::Artists, false) => SearchTab::Albums,
+            (SearchTab::Albums, true) | (SearchTab::Tracks, false) => SearchTab::Artists,
+            (SearchTab::Artists, true) | (SearchTab::Albums, false) => SearchTab::Tracks,
+        };
+        self.add_debug(format!("Switched to {:?} results", self.search.tab));
+    }
+
+    /// Step the search service filter: all, Tidal, YouTube, Bandcamp, all
+    /// (reversed when `forward` is false)
+    pub fn cycle_search_service_filter(&mut self, forward: bool) {
+        const FILTERS: [Option<ServiceType>; 4] =
+            [None, Some(ServiceType::Tidal), Some(ServiceType::YouTube), Some(ServiceType::Bandcamp)];
+        let current = FILTERS.iter().position(|f| *f == self.search.service_filter).unwrap_or(0);
+        let step = if forward { 1 } else { FILTERS.len() - 1 };
+        self.search.service_filter = FILTERS[(current + step) % FILTERS.len()];
+        self.search.selected_track = 0;
+        self.search.selected_album = 0;
+        self.search.selected_artist = 0;
+        self.add_debug(format!("Service filter: {:?}", self.search.service_filter));
     }
 
     pub async fn load_favorites(&mut self) {
@@ -826,32 +1285,202 @@ impl App {
         }
     }
 
-    pub fn get_selected_track(&self) -> Option<Track> {
+    /// Favorite the playing track, whatever is selected
+    pub async fn love_current_track(&mut self) {
+        let Some(track) = self.current_track.clone() else {
+            self.set_status_error("No track playing to love".to_string());
+            return;
+        };
+        if self.favorite_tracks.iter().any(|t| t.id == track.id) {
+            self.set_status_info(format!("♥ '{}' is already a favorite", track.title));
+            return;
+        }
+
+        match self.music_service.add_favorite_track(&track.id).await {
+            Ok(()) => {
+                self.add_debug(format!("Added '{}' to favorites", track.title));
+                self.set_status_info(format!("♥ Loved '{}'", track.title));
+                self.favorite_tracks.push(track);
+            }
+            Err(e) => {
+                self.set_status_error(format!("Failed to add to favorites: {}", e));
+            }
+        }
+    }
+
+    /// Favorite the album `track` is on
+    pub async fn add_favorite_album_of(&mut self, track: Track) {
+        let Some(album_id) = track.album_id.clone() else {
+            self.set_status_error(format!("No album known for '{}'", track.title));
+            return;
+        };
+        self.add_favorite_album(Album {
+            id: album_id,
+            title: track.album,
+            artist: track.artist,
+            num_tracks: 0,
+            cover_art: track.cover_art,
+            service: track.service,
+            album_type: AlbumType::default(),
+        })
+        .await;
+    }
+
+    /// Favorite `album` and list it with the library's favorite albums
+    pub async fn add_favorite_album(&mut self, album: Album) {
+        if self.favorite_albums.iter().any(|a| a.id == album.id) {
+            self.set_status_info(format!("'{}' is already a favorite", album.title));
+            return;
+        }
+
+        self.add_debug(format!("Adding album to favorites: {}", album.title));
+        match self.music_service.add_favorite_album(&album.id).await {
+            Ok(()) => {
+                self.set_status_info(format!("Added album '{}' to favorites", album.title));
+                self.favorite_albums.push(album);
+            }
+            Err(e) => {
+                self.set_status_error(format!("Failed to favorite album: {}", e));
+            }
+        }
+    }
+
+    pub async fn remove_favorite_album(&mut self, index: usize) {
+        let Some(album) = self.favorite_albums.get(index).cloned() else {
+            return;
+        };
+
+        match self.music_service.remove_favorite_album(&album.id).await {
+            Ok(()) => {
+                self.set_status_info(format!("Removed album '{}' from favorites", album.title));
+                self.favorite_albums.remove(index);
+                self.library.selected_album = self.library.selected_album.min(self.favorite_albums.len().saturating_sub(1));
+            }
+            Err(e) => {
+                self.set_status_error(format!("Failed to remove album from favorites: {}", e));
+            }
+        }
+    }
+
+    /// Favorite `artist` and list it with the library's favorite artists
+    pub async fn add_favorite_artist(&mut self, artist: Artist) {
+        if self.favorite_artists.iter().any(|a| a.id == artist.id) {
+            self.set_status_info(format!("'{}' is already a favorite", artist.name));
+            return;
+        }
+
+        self.add_debug(format!("Adding artist to favorites: {}", artist.name));
+        match self.music_service.add_favorite_artist(&artist.id).await {
+            Ok(()) => {
+                self.set_status_info(format!("Added artist '{}' to favorites", artist.name));
+                self.favorite_artists.push(artist);
+            }
+            Err(e) => {
+                self.set_status_error(format!("Failed to favorite artist: {}", e));
+            }
+        }
+    }
+
+    pub async fn remove_favorite_artist(&mut self, index: usize) {
+        let Some(artist) = self.favorite_artists.get(index).cloned() else {
+            return;
+        };
+
+        match self.music_service.remove_favorite_artist(&artist.id).await {
+            Ok(()) => {
+                self.set_status_info(format!("Removed artist '{}' from favorites", artist.name));
+                self.favorite_artists.remove(index);
+                self.library.selected_artist = self.library.selected_artist.min(self.favorite_artists.len().saturating_sub(1));
+            }
+            Err(e) => {
+                self.set_status_error(format!("Failed to remove artist from favorites: {}", e));
+            }
+        }
+    }
+
+    /// Item selected in the focused list: the queue when it has focus,
+    /// otherwise the current view's list (or panel, or tab)
+    pub fn get_selected_item(&self) -> Option<SelectedItem> {
+        if self.playback.show_queue && !self.local_queue.is_empty() {
+            let index = self.playback.selected_queue_item;
+            return self.local_queue.get(index).cloned().map(|t| SelectedItem::QueueItem(index, t));
+        }
+
         match self.view_mode {
+            ViewMode::Browse if self.browse.selected_tab == 0 => {
+                self.playlists.get(self.browse.selected_playlist).cloned().map(SelectedItem::Playlist)
+            }
             ViewMode::Browse => {
-                if self.browse.selected_tab == 1 && self.browse.selected_track < self.tracks.len() {
-                    Some(self.tracks[self.browse.selected_track].clone())
-                } else {
-                    None
-                }
+                self.browse.selected_row()?;
+                self.tracks.get(self.browse.selected_track).cloned().map(SelectedItem::Track)
             }
             ViewMode::Search => {
-                if let Some(ref results) = self.search_results {
-                    if self.search.tab == crate::ui::SearchTab::Tracks
-                        && self.search.selected_track < results.tracks.len()
-                    {
-                        Some(results.tracks[self.search.selected_track].clone())
-                    } else {
-                        None
-                    }
-                } else {
-                    None
+                let results = self.search_results.as_ref()?;
+                match self.search.tab {
+                    crate::ui::SearchTab::Tracks => results.tracks.get(self.search.selected_track).cloned().map(SelectedItem::Track),
+                    crate::ui::SearchTab::Albums => results.albums.get(self.search.selected_album).cloned().map(SelectedItem::Album),
+                    crate::ui::SearchTab::Artists => results.artists.get(self.search.selected_artist).cloned().map(SelectedItem::Artist),
                 }
             }
+            ViewMode::Library => match self.library.tab {
+                crate::ui::LibraryTab::Tracks => self.favorite_tracks.get(self.library.selected_track).cloned().map(SelectedItem::Track),
+                crate::ui::LibraryTab::Albums => self.favorite_albums.get(self.library.selected_album).cloned().map(SelectedItem::Album),
+                crate::ui::LibraryTab::Artists => self.favorite_artists.get(self.library.selected_artist).cloned().map(SelectedItem::Artist),
+                crate::ui::LibraryTab::History => self
+                    .history_entries
+                    .get(self.library.selected_history)
+                    .map(|entry| SelectedItem::Track(Track::from(entry))),
+            },
+            ViewMode::ArtistDetail if self.artist_detail.selected_panel == 0 => {
+                self.artist_detail.top_tracks.get(self.artist_detail.selected_track).cloned().map(SelectedItem::Track)
+            }
+            ViewMode::ArtistDetail => {
+                self.artist_detail.albums.get(self.artist_detail.selected_album).cloned().map(SelectedItem::Album)
+            }
+            ViewMode::AlbumDetail => {
+                self.album_detail.tracks.get(self.album_detail.selected_track).cloned().map(SelectedItem::Track)
+            }
+            ViewMode::Downloads => None,
+        }
+    }
+
+    /// Selected track, including one selected in the queue
+    pub fn get_selected_track(&self) -> Option<Track> {
+        match self.get_selected_item()? {
+            SelectedItem::QueueItem(_, track) | SelectedItem::Track(track) => Some(track),
+            _ => None,
+        }
+    }
+
+    pub fn get_selected_album(&self) -> Option<Album> {
+        match self.get_selected_item()? {
+            SelectedItem::Album(album) => Some(album),
             _ => None,
         }
     }
 
+    /// Selected artist, or in artist detail the one whose page is open
+    pub fn get_selected_artist(&self) -> Option<Artist> {
+        match self.get_selected_item() {
+            Some(SelectedItem::Artist(artist)) => Some(artist),
+            Some(SelectedItem::QueueItem(..)) => None,
+            _ if self.view_mode == ViewMode::ArtistDetail => self.artist_detail.artist.clone(),
+            _ => None,
+        }
+    }
+
+    /// Track list shown in the current view, empty when the view has none
+    pub fn visible_tracks(&self) -> &[Track] {
+        match self.view_mode {
+            ViewMode::Browse => &self.tracks,
+            ViewMode::Search => self.search_results.as_ref().map_or(&[], |r| r.tracks.as_slice()),
+            ViewMode::Library if self.library.tab == crate::ui::LibraryTab::Tracks => &self.favorite_tracks,
+            ViewMode::AlbumDetail => &self.album_detail.tracks,
+            ViewMode::ArtistDetail => &self.artist_detail.top_tracks,
+            ViewMode::Library | ViewMode::Downloads => &[],
+        }
+    }
+
     /// Push current view to navigation history and switch to new view
     pub fn push_view(&mut self, new_mode: ViewMode) {
         self.navigation_history.push(self.view_mode);
@@ -865,6 +1494,71 @@ impl App {
         }
     }
 
+    /// Jump straight to a pasted track, album or artist link instead of searching
+    async fn open_service_url(&mut self, service: ServiceType, kind: ResourceKind, id: String) -> Result<()> {
+        self.add_debug(format!("Opening {} {:?} link: {}", service, kind, id));
+
+        match kind {
+            ResourceKind::Track => {
+                // The link only carries the id; look the track up so it plays
+                // (and is recorded) under its real title
+                let track = match self.music_service.get_track_from(service, &id).await {
+                    Ok(track) => track,
+                    Err(e) => {
+                        self.add_debug(format!("Could not look up track {}: {}", id, e));
+                        Track {
+                            title: id.clone(),
+                            id,
+                            artist: String::new(),
+                            album: String::new(),
+                            album_id: None,
+                            duration_seconds: 0,
+                            cover_art: CoverArt::None,
+                            service,
+                            available: true,
+                        }
+                    }
+                };
+                self.play_track(track).await?;
+            }
+            ResourceKind::Album => {
+                let album = Album {
+                    title: id.clone(),
+                    id,
+                    artist: String::new(),
+                    num_tracks: 0,
+                    cover_art: CoverArt::None,
+                    service,
+                    album_type: AlbumType::Unknown,
+                };
+                self.push_view(ViewMode::AlbumDetail);
+                self.load_album_detail(album).await;
+
+                // The link only carries the id; name the album after its tracks
+                if let (Some(album), Some(first)) = (self.album_detail.album.as_mut(), self.album_detail.tracks.first()) {
+                    album.title = first.album.clone();
+                    album.artist = first.artist.clone();
+                    album.num_tracks = self.album_detail.tracks.len() as u32;
+                    album.cover_art = first.cover_art.clone();
+                }
+            }
+            ResourceKind::Artist => {
+                let artist = Artist {
+                    name: id.clone(),
+                    id,
+                    service,
+                };
+                self.push_view(ViewMode::ArtistDetail);
+                self.load_artist_detail(artist).await;
+
+                if let (Some(artist), Some(first)) = (self.artist_detail.artist.as_mut(), self.artist_detail.top_tracks.first()) {
+                    artist.name = first.artist.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Load artist detail data
     pub async fn load_artist_detail(&mut self, artist: Artist) {
         use crate::storage::metadata_cache::CacheStatus;
@@ -874,7 +1568,8 @@ impl App {
         self.artist_detail.selected_album = 0;
         self.artist_detail.selected_panel = 0;
         self.artist_detail.top_tracks.clear();
-        self.artist_detail.albums.clear();
+        self.artist_detail.album_filter = None;
+        self.set_artist_albums(Vec::new());
 
         self.add_debug(format!("Loading artist: {}", artist.name));
 
@@ -882,7 +1577,7 @@ impl App {
         if let Ok(Some(hit)) = self.metadata_cache.get_artist_data(&artist.id) {
             let (tracks, albums) = hit.data;
             self.artist_detail.top_tracks = tracks;
-            self.artist_detail.albums = albums;
+            self.set_artist_albums(albums);
             let status = match hit.status {
                 CacheStatus::Fresh => { return; } // fresh — done
                 CacheStatus::Stale => "stale",
@@ -893,7 +1588,7 @@ impl App {
 
         // Fetch top tracks from API
         let mut tracks_ok = false;
-        match self.music_service.get_artist_top_tracks(&artist.id).await {
+        match self.music_service.get_artist_top_tracks_for(&artist).await {
             Ok(tracks) => {
                 self.add_debug(format!("Loaded {} top tracks", tracks.len()));
                 self.artist_detail.top_tracks = tracks;
@@ -906,10 +1601,10 @@ impl App {
 
         // Fetch albums from API
         let mut albums_ok = false;
-        match self.music_service.get_artist_albums(&artist.id).await {
+        match self.music_service.get_artist_albums_for(&artist).await {
             Ok(albums) => {
                 self.add_debug(format!("Loaded {} albums", albums.len()));
-                self.artist_detail.albums = albums;
+                self.set_artist_albums(albums);
                 albums_ok = true;
             }
             Err(e) => {
@@ -922,11 +1617,52 @@ impl App {
             let _ = self.metadata_cache.set_artist_data(
                 &artist.id,
                 &self.artist_detail.top_tracks,
-                &self.artist_detail.albums,
+                &self.artist_detail.all_albums,
             );
         }
     }
 
+    /// Replace the artist's albums, re-applying the current type filter
+    fn set_artist_albums(&mut self, albums: Vec<Album>) {
+        self.artist_detail.all_albums = albums;
+        self.apply_album_filter();
+    }
+
+    fn apply_album_filter(&mut self) {
+        let filter = self.artist_detail.album_filter;
+        self.artist_detail.albums = self
+            .artist_detail
+            .all_albums
+            .iter()
+            .filter(|a| filter.is_none_or(|t| a.album_type == t))
+            .cloned()
+            .collect();
+        self.artist_detail.selected_album = self
+            .artist_detail
+            .selected_album
+            .min(self.artist_detail.albums.len().saturating_sub(1));
+    }
+
+    /// Cycle the artist detail album filter: all -> albums -> singles -> EPs -> compilations
+    pub fn cycle_album_filter(&mut self) {
+        if self.artist_detail.all_albums.iter().all(|a| a.album_type == AlbumType::Unknown) {
+            self.set_status_info("This service doesn't report album types".to_string());
+            return;
+        }
+
+        self.artist_detail.album_filter = AlbumType::next_filter(self.artist_detail.album_filter);
+        self.artist_detail.selected_album = 0;
+        self.apply_album_filter();
+
+        let label = self.artist_detail.album_filter.map_or("all releases", |t| t.label());
+        self.set_status_info(format!(
+            "Showing {} ({} of {})",
+            label.to_lowercase(),
+            self.artist_detail.albums.len(),
+            self.artist_detail.all_albums.len()
+        ));
+    }
+
     /// Load album detail data
     pub async fn load_album_detail(&mut self, album: Album) {
         self.album_detail.album = Some(album.clone());
@@ -945,7 +1681,7 @@ impl App {
         }
 
         // Fetch from API
-        match self.music_service.get_album_tracks(&album.id).await {
+        match self.music_service.get_album_tracks_for(&album).await {
             Ok(tracks) => {
                 self.add_debug(format!("Loaded {} tracks", tracks.len()));
                 let _ = self.metadata_cache.set_album_tracks(&album.id, &tracks);
@@ -968,6 +1704,70 @@ impl App {
                 self.add_debug(format!("Failed to record history: {}", e));
             }
         }
+
+        // Tell the scrobbler now; the listen itself is submitted from
+        // check_mpd_status once enough of the track has played
+        if let Some(scrobbler) = self.scrobbler.clone() {
+            self.playback.pending_listen = Some(HistoryEntry::new(track, chrono::Utc::now()));
+            let track = track.clone();
+            tokio::spawn(async move {
+                if let Err(e) = scrobbler.update_now_playing(&track).await {
+                    tracing::warn!("{} now playing update failed: {}", scrobbler.name(), e);
+                }
+            });
+        }
+    }
+
+    /// Look `track` up on MusicBrainz, from the cache when it's been looked
+    /// up before (however long ago; release years don't change)
+    fn enrich_track(&mut self, track: &Track) {
+        let Some(musicbrainz) = self.musicbrainz.clone() else {
+            return;
+        };
+        if let Some(pending) = self.playback.pending_enrichment.take() {
+            pending.task.abort();
+        }
+        if let Ok(Some(hit)) = self.metadata_cache.get_enrichment(track.service, &track.id) {
+            self.playback.enrichment = Some((track.id.clone(), hit.data));
+            return;
+        }
+
+        let lookup = track.clone();
+        self.playback.pending_enrichment = Some(PendingEnrichment {
+            track_id: track.id.clone(),
+            service: track.service,
+            task: tokio::spawn(async move { musicbrainz.enrich_track(&lookup).await }),
+        });
+    }
+
+    /// Cache and show the MusicBrainz lookup in flight once its task finishes
+    pub async fn poll_enrichment(&mut self) {
+        if !self.playback.pending_enrichment.as_ref().is_some_and(|p| p.task.is_finished()) {
+            return;
+        }
+        let Some(PendingEnrichment { track_id, service, task }) = self.playback.pending_enrichment.take() else {
+            return;
+        };
+
+        match task.await.map_err(anyhow::Error::from).and_then(|r| r) {
+            Ok(enriched) => {
+                if let Err(e) = self.metadata_cache.set_enrichment(service, &track_id, &enriched) {
+                    self.add_debug(format!("Failed to cache MusicBrainz details: {}", e));
+                }
+                self.playback.enrichment = Some((track_id, enriched));
+            }
+            // Not worth a status message; the pane just goes without
+            Err(e) => self.add_debug(format!("MusicBrainz lookup failed: {}", e)),
+        }
+    }
+
+    /// Release year of the current track, once MusicBrainz has found it
+    pub fn release_year(&self) -> Option<u16> {
+        let current = self.current_track.as_ref()?;
+        match &self.playback.enrichment {
+            Some((track_id, enriched)) if *track_id == current.id => enriched.year,
+            _ => None,
+        }
     }
 
     // ========== Playlist Management ==========
@@ -1004,6 +1804,8 @@ impl App {
         self.dialog.mode = DialogMode::ConfirmDeletePlaylist {
             playlist_id: playlist.id.clone(),
             playlist_title: playlist.title.clone(),
+            track_count: playlist.num_tracks,
+            synced: self.downloads.synced_playlist_ids.contains(&playlist.id),
         };
         self.add_debug(format!("Delete playlist dialog for: {}", playlist.title));
     }
@@ -1013,6 +1815,7 @@ impl App {
         self.dialog.mode = DialogMode::None;
         self.dialog.input_text.clear();
         self.dialog.selected_index = 0;
+        self.dialog.pending_downloads.clear();
     }
 
     /// Check if any dialog is open
@@ -1042,6 +1845,65 @@ impl App {
         }
     }
 
+    /// Copy the selected playlist into a new "<title> (copy)" playlist
+    pub async fn duplicate_selected_playlist(&mut self) {
+        // Services cap how many ids one add request may carry
+        const BATCH_SIZE: usize = 100;
+
+        let Some(original) = self.playlists.get(self.browse.selected_playlist).cloned() else {
+            return;
+        };
+        if original.id.starts_with("demo-") {
+            self.add_debug("Cannot duplicate demo playlists".to_string());
+            return;
+        }
+
+        let tracks = match self.music_service.get_playlist_tracks(&original.id).await {
+            Ok(tracks) => tracks,
+            Err(e) => {
+                self.set_status_error(format!("Failed to load '{}': {}", original.title, e));
+                return;
+            }
+        };
+
+        let name = format!("{} (copy)", original.title);
+        let mut copy = match self.music_service.create_playlist(&name, original.description.as_deref()).await {
+            Ok(playlist) => playlist,
+            Err(e) => {
+                self.set_status_error(format!("Failed to create '{}': {}", name, e));
+                return;
+            }
+        };
+        self.add_debug(format!("Duplicating '{}' ({} tracks)", original.title, tracks.len()));
+
+        let track_ids: Vec<String> = tracks.iter().map(|t| t.id.clone()).collect();
+        let mut copied = 0;
+        for batch in track_ids.chunks(BATCH_SIZE) {
+            if let Err(e) = self.music_service.add_tracks_to_playlist(&copy.id, batch).await {
+                self.set_status_error(format!(
+                    "Copied {}/{} tracks to '{}': {}",
+                    copied,
+                    track_ids.len(),
+                    copy.title,
+                    e
+                ));
+                break;
+            }
+            copied += batch.len();
+            if track_ids.len() > BATCH_SIZE {
+                self.add_debug(format!("  copied {}/{} tracks", copied, track_ids.len()));
+            }
+        }
+
+        copy.num_tracks = copied;
+        if copied == track_ids.len() {
+            self.set_status_info(format!("Duplicated '{}' as '{}'", original.title, copy.title));
+        }
+        self.playlists.insert(0, copy);
+        // Keep the original selected now that everything shifted down
+        self.browse.selected_playlist += 1;
+    }
+
     /// Add a track to the selected playlist
     pub async fn add_track_to_playlist_from_dialog(&mut self) {
         let (track_id, playlist_id) = match &self.dialog.mode {
@@ -1107,9 +1969,9 @@ impl App {
 
     /// Delete the playlist after confirmation
     pub async fn delete_playlist_from_dialog(&mut self) {
-        let (playlist_id, playlist_title) = match &self.dialog.mode {
-            DialogMode::ConfirmDeletePlaylist { playlist_id, playlist_title } => {
-                (playlist_id.clone(), playlist_title.clone())
+        let (playlist_id, playlist_title, synced) = match &self.dialog.mode {
+            DialogMode::ConfirmDeletePlaylist { playlist_id, playlist_title, synced, .. } => {
+                (playlist_id.clone(), playlist_title.clone(), *synced)
             }
             _ => return,
         };
@@ -1119,6 +1981,16 @@ impl App {
         match self.music_service.delete_playlist(&playlist_id).await {
             Ok(()) => {
                 self.add_debug("Playlist deleted".to_string());
+                // Stop auto-syncing a playlist that no longer exists; the
+                // downloaded files stay on disk
+                if synced {
+                    if let Some(ref dm) = self.download_manager {
+                        if let Err(e) = dm.remove_synced_playlist(&playlist_id) {
+                            self.add_debug(format!("Failed to unsync deleted playlist: {}", e));
+                        }
+                    }
+                    self.downloads.synced_playlist_ids.remove(&playlist_id);
+                }
                 // Remove from local state
                 self.playlists.retain(|p| p.id != playlist_id);
                 // Reset selection if needed
@@ -1156,22 +2028,15 @@ impl App {
         let playlist_id = playlist.id.clone();
         let track_index = self.browse.selected_track;
         let track_title = self.tracks[track_index].title.clone();
+        // The playlist index differs from the display index when sorted
+        let playlist_index = self.browse.track_positions.get(track_index).copied().unwrap_or(track_index);
 
         self.add_debug(format!("Removing '{}' from playlist", track_title));
 
-        match self.music_service.remove_tracks_from_playlist(&playlist_id, &[track_index]).await {
+        match self.music_service.remove_tracks_from_playlist(&playlist_id, &[playlist_index]).await {
             Ok(()) => {
                 self.add_debug(format!("Removed '{}' from playlist", track_title));
-                // Remove from local state
-                self.tracks.remove(track_index);
-                // Update playlist track count
-                if let Some(p) = self.playlists.iter_mut().find(|p| p.id == playlist_id) {
-                    p.num_tracks = p.num_tracks.saturating_sub(1);
-                }
-                // Adjust selection if needed
-                if self.browse.selected_track >= self.tracks.len() && !self.tracks.is_empty() {
-                    self.browse.selected_track = self.tracks.len() - 1;
-                }
+                self.forget_playlist_track(&playlist_id, playlist_index);
             }
             Err(e) => {
                 self.add_debug(format!("Failed to remove track: {}", e));
@@ -1179,6 +2044,74 @@ impl App {
         }
     }
 
+    /// Remove the playing track from the playlist it was queued from, then
+    /// skip to the next track
+    pub async fn remove_current_from_source_playlist(&mut self) {
+        let Some(track) = self.current_track.clone() else {
+            self.set_status_error("No track playing".to_string());
+            return;
+        };
+        let Some(origin) = self.playback.track_origins.get(&track.id).cloned() else {
+            self.set_status_error(format!("'{}' wasn't queued from a playlist", track.title));
+            return;
+        };
+        // Only the user's own playlists can be edited
+        let Some(playlist) = self.playlists.iter().find(|p| p.id == origin.playlist_id) else {
+            self.set_status_error("Can't edit the playlist this track came from".to_string());
+            return;
+        };
+        if playlist.id.starts_with("demo-") {
+            self.set_status_error("Cannot modify demo playlists".to_string());
+            return;
+        }
+        let playlist_title = playlist.title.clone();
+
+        match self.music_service.remove_tracks_from_playlist(&origin.playlist_id, &[origin.index]).await {
+            Ok(()) => {
+                self.forget_playlist_track(&origin.playlist_id, origin.index);
+                self.set_status_info(format!("Removed '{}' from '{}'", track.title, playlist_title));
+                if let Err(e) = self.mpd_controller.next(&mut self.debug_log).await {
+                    self.set_status_error(format!("Next failed: {}", e));
+                }
+            }
+            Err(e) => {
+                self.set_status_error(format!("Failed to remove track from playlist: {}", e));
+            }
+        }
+    }
+
+    /// Update local state after the track at `index` was removed from a
+    /// playlist: the loaded track list, its count and queued tracks' origins
+    fn forget_playlist_track(&mut self, playlist_id: &str, index: usize) {
+        if self.browse.loaded_playlist.as_deref() == Some(playlist_id) {
+            if let Some(row) = self.browse.track_positions.iter().position(|&pos| pos == index) {
+                self.tracks.remove(row);
+                self.browse.track_positions.remove(row);
+            }
+            for pos in self.browse.track_positions.iter_mut() {
+                if *pos > index {
+                    *pos -= 1;
+                }
+            }
+            // Adjust selection if needed
+            if self.browse.selected_track >= self.tracks.len() && !self.tracks.is_empty() {
+                self.browse.selected_track = self.tracks.len() - 1;
+            }
+            self.refilter_tracks();
+        }
+
+        if let Some(p) = self.playlists.iter_mut().find(|p| p.id == playlist_id) {
+            p.num_tracks = p.num_tracks.saturating_sub(1);
+        }
+
+        self.playback.track_origins.retain(|_, origin| origin.playlist_id != playlist_id || origin.index != index);
+        for origin in self.playback.track_origins.values_mut() {
+            if origin.playlist_id == playlist_id && origin.index > index {
+                origin.index -= 1;
+            }
+        }
+    }
+
     /// Prefetch album art for the currently selected search result
     pub async fn prefetch_search_preview_art(&mut self) {
         // Only prefetch when preview is enabled and in search mode
@@ -1187,7 +2120,7 @@ impl App {
         }
 
         // Get the cover art for the selected item
-        let cover_art = match self.search.tab {
+        let cover_art: Option<CoverArt> = match self.search.tab {
             crate::ui::SearchTab::Tracks => {
                 self.search_results.as_ref().and_then(|r| {
                     // Apply service filter
@@ -1211,25 +2144,57 @@ impl App {
             }
         };
 
-        // Load the cover art if not cached
-        if let Some(cover) = cover_art {
-            match &cover {
-                CoverArt::ServiceId { id, .. } => {
-                    if !self.album_art_cache.has_cached(id, 320) {
-                        if let Err(e) = self.album_art_cache.get_album_art(id, 320).await {
-                            self.add_debug(format!("Preview art load failed: {}", e));
-                        }
-                    }
-                }
-                CoverArt::Url(url) => {
-                    if !self.album_art_cache.has_url_cached(url, 320) {
-                        if let Err(e) = self.album_art_cache.get_album_art_from_url(url, 320).await {
-                            self.add_debug(format!("Preview art load failed: {}", e));
-                        }
-                    }
-                }
-                CoverArt::None => {}
+        // Only the selected result's art is fetched: moving on aborts the
+        // fetch for the one before, so holding j doesn't pile up requests
+        if self.search.preview_art.as_ref().is_some_and(|fetch| Some(&fetch.cover) != cover_art.as_ref()) {
+            if let Some(task) = self.search.preview_art.take().and_then(|fetch| fetch.task) {
+                task.abort();
             }
         }
+        let Some(cover) = cover_art else {
+            return;
+        };
+
+        let Some(fetch) = self.search.preview_art.as_mut() else {
+            if !self.album_art_cache.has_cover_cached(&cover, 320) {
+                self.search.preview_art = Some(state::PreviewArtFetch {
+                    cover,
+                    selected_at: std::time::Instant::now(),
+                    requested: false,
+                    task: None,
+                });
+            }
+            return;
+        };
+        if !fetch.requested {
+            // Wait for the selection to rest so results skimmed past aren't fetched
+            let delay = std::time::Duration::from_millis(self.config.ui.art_prefetch_delay_ms);
+            if fetch.selected_at.elapsed() >= delay {
+                fetch.requested = true;
+                fetch.task = self.album_art_cache.fetch(&fetch.cover, 320).map(tokio::spawn);
+            }
+            return;
+        }
+        if !fetch.task.as_ref().is_some_and(|task| task.is_finished()) {
+            return;
+        }
+        let Some(task) = fetch.task.take() else {
+            return;
+        };
+        match task.await.map_err(anyhow::Error::from).and_then(|r| r) {
+            Ok(art) => self.album_art_cache.insert_fetched(art),
+            Err(e) => self.add_debug(format!("Preview art load failed: {}", e)),
+        }
     }
 }
+
+/// Turn terminal mouse capture on or off. Off gives back the terminal's own
+/// text selection.
+pub fn set_mouse_capture(enabled: bool) {
+    use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+    let _ = if enabled {
+        crossterm::execute!(std::io::stdout(), EnableMouseCapture)
+    } else {
+        crossterm::execute!(std::io::stdout(), DisableMouseCapture)
+    };
+}
@@ -1,3 +1,5 @@
+use ratatui::layout::Position;
+
 use super::App;
 use super::state::ViewMode;
 use crate::ui::{SearchTab, LibraryTab};
@@ -42,8 +44,9 @@ impl App {
         } else if self.view_mode == ViewMode::Browse {
             if self.browse.selected_tab == 0 && !self.playlists.is_empty() {
                 self.browse.selected_playlist = (self.browse.selected_playlist + 1).min(self.playlists.len() - 1);
-            } else if self.browse.selected_tab == 1 && !self.tracks.is_empty() {
-                self.browse.selected_track = (self.browse.selected_track + 1).min(self.tracks.len() - 1);
+            } else if self.browse.selected_tab == 1 {
+                let row = self.browse.selected_row().map_or(0, |r| r + 1);
+                self.browse.select_row(row, self.tracks.len());
             }
         } else if let Some(ref results) = self.search_results {
             match self.search.tab {
@@ -99,8 +102,9 @@ impl App {
         } else if self.view_mode == ViewMode::Browse {
             if self.browse.selected_tab == 0 && self.browse.selected_playlist > 0 {
                 self.browse.selected_playlist -= 1;
-            } else if self.browse.selected_tab == 1 && self.browse.selected_track > 0 {
-                self.browse.selected_track -= 1;
+            } else if self.browse.selected_tab == 1 {
+                let row = self.browse.selected_row().unwrap_or(0).saturating_sub(1);
+                self.browse.select_row(row, self.tracks.len());
             }
         } else if self.search_results.is_some() {
             match self.search.tab {
@@ -160,7 +164,7 @@ impl App {
             if self.browse.selected_tab == 0 {
                 self.browse.selected_playlist = 0;
             } else {
-                self.browse.selected_track = 0;
+                self.browse.select_row(0, self.tracks.len());
             }
         } else {
             match self.search.tab {
@@ -203,8 +207,8 @@ impl App {
         } else if self.view_mode == ViewMode::Browse {
             if self.browse.selected_tab == 0 && !self.playlists.is_empty() {
                 self.browse.selected_playlist = self.playlists.len() - 1;
-            } else if self.browse.selected_tab == 1 && !self.tracks.is_empty() {
-                self.browse.selected_track = self.tracks.len() - 1;
+            } else if self.browse.selected_tab == 1 {
+                self.browse.select_row(usize::MAX, self.tracks.len());
             }
         } else if let Some(ref results) = self.search_results {
             match self.search.tab {
@@ -222,23 +226,122 @@ impl App {
         }
     }
 
+    /// Tab / Shift+Tab: switch panel in Browse and artist detail, the
+    /// Library tab, or the search result tab or service filter
+    pub fn cycle_tab(&mut self, forward: bool) {
+        if self.view_mode == ViewMode::Browse {
+            self.browse.selected_tab = (self.browse.selected_tab + 1) % 2;
+            self.add_debug(format!("Switched to {} panel",
+                if self.browse.selected_tab == 0 { "playlists" } else { "tracks" }));
+        } else if self.view_mode == ViewMode::ArtistDetail {
+            self.artist_detail.selected_panel = (self.artist_detail.selected_panel + 1) % 2;
+            self.add_debug(format!("Switched to {} panel",
+                if self.artist_detail.selected_panel == 0 { "top tracks" } else { "albums" }));
+        } else if self.view_mode == ViewMode::Library {
+            self.library.tab = match (self.library.tab, forward) {
+                (LibraryTab::Tracks, true) | (LibraryTab::Artists, false) => LibraryTab::Albums,
+                (LibraryTab::Albums, true) | (LibraryTab::History, false) => LibraryTab::Artists,
+                (LibraryTab::Artists, true) | (LibraryTab::Tracks, false) => LibraryTab::History,
+                (LibraryTab::History, true) | (LibraryTab::Albums, false) => LibraryTab::Tracks,
+            };
+            self.add_debug(format!("Switched to {:?} tab", self.library.tab));
+        } else if self.view_mode == ViewMode::Search {
+            if self.tab_cycles_search_filter() {
+                self.cycle_search_service_filter(forward);
+            } else {
+                self.cycle_search_tab(forward);
+            }
+        }
+    }
+
+    /// Open the selected artist or album in its detail view
+    pub async fn open_selected_detail(&mut self) {
+        match self.view_mode {
+            ViewMode::Search => {
+                if let Some(ref results) = self.search_results {
+                    match self.search.tab {
+                        SearchTab::Artists => {
+                            if self.search.selected_artist < results.artists.len() {
+                                let artist = results.artists[self.search.selected_artist].clone();
+                                self.add_debug(format!("Opening artist: {}", artist.name));
+                                self.push_view(ViewMode::ArtistDetail);
+                                self.load_artist_detail(artist).await;
+                            }
+                        }
+                        SearchTab::Albums => {
+                            if self.search.selected_album < results.albums.len() {
+                                let album = results.albums[self.search.selected_album].clone();
+                                self.add_debug(format!("Opening album: {}", album.title));
+                                self.push_view(ViewMode::AlbumDetail);
+                                self.load_album_detail(album).await;
+                            }
+                        }
+                        _ => {
+                            self.add_debug("Use 'v' on Artists or Albums tab".to_string());
+                        }
+                    }
+                }
+            }
+            ViewMode::Library => {
+                match self.library.tab {
+                    LibraryTab::Artists => {
+                        if self.library.selected_artist < self.favorite_artists.len() {
+                            let artist = self.favorite_artists[self.library.selected_artist].clone();
+                            self.add_debug(format!("Opening artist: {}", artist.name));
+                            self.push_view(ViewMode::ArtistDetail);
+                            self.load_artist_detail(artist).await;
+                        }
+                    }
+                    LibraryTab::Albums => {
+                        if self.library.selected_album < self.favorite_albums.len() {
+                            let album = self.favorite_albums[self.library.selected_album].clone();
+                            self.add_debug(format!("Opening album: {}", album.title));
+                            self.push_view(ViewMode::AlbumDetail);
+                            self.load_album_detail(album).await;
+                        }
+                    }
+                    _ => {
+                        self.add_debug("Use 'v' on Artists or Albums tab".to_string());
+                    }
+                }
+            }
+            ViewMode::ArtistDetail => {
+                // From artist detail, 'v' on an album opens album detail
+                if self.artist_detail.selected_panel == 1 {
+                    if self.artist_detail.selected_album < self.artist_detail.albums.len() {
+                        let album = self.artist_detail.albums[self.artist_detail.selected_album].clone();
+                        self.add_debug(format!("Opening album: {}", album.title));
+                        self.push_view(ViewMode::AlbumDetail);
+                        self.load_album_detail(album).await;
+                    }
+                } else {
+                    self.add_debug("Switch to albums panel (h/l) to view album details".to_string());
+                }
+            }
+            _ => {
+                self.add_debug("Use 'v' in Search or Library view on artists/albums".to_string());
+            }
+        }
+    }
+
     pub async fn handle_mouse_click(&mut self, col: u16, row: u16) {
         // Check progress bar for seeking
         if let Some(progress_area) = self.clickable_areas.progress_bar {
-            if col >= progress_area.x
-                && col < progress_area.x + progress_area.width
-                && row >= progress_area.y
-                && row < progress_area.y + progress_area.height
-            {
-                let click_offset = col - progress_area.x;
-                let progress_ratio = click_offset as f64 / progress_area.width as f64;
+            if progress_area.contains(Position::new(col, row)) {
+                if self.current_song.is_none() {
+                    return;
+                }
 
-                if let Some(ref song) = self.current_song {
-                    let seek_seconds = (song.duration.as_secs_f64() * progress_ratio) as u32;
-                    self.add_debug(format!("Seeking to {}s ({}%)", seek_seconds, (progress_ratio * 100.0) as u8));
-                    if let Err(e) = self.mpd_controller.seek_to(seek_seconds, &mut self.debug_log).await {
-                        self.add_debug(format!("Seek failed: {}", e));
+                let fraction = (col - progress_area.x) as f64 / progress_area.width.max(1) as f64;
+                self.add_debug(format!("Seeking to {}%", (fraction * 100.0) as u8));
+                match self.mpd_controller.seek_to_fraction(fraction, &mut self.debug_log).await {
+                    Ok(Some(elapsed)) => {
+                        if let Some(ref mut song) = self.current_song {
+                            song.elapsed = elapsed;
+                        }
                     }
+                    Ok(None) => {}
+                    Err(e) => self.set_status_error(format!("Seek error: {}", e)),
                 }
                 return;
             }
@@ -289,9 +392,9 @@ impl App {
                 let clicked_row = (row - right_area.y).saturating_sub(1) as usize;
                 if self.view_mode == ViewMode::Browse {
                     self.browse.selected_tab = 1;
-                    if clicked_row < self.tracks.len() {
-                        self.browse.selected_track = clicked_row;
-                        self.add_debug(format!("Selected track {}", clicked_row + 1));
+                    if let Some(index) = self.browse.track_at_row(clicked_row, self.tracks.len()) {
+                        self.browse.selected_track = index;
+                        self.add_debug(format!("Selected track {}", index + 1));
                     }
                 } else if let Some(ref results) = self.search_results {
                     match self.search.tab {
@@ -1,13 +1,45 @@
 use anyhow::Result;
+use std::time::{Duration, Instant};
 
 use super::App;
-use super::state::{RadioSeed, ViewMode};
+use super::state::{DialogMode, RadioSeed, RECENTLY_PLAYED_MAX};
+use crate::mpd::RepeatMode;
+use crate::nowplaying;
+use crate::scrobble::listen_threshold_secs;
 use crate::service::{CoverArt, MusicService, ServiceType, Track};
-use crate::ui::{SearchTab, LibraryTab};
+
+/// Favorites drawn as radio seeds for each "My Station" top-up
+const STATION_SEEDS: usize = 3;
+
+/// How often MPD's current song is checked against the one Drift expects
+const DESYNC_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long MPD must stay stopped before `playback.clear_on_stop` resets
+/// Now Playing, so the brief stop while a queue is replaced doesn't flicker
+const STOP_CONFIRM_DELAY: Duration = Duration::from_secs(1);
+
+/// Longest sleep timer the Space+t dialog accepts, a day
+const SLEEP_TIMER_MAX_MINUTES: u64 = 24 * 60;
+
+/// Crossfade Space+X turns on when `playback.crossfade_seconds` is 0
+const DEFAULT_CROSSFADE_SECS: u32 = 5;
+
+/// How long before the current track ends the next one's stream is checked
+const PREFETCH_LEAD: Duration = Duration::from_secs(20);
+
+/// Stream URLs resolved longer ago than this are re-resolved before they
+/// play, since Tidal's expire
+pub(super) const STREAM_URL_MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
+/// `volume` (0-100) raised or lowered by `gain_db`
+fn with_gain(volume: u8, gain_db: f64) -> u8 {
+    (volume as f64 * 10f64.powf(gain_db / 20.0)).round().clamp(0.0, 100.0) as u8
+}
 
 impl App {
     pub async fn play_track(&mut self, track: Track) -> Result<()> {
         self.add_debug(format!("Playing: {} - {}", track.artist, track.title));
+        self.remember_track_origins(std::slice::from_ref(&track));
 
         // Check if we should use video mode (YouTube track + video mode enabled + mpv available)
         let use_video = track.service == ServiceType::YouTube
@@ -55,6 +87,8 @@ impl App {
             self.add_debug(format!("Add failed: {}", e));
             return Err(e);
         }
+        // Keep local_queue aligned with MPD positions
+        self.local_queue.push(track.clone());
 
         self.add_debug("Starting playback...".to_string());
         if let Err(e) = self.mpd_controller.play(&mut self.debug_log).await {
@@ -64,11 +98,51 @@ impl App {
 
         self.playback.is_playing = true;
         self.record_history(&track).await;
-        self.current_track = Some(track);
+        self.set_current_track(track);
         self.add_debug("Playback started".to_string());
         Ok(())
     }
 
+    /// Play the open album from track `start` to the end, replacing the queue
+    pub async fn play_album_from(&mut self, start: usize) -> Result<()> {
+        let Some(first) = self.album_detail.tracks.get(start).cloned() else {
+            return Ok(());
+        };
+        let rest = self.album_detail.tracks[start + 1..].to_vec();
+        let is_video = first.service == ServiceType::YouTube
+            && self.playback.video_mode
+            && self.video_controller.is_some();
+
+        self.play_track(first).await?;
+        // mpv plays a single video; there's no MPD queue to extend
+        if is_video || rest.is_empty() {
+            return Ok(());
+        }
+
+        let mut added = 0;
+        for track in &rest {
+            match self.resolve_play_url(track).await {
+                Ok(Some(url)) => {
+                    if let Err(e) = self.mpd_controller.add_track(&url, &mut self.debug_log).await {
+                        self.add_debug(format!("Failed to add {}: {}", track.title, e));
+                    } else {
+                        self.local_queue.push(track.clone());
+                        added += 1;
+                    }
+                }
+                Ok(None) => {} // offline, not downloaded
+                Err(e) => {
+                    self.add_debug(format!("Failed to get URL for {}: {}", track.title, e));
+                }
+            }
+        }
+
+        self.add_debug(format!("Queued {}/{} following album tracks", added, rest.len()));
+        self.playback.queue_dirty = true;
+        self.refresh_queue_after_edit().await;
+        Ok(())
+    }
+
     /// Play a YouTube track using mpv video player
     async fn play_track_video(&mut self, track: Track) -> Result<()> {
         self.add_debug(format!("Playing video: {} - {}", track.artist, track.title));
@@ -88,45 +162,47 @@ impl App {
 
         self.playback.is_playing = true;
         self.record_history(&track).await;
-        self.current_track = Some(track);
+        self.set_current_track(track);
         self.add_debug("Video playback started in mpv".to_string());
         Ok(())
     }
 
-    pub async fn play_selected_track(&mut self) -> Result<()> {
-        let track = match self.view_mode {
-            ViewMode::Browse => {
-                if self.browse.selected_track < self.tracks.len() {
-                    self.tracks[self.browse.selected_track].clone()
-                } else {
-                    return Ok(());
-                }
-            }
-            ViewMode::Search => {
-                if let Some(ref results) = self.search_results {
-                    if self.search.tab == SearchTab::Tracks && self.search.selected_track < results.tracks.len() {
-                        results.tracks[self.search.selected_track].clone()
-                    } else {
-                        return Ok(());
-                    }
-                } else {
-                    return Ok(());
-                }
-            }
-            ViewMode::Library => {
-                match self.library.tab {
-                    LibraryTab::Tracks if self.library.selected_track < self.favorite_tracks.len() => {
-                        self.favorite_tracks[self.library.selected_track].clone()
-                    }
-                    LibraryTab::History if self.library.selected_history < self.history_entries.len() => {
-                        Track::from(&self.history_entries[self.library.selected_history])
-                    }
-                    _ => return Ok(()),
-                }
+    /// Make `track` the current track, remembering the one it replaces
+    pub fn set_current_track(&mut self, track: Track) {
+        if self.current_track.as_ref().is_none_or(|previous| previous.id != track.id) {
+            self.enrich_track(&track);
+        }
+        if let Some(previous) = self.current_track.take() {
+            if previous.id != track.id {
+                let recent = &mut self.playback.recently_played;
+                recent.retain(|t| t.id != previous.id);
+                recent.push_front(previous);
+                recent.truncate(RECENTLY_PLAYED_MAX);
             }
-            ViewMode::Downloads | ViewMode::ArtistDetail | ViewMode::AlbumDetail => return Ok(()),
-        };
+        }
+        self.current_track = Some(track);
+    }
+
+    /// Play one random track from the queue (when shown) or the current list
+    pub async fn play_random_track(&mut self) -> Result<()> {
+        use rand::Rng;
+
+        if self.playback.show_queue && !self.local_queue.is_empty() {
+            let position = self.rng.gen_range(0..self.local_queue.len());
+            let title = self.local_queue[position].title.clone();
+            self.playback.selected_queue_item = position;
+            self.mpd_controller.play_position(position, &mut self.debug_log).await?;
+            self.set_status_info(format!("Surprise: '{}'", title));
+            return Ok(());
+        }
 
+        let available: Vec<bool> = self.visible_tracks().iter().map(|t| t.available).collect();
+        let Some(index) = pick_playable(&available, &mut self.rng) else {
+            self.set_status_error("No tracks here to pick from".to_string());
+            return Ok(());
+        };
+        let track = self.visible_tracks()[index].clone();
+        self.set_status_info(format!("Surprise: '{}'", track.title));
         self.play_track(track).await
     }
 
@@ -150,6 +226,116 @@ impl App {
         Ok(())
     }
 
+    /// Turn MPD's crossfade off, or on at `playback.crossfade_seconds`.
+    /// Does nothing but warn when there's no audio output to mix on.
+    pub async fn toggle_crossfade(&mut self) {
+        let current = match self.mpd_controller.crossfade().await {
+            Ok(seconds) => seconds,
+            Err(e) => {
+                self.set_status_error(format!("Couldn't read crossfade: {}", e));
+                return;
+            }
+        };
+        let target = match (current, self.config.playback.crossfade_seconds) {
+            (0, 0) => DEFAULT_CROSSFADE_SECS,
+            (0, seconds) => seconds,
+            _ => 0,
+        };
+
+        if target > 0 && !self.mpd_controller.has_enabled_output().await.unwrap_or(true) {
+            self.set_status_error("Crossfade unavailable: MPD has no enabled audio output".to_string());
+            return;
+        }
+        if let Err(e) = self.mpd_controller.set_crossfade(target, &mut self.debug_log).await {
+            self.set_status_error(format!("Crossfade unavailable: {}", e));
+            return;
+        }
+
+        match self.mpd_controller.crossfade().await {
+            Ok(0) => self.set_status_info("Crossfade off".to_string()),
+            // MPD fades only between tracks sharing an audio format, which
+            // streams from different services or qualities often don't
+            Ok(seconds) => self.set_status_info(format!(
+                "Crossfade {}s (tracks in different stream formats won't fade)",
+                seconds
+            )),
+            Err(e) => self.add_debug(format!("Couldn't confirm crossfade: {}", e)),
+        }
+    }
+
+    /// Go to the previous track, or restart the current one if it has
+    /// played past `playback.previous_restart_threshold` seconds
+    pub async fn previous_track(&mut self) -> Result<()> {
+        let threshold = self.config.playback.previous_restart_threshold;
+        let elapsed = self.current_song.as_ref().map_or(0, |s| s.elapsed.as_secs());
+
+        if threshold > 0 && elapsed > threshold {
+            return self.restart_current_track().await;
+        }
+
+        self.add_debug("Previous track".to_string());
+        self.mpd_controller.previous(&mut self.debug_log).await
+    }
+
+    /// Seek the current track back to 0:00
+    pub async fn restart_current_track(&mut self) -> Result<()> {
+        let Some(title) = self.current_track.as_ref().map(|t| t.title.clone()) else {
+            self.set_status_info("Nothing is playing".to_string());
+            return Ok(());
+        };
+
+        let using_video = self.playback.video_mode
+            && self.video_controller.as_mut().is_some_and(|m| m.is_running());
+
+        if using_video {
+            if let Some(ref mut mpv) = self.video_controller {
+                mpv.seek_to(0.0, &mut self.debug_log).await?;
+            }
+        } else {
+            self.mpd_controller.seek_to(0, &mut self.debug_log).await?;
+        }
+
+        self.set_status_info(format!("Restarted '{}'", title));
+        Ok(())
+    }
+
+    /// Switch MPD to `mode`, setting its repeat and single flags together
+    pub async fn set_repeat_mode(&mut self, mode: RepeatMode) {
+        match self.mpd_controller.set_repeat_mode(mode, &mut self.debug_log).await {
+            Ok(()) => {
+                self.playback.repeat_mode = mode;
+                self.set_status_info(format!("Repeat: {}", mode.label().unwrap_or("off")));
+            }
+            Err(e) => self.set_status_error(format!("Repeat mode error: {}", e)),
+        }
+    }
+
+    /// Seek within the current track, showing the new position right away
+    /// instead of on the next status poll
+    pub async fn seek_by(&mut self, seconds: i64) {
+        match self.mpd_controller.seek(seconds, &mut self.debug_log).await {
+            Ok(Some(elapsed)) => {
+                if let Some(ref mut song) = self.current_song {
+                    song.elapsed = elapsed.min(song.duration);
+                }
+            }
+            Ok(None) => self.add_debug("Seeked past the end, skipping to next track".to_string()),
+            Err(e) => self.set_status_error(format!("Seek error: {}", e)),
+        }
+    }
+
+    /// Turn shuffle on or off, from the last state read back from MPD
+    pub async fn toggle_shuffle(&mut self) {
+        let on = !self.playback.random_mode;
+        match self.mpd_controller.set_random(on, &mut self.debug_log).await {
+            Ok(()) => {
+                self.playback.random_mode = on;
+                self.set_status_info(format!("Shuffle: {}", if on { "on" } else { "off" }));
+            }
+            Err(e) => self.set_status_error(format!("Shuffle toggle error: {}", e)),
+        }
+    }
+
     pub async fn check_mpd_status(&mut self) -> Result<()> {
         // Check if we're in video mode with mpv running
         let using_video = self.playback.video_mode
@@ -173,11 +359,16 @@ impl App {
                     }
                 }
             }
+            self.submit_pending_listen();
+            self.update_nowplaying_file();
             return Ok(());
         }
 
         // Standard MPD status check
         let status = self.mpd_controller.get_status(&mut self.debug_log).await?;
+        if self.mpd_controller.take_reconnected() {
+            self.resync_after_reconnect().await;
+        }
 
         if status.is_playing != self.playback.is_playing {
             self.playback.is_playing = status.is_playing;
@@ -185,11 +376,52 @@ impl App {
         }
 
         if let Some(vol) = status.volume {
+            // Set by another client (or the mixer): take it as the new
+            // volume with the current gain already in it
+            if vol != self.playback.volume && self.playback.sleep_fade_volume.is_none() {
+                self.playback.base_volume = with_gain(vol, -self.playback.applied_gain_db);
+            }
             self.playback.volume = vol;
         }
-        self.playback.repeat_mode = status.repeat;
+        self.playback.repeat_mode = status.repeat_mode();
         self.playback.random_mode = status.random;
-        self.playback.single_mode = status.single;
+
+        match status.error {
+            Some(error) if self.playback.last_error.as_ref() != Some(&error) => {
+                self.playback.last_error = Some(error.clone());
+                self.handle_playback_failure(error).await;
+            }
+            Some(_) => {}
+            None => self.playback.last_error = None,
+        }
+
+        if !status.is_stopped {
+            self.playback.stopped_since = None;
+        } else if self.playback.stopped_since.is_none() {
+            self.playback.stopped_since = Some(Instant::now());
+        } else if self.config.playback.clear_on_stop
+            && self.current_track.is_some()
+            && self.playback.stopped_since.is_some_and(|t| t.elapsed() >= STOP_CONFIRM_DELAY)
+        {
+            self.add_debug("Playback stopped".to_string());
+            self.current_track = None;
+            self.current_song = None;
+            self.playback.queue_position = None;
+        }
+
+        // Follow MPD as it advances through the queue
+        if let Ok(Some((pos, _))) = self.mpd_controller.get_playback_position().await {
+            if self.playback.queue_position != Some(pos) {
+                self.playback.queue_position = Some(pos);
+                if let Some(track) = self.local_queue.get(pos).cloned() {
+                    if self.current_track.as_ref().map(|t| t.id.as_str()) != Some(track.id.as_str()) {
+                        self.set_current_track(track);
+                    }
+                }
+            }
+        }
+        self.check_current_file().await;
+        self.apply_service_gain().await;
 
         if let Some(ref track) = self.current_track {
             match self.mpd_controller.get_timing_info().await {
@@ -214,15 +446,16 @@ impl App {
                     self.add_debug(format!("Failed to get timing info: {}", e));
                 }
             }
-        } else if self.current_song.is_some() {
-            self.current_song = None;
+        } else {
+            // Nothing Drift queued is playing; show whatever MPD reports,
+            // e.g. a song another client added
+            self.current_song = self.mpd_controller.get_current_song().await.ok().flatten();
         }
+        self.submit_pending_listen();
+        self.update_nowplaying_file();
+        self.prefetch_next_stream().await;
 
-        if self.playback.show_queue {
-            if let Ok(queue) = self.mpd_controller.get_queue().await {
-                self.queue = queue;
-            }
-        }
+        self.poll_queue().await;
 
         // Check if we need to add radio tracks
         self.check_radio_queue().await;
@@ -230,6 +463,420 @@ impl App {
         Ok(())
     }
 
+    /// As the current track nears its end, make sure MPD's next entry is a
+    /// stream URL that still works, so MPD can open it ahead of time and
+    /// move on without a gap. URLs resolved more than a few minutes ago
+    /// (queued long before, or held over a pause) are re-resolved and
+    /// swapped in.
+    async fn prefetch_next_stream(&mut self) {
+        // With random or repeat-one, MPD's next song isn't the next entry
+        if !self.playback.is_playing || self.playback.random_mode || self.playback.repeat_mode == RepeatMode::One {
+            return;
+        }
+        let Some(ref song) = self.current_song else {
+            return;
+        };
+        if song.duration.is_zero() || song.duration.saturating_sub(song.elapsed) > PREFETCH_LEAD {
+            return;
+        }
+
+        let Some(next) = self.playback.queue_position.map(|pos| pos + 1) else {
+            return;
+        };
+        let (Some(track), Some(file)) = (self.local_queue.get(next).cloned(), self.mpd_controller.queued_file(next)) else {
+            return;
+        };
+        // Local files don't expire
+        if !file.starts_with("http") {
+            return;
+        }
+        let file = file.to_string();
+        if self.playback.stream_resolved_at.get(&file).is_some_and(|at| at.elapsed() < STREAM_URL_MAX_AGE) {
+            return;
+        }
+        // Succeed or fail, don't retry this entry every poll
+        self.playback.stream_resolved_at.insert(file.clone(), Instant::now());
+
+        let url = match self.resolve_play_url(&track).await {
+            Ok(Some(url)) if url != file => url,
+            Ok(_) => return,
+            Err(e) => {
+                self.add_debug(format!("Could not refresh stream for '{}': {}", track.title, e));
+                return;
+            }
+        };
+        match self.mpd_controller.replace_next(next, &url, &mut self.debug_log).await {
+            Ok(()) => {
+                self.playback.stream_resolved_at.remove(&file);
+                self.add_debug(format!("Refreshed stream URL for next track '{}'", track.title));
+                self.refresh_queue_after_edit().await;
+            }
+            Err(e) => self.add_debug(format!("Could not swap in fresh stream for '{}': {}", track.title, e)),
+        }
+    }
+
+    /// Developer command (`debug.enabled`): fetch the playing track's stream
+    /// URL, copy it for testing in mpv/ffplay, and log it with credentials
+    /// masked so the log can go in a bug report
+    pub async fn copy_stream_url(&mut self) {
+        let Some(track) = self.current_track.clone() else {
+            self.set_status_info("Nothing playing".to_string());
+            return;
+        };
+
+        match self.music_service.get_stream_url_for_track(&track).await {
+            Ok(url) => {
+                let masked = crate::service::mask_url_credentials(&url);
+                self.add_debug(format!("Stream URL for '{}' ({}): {}", track.title, track.service, masked));
+                match crate::clipboard::copy(&url) {
+                    Ok(via) => {
+                        self.add_debug(format!("Stream URL copied via {}", via));
+                        if masked != url {
+                            self.set_status_info("Copied stream URL (it holds credentials, don't share it)".to_string());
+                        } else {
+                            self.set_status_info("Copied stream URL".to_string());
+                        }
+                    }
+                    Err(e) => self.set_status_error(format!("Copy failed: {}", e)),
+                }
+            }
+            Err(e) => {
+                self.add_debug(format!("Stream URL for '{}' failed: {:#}", track.title, e));
+                self.set_status_error(format!("Could not get stream URL: {}", e));
+            }
+        }
+    }
+
+    /// Submit the current track's listen once it has played long enough,
+    /// dropping it if playback moved on first
+    fn submit_pending_listen(&mut self) {
+        let Some(ref entry) = self.playback.pending_listen else {
+            return;
+        };
+        if self.current_track.as_ref().map(|t| t.id.as_str()) != Some(entry.track_id.as_str()) {
+            self.playback.pending_listen = None;
+            return;
+        }
+        let elapsed = self.current_song.as_ref().map_or(0, |s| s.elapsed.as_secs());
+        if elapsed < u64::from(listen_threshold_secs(entry.duration_seconds)) {
+            return;
+        }
+
+        let (Some(entry), Some(scrobbler)) = (self.playback.pending_listen.take(), self.scrobbler.clone()) else {
+            return;
+        };
+        self.add_debug(format!("Submitting listen '{}' to {}", entry.title, scrobbler.name()));
+        tokio::spawn(async move {
+            if let Err(e) = scrobbler.submit_listen(&entry).await {
+                tracing::warn!("{} listen submission failed: {}", scrobbler.name(), e);
+            }
+        });
+    }
+
+    /// Keep `integrations.nowplaying_file` showing the current track, or
+    /// `nowplaying_idle` once stopped. Only rewritten when the text changes.
+    fn update_nowplaying_file(&mut self) {
+        let integrations = &self.config.integrations;
+        let Some(ref path) = integrations.nowplaying_file else {
+            return;
+        };
+        let text = match self.current_track {
+            Some(ref track) if self.playback.stopped_since.is_none() => {
+                nowplaying::format_track(&integrations.nowplaying_format, track)
+            }
+            _ => integrations.nowplaying_idle.clone(),
+        };
+        let written = (path.clone(), text);
+        if self.playback.nowplaying_written.as_ref() == Some(&written) {
+            return;
+        }
+
+        // Recorded even on failure, so a bad path is reported once per change
+        if let Err(e) = nowplaying::write_atomic(std::path::Path::new(&written.0), &written.1) {
+            self.add_debug(format!("Failed to write now playing file: {:#}", e));
+        }
+        self.playback.nowplaying_written = Some(written);
+    }
+
+    /// Set MPD's volume to the user's volume with the playing track's
+    /// `playback.service_gain_db`, so loudness stays even across services
+    async fn apply_service_gain(&mut self) {
+        let Some(service) = self.current_track.as_ref().map(|t| t.service) else {
+            return;
+        };
+        let gain_db = self.config.playback.gain_db_for(service);
+        if gain_db == self.playback.applied_gain_db {
+            return;
+        }
+
+        let volume = with_gain(self.playback.base_volume, gain_db);
+        self.add_debug(format!("{} gain {:+.1} dB: volume {}% -> {}%", service, gain_db, self.playback.base_volume, volume));
+        // Recorded even on failure, so a mixer-less MPD isn't retried every tick
+        self.playback.applied_gain_db = gain_db;
+        match self.mpd_controller.set_volume(volume, &mut self.debug_log).await {
+            Ok(()) => self.playback.volume = volume,
+            Err(e) => self.add_debug(format!("Failed to apply service gain: {}", e)),
+        }
+    }
+
+    /// Move the user's volume by `delta` points and set MPD's from it
+    pub async fn change_volume(&mut self, delta: i16) {
+        let base = (self.playback.base_volume as i16 + delta).clamp(0, 100) as u8;
+        let volume = with_gain(base, self.playback.applied_gain_db);
+        match self.mpd_controller.set_volume(volume, &mut self.debug_log).await {
+            Ok(()) => {
+                self.playback.base_volume = base;
+                self.playback.volume = volume;
+            }
+            Err(e) => self.set_status_error(format!("Volume error: {}", e)),
+        }
+    }
+
+    /// Ask how many minutes until the sleep timer pauses playback, filled in
+    /// with what's left if it's already running
+    pub fn open_sleep_timer_dialog(&mut self) {
+        self.dialog.mode = DialogMode::SleepTimer;
+        self.dialog.input_text = self
+            .sleep_timer_remaining()
+            .map(|left| left.as_secs().div_ceil(60).to_string())
+            .unwrap_or_default();
+    }
+
+    /// Start the sleep timer with the dialog's minutes; 0 or nothing
+    /// cancels it
+    pub async fn set_sleep_timer_from_dialog(&mut self) {
+        let input = self.dialog.input_text.trim();
+        let minutes = if input.is_empty() {
+            0
+        } else {
+            match input.parse::<u64>() {
+                Ok(minutes) if minutes <= SLEEP_TIMER_MAX_MINUTES => minutes,
+                Ok(_) => {
+                    self.set_status_error(format!("Sleep timer is limited to {} min", SLEEP_TIMER_MAX_MINUTES));
+                    return;
+                }
+                Err(_) => {
+                    self.set_status_error(format!("'{}' isn't a number of minutes", input));
+                    return;
+                }
+            }
+        };
+        self.close_dialog();
+        // Restarting mid-fade starts over from the volume it faded from
+        self.restore_sleep_fade_volume().await;
+
+        if minutes == 0 {
+            if self.playback.sleep_timer.take().is_some() {
+                self.set_status_info("Sleep timer cancelled".to_string());
+            }
+            return;
+        }
+        self.playback.sleep_timer = Some(Instant::now() + Duration::from_secs(minutes * 60));
+        self.add_debug(format!("Sleep timer set for {} min", minutes));
+        self.set_status_info(format!("Pausing in {} min", minutes));
+    }
+
+    /// Time left on the sleep timer, if it's running
+    pub fn sleep_timer_remaining(&self) -> Option<Duration> {
+        self.playback
+            .sleep_timer
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Pause once the sleep timer runs out, first fading the volume over its
+    /// last `playback.sleep_fade_seconds`
+    pub async fn check_sleep_timer(&mut self) {
+        let Some(remaining) = self.sleep_timer_remaining() else {
+            return;
+        };
+
+        if remaining.is_zero() {
+            self.playback.sleep_timer = None;
+            if self.playback.is_playing {
+                self.add_debug("Sleep timer: pausing playback".to_string());
+                if let Err(e) = self.toggle_playback().await {
+                    self.set_status_error(format!("Sleep timer couldn't pause: {}", e));
+                    return;
+                }
+            }
+            // Back to where it was, so the next play isn't silent
+            self.restore_sleep_fade_volume().await;
+            self.set_status_info("Sleep timer: playback paused".to_string());
+            return;
+        }
+
+        // mpv keeps its own volume, so video just pauses at the deadline
+        let fade = Duration::from_secs(self.config.playback.sleep_fade_seconds);
+        if remaining > fade || !self.playback.is_playing || self.playback.video_mode {
+            return;
+        }
+        let from = *self.playback.sleep_fade_volume.get_or_insert(self.playback.volume);
+        let volume = (from as f64 * remaining.as_secs_f64() / fade.as_secs_f64()).round() as u8;
+        if volume < self.playback.volume {
+            match self.mpd_controller.set_volume(volume, &mut self.debug_log).await {
+                Ok(()) => self.playback.volume = volume,
+                Err(e) => self.add_debug(format!("Sleep timer fade failed: {}", e)),
+            }
+        }
+    }
+
+    async fn restore_sleep_fade_volume(&mut self) {
+        let Some(volume) = self.playback.sleep_fade_volume.take() else {
+            return;
+        };
+        match self.mpd_controller.set_volume(volume, &mut self.debug_log).await {
+            Ok(()) => self.playback.volume = volume,
+            Err(e) => self.add_debug(format!("Failed to restore volume after fade: {}", e)),
+        }
+    }
+
+    /// Compare the file MPD is playing with the one Drift queued at that
+    /// position. They drift apart when another MPD client reorders the
+    /// queue, adds to it or jumps around; the current track is then
+    /// re-derived from what MPD is actually playing.
+    async fn check_current_file(&mut self) {
+        if self.playback.desync_checked_at.is_some_and(|t| t.elapsed() < DESYNC_CHECK_INTERVAL) {
+            return;
+        }
+        self.playback.desync_checked_at = Some(Instant::now());
+
+        // Queues Drift didn't build this session (e.g. left over in MPD from
+        // before startup) have nothing to compare against
+        if !self.mpd_controller.has_queued_files() {
+            return;
+        }
+        let (Some(pos), Ok(Some(file))) = (
+            self.playback.queue_position,
+            self.mpd_controller.get_current_file().await,
+        ) else {
+            return;
+        };
+        if self.mpd_controller.queued_file(pos) == Some(file.as_str()) {
+            self.playback.desynced_file = None;
+            return;
+        }
+        if self.playback.desynced_file.as_ref() == Some(&file) {
+            return;
+        }
+
+        self.add_debug(format!("MPD's song at #{} isn't the one Drift queued there, resyncing", pos + 1));
+        match self.mpd_controller.queued_position(&file).and_then(|i| self.local_queue.get(i).cloned()) {
+            Some(track) => {
+                if self.current_track.as_ref().map(|t| t.id.as_str()) != Some(track.id.as_str()) {
+                    self.set_current_track(track);
+                }
+            }
+            // Queued by another client; current_song falls back to MPD's tags
+            None => self.current_track = None,
+        }
+        self.playback.desynced_file = Some(file);
+        self.refresh_queue().await;
+    }
+
+    /// React to a track MPD couldn't play, per `playback.on_failure`
+    async fn handle_playback_failure(&mut self, error: String) {
+        let failed = self
+            .playback
+            .queue_position
+            .and_then(|pos| self.local_queue.get(pos))
+            .or(self.current_track.as_ref())
+            .map(|t| t.title.clone())
+            .unwrap_or_else(|| "track".to_string());
+        self.add_debug(format!("Playback failed for '{}': {}", failed, error));
+
+        if self.config.playback.on_failure == "stop" {
+            if let Err(e) = self.mpd_controller.stop(&mut self.debug_log).await {
+                self.add_debug(format!("Stop failed: {}", e));
+            }
+            self.playback.is_playing = false;
+            self.set_status_error(format!("Stopped: '{}' failed to play ({})", failed, error));
+            return;
+        }
+
+        // MPD clears its error when playback moves on
+        if let Err(e) = self.mpd_controller.next(&mut self.debug_log).await {
+            self.set_status_error(format!("'{}' failed and skipping failed: {}", failed, e));
+            return;
+        }
+        if let Err(e) = self.mpd_controller.play(&mut self.debug_log).await {
+            self.add_debug(format!("Play failed: {}", e));
+        }
+        self.set_status_error(format!("Skipped '{}': {}", failed, error));
+    }
+
+    /// Start "My Station", an endless radio seeded from favorites
+    pub async fn start_favorites_station(&mut self) {
+        if !self.library.loaded {
+            self.load_favorites().await;
+        }
+        if self.favorite_tracks.is_empty() && self.favorite_artists.is_empty() {
+            self.set_status_error("My Station needs favorite tracks or artists".to_string());
+            return;
+        }
+
+        self.playback.radio_seed = Some(RadioSeed::Favorites);
+        self.add_debug("My Station ON".to_string());
+
+        let start = self.local_queue.len();
+        self.top_up_radio().await;
+        let added = self.local_queue.len() - start;
+        if added == 0 {
+            self.set_status_error("My Station: no tracks found".to_string());
+            return;
+        }
+
+        if !self.playback.is_playing {
+            if let Err(e) = self.mpd_controller.play_position(start, &mut self.debug_log).await {
+                self.add_debug(format!("My Station: play failed: {}", e));
+            }
+        }
+        self.set_status_info(format!("My Station: queued {} tracks from your favorites", added));
+    }
+
+    /// Radio tracks for "My Station": a few random favorites as seeds, their
+    /// radios interleaved so no single seed dominates, duplicates dropped
+    async fn fetch_favorites_station(&mut self) -> Vec<Track> {
+        use rand::seq::SliceRandom;
+
+        let mut seeds: Vec<RadioSeed> = self.favorite_tracks.iter()
+            .map(|t| RadioSeed::Track(t.id.clone()))
+            .chain(self.favorite_artists.iter().map(|a| RadioSeed::Artist(a.id.clone())))
+            .collect();
+        seeds.shuffle(&mut self.rng);
+        seeds.truncate(STATION_SEEDS);
+
+        let mut batches = Vec::new();
+        for seed in seeds {
+            let result = match seed {
+                RadioSeed::Track(ref id) => self.music_service.get_track_radio(id, 10).await,
+                RadioSeed::Artist(ref id) => self.music_service.get_artist_radio(id, 10).await,
+                _ => continue,
+            };
+            match result {
+                Ok(tracks) => batches.push(tracks.into_iter()),
+                Err(e) => self.add_debug(format!("My Station: seed fetch failed: {}", e)),
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        let mut tracks = Vec::new();
+        loop {
+            let mut any = false;
+            for batch in batches.iter_mut() {
+                if let Some(track) = batch.next() {
+                    any = true;
+                    if seen.insert(track.id.clone()) {
+                        tracks.push(track);
+                    }
+                }
+            }
+            if !any {
+                break;
+            }
+        }
+        tracks
+    }
+
     pub async fn check_radio_queue(&mut self) {
         // Skip if radio mode is off or we're already fetching
         if self.playback.radio_seed.is_none() || self.playback.radio_fetching {
@@ -250,6 +897,11 @@ impl App {
             return;
         }
 
+        self.top_up_radio().await;
+    }
+
+    /// Fetch tracks for the current radio seed and append the new ones
+    async fn top_up_radio(&mut self) {
         self.playback.radio_fetching = true;
 
         // Clone the seed to avoid borrow issues
@@ -324,6 +976,10 @@ impl App {
                     }
                 }
             }
+            RadioSeed::Favorites => {
+                self.add_debug("My Station: fetching tracks from favorites".to_string());
+                self.fetch_favorites_station().await
+            }
         };
 
         if radio_tracks.is_empty() {
@@ -367,8 +1023,51 @@ impl App {
         if added > 0 {
             self.add_debug(format!("Radio: added {} tracks to queue", added));
             self.playback.queue_dirty = true;
+            self.refresh_queue_after_edit().await;
         }
 
         self.playback.radio_fetching = false;
     }
 }
+
+/// Index of a random streamable track for Space+s, given each track's
+/// `available` flag
+fn pick_playable(available: &[bool], rng: &mut impl rand::Rng) -> Option<usize> {
+    let playable: Vec<usize> = available.iter()
+        .enumerate()
+        .filter(|(_, &a)| a)
+        .map(|(i, _)| i)
+        .collect();
+    if playable.is_empty() {
+        return None;
+    }
+    Some(playable[rng.gen_range(0..playable.len())])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_pick_playable_skips_unavailable() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            assert_eq!(pick_playable(&[false, true, false], &mut rng), Some(1));
+        }
+        assert_eq!(pick_playable(&[false], &mut rng), None);
+        assert_eq!(pick_playable(&[], &mut rng), None);
+    }
+
+    #[test]
+    fn test_pick_playable_is_seeded() {
+        let available = [true; 50];
+        let picks = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..10).map(|_| pick_playable(&available, &mut rng).unwrap()).collect::<Vec<_>>()
+        };
+        assert_eq!(picks(42), picks(42));
+        assert!(picks(42).iter().all(|&i| i < available.len()));
+    }
+}
@@ -1,13 +1,67 @@
 use anyhow::Result;
+use futures_util::StreamExt;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, error::TryRecvError};
+use tokio::sync::oneshot;
 
 use super::App;
-use super::state::ViewMode;
+use super::state::{BulkEnqueue, DiscographyFetch, EnqueueEvent, FetchedAlbum, SelectedItem, TrackOrigin, ViewMode};
+use crate::playlist_view_state::TrackSort;
 use crate::queue_persistence::PersistedQueue;
-use crate::service::{MusicService, Track};
+use crate::service::{Album, MultiServiceManager, MusicService, Track};
+use crate::tasks::TaskHandle;
+use crate::ui::queue_as_text;
 use crate::ui::search::SearchTab;
 
+/// How often the queue panel is re-read from MPD while visible
+const QUEUE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the queue is re-saved while playing, so a crash or power loss
+/// resumes close to where playback was
+const POSITION_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Albums fetched at once when queuing an artist's discography
+const ALBUM_FETCH_CONCURRENCY: usize = 4;
+
 impl App {
+    /// Re-read the MPD queue into `self.queue`
+    pub async fn refresh_queue(&mut self) {
+        match self.mpd_controller.get_queue().await {
+            Ok(queue) => {
+                self.queue = queue;
+                self.playback.queue_refreshed_at = Some(Instant::now());
+            }
+            Err(e) => self.add_debug(format!("Failed to refresh queue: {}", e)),
+        }
+    }
+
+    /// Refresh right after a queue edit so the panel isn't stale until the
+    /// next poll (`ui.refresh_queue_on_edit`)
+    pub async fn refresh_queue_after_edit(&mut self) {
+        if self.config.ui.refresh_queue_on_edit {
+            self.refresh_queue().await;
+        }
+    }
+
+    /// Periodic refresh while the queue panel is shown, skipped if an edit
+    /// already refreshed it within the poll interval
+    pub async fn poll_queue(&mut self) {
+        let fresh = self.playback.queue_refreshed_at
+            .is_some_and(|t| t.elapsed() < QUEUE_POLL_INTERVAL);
+        if self.playback.show_queue && !fresh {
+            self.refresh_queue().await;
+        }
+    }
+
+    /// Whether the playing position is due to be saved again
+    pub fn position_save_due(&self) -> bool {
+        self.playback.is_playing
+            && !self.local_queue.is_empty()
+            && self.playback.queue_saved_at.is_none_or(|t| t.elapsed() >= POSITION_SAVE_INTERVAL)
+    }
+
     pub async fn save_queue_state(&mut self) {
+        self.playback.queue_saved_at = Some(Instant::now());
         if self.local_queue.is_empty() {
             let persisted = PersistedQueue::new();
             if let Err(e) = self.storage.save_queue(&persisted).await {
@@ -54,40 +108,57 @@ impl App {
             return;
         }
 
-        let mut added = 0;
-        let mut local_count = 0;
-        for pt in &persisted.tracks {
-            let track = Track::from(pt);
-            match self.resolve_play_url(&track).await {
-                Ok(Some(url)) => {
-                    let is_local = url.starts_with('/');
-                    if let Err(e) = self.mpd_controller.add_track(&url, &mut self.debug_log).await {
-                        self.add_debug(format!("Failed to add track {}: {}", track.title, e));
-                    } else {
-                        added += 1;
-                        if is_local { local_count += 1; }
-                    }
-                }
-                Ok(None) => {
-                    // Offline and not downloaded — skip silently
-                }
+        // The track playback resumes on is resolved last, so its stream URL
+        // is still fresh when it plays however long the rest took
+        let tracks: Vec<Track> = persisted.tracks.iter().map(Track::from).collect();
+        let resume = persisted.current_position.filter(|&pos| pos < tracks.len());
+        let mut urls: Vec<Option<String>> = vec![None; tracks.len()];
+        for i in (0..tracks.len()).filter(|&i| Some(i) != resume).chain(resume) {
+            match self.resolve_play_url(&tracks[i]).await {
+                Ok(url) => urls[i] = url,
                 Err(e) => {
-                    self.add_debug(format!("Failed to resolve {}: {}", track.title, e));
+                    self.add_debug(format!("Failed to resolve {}: {}", tracks[i].title, e));
                 }
             }
         }
 
+        // Offline and not downloaded, or unresolvable: left out, with the
+        // local queue rebuilt so it stays aligned with MPD's positions
+        let total = tracks.len();
+        let mut restored = Vec::with_capacity(total);
+        let mut resume_at = None;
+        let mut local_count = 0;
+        for (i, (track, url)) in tracks.into_iter().zip(urls).enumerate() {
+            let Some(url) = url else { continue };
+            if let Err(e) = self.mpd_controller.add_track(&url, &mut self.debug_log).await {
+                self.add_debug(format!("Failed to add track {}: {}", track.title, e));
+                continue;
+            }
+            if url.starts_with('/') {
+                local_count += 1;
+            }
+            if Some(i) == resume {
+                resume_at = Some(restored.len());
+            }
+            restored.push(track);
+        }
+        let added = restored.len();
+        if added < total {
+            self.playback.queue_dirty = true;
+        }
+        self.local_queue = restored;
+
         if local_count > 0 {
             self.add_debug(format!(
                 "Restored {}/{} tracks ({} local files, {} streamed)",
-                added, persisted.tracks.len(), local_count, added - local_count
+                added, total, local_count, added - local_count
             ));
         } else {
-            self.add_debug(format!("Restored {}/{} tracks to MPD", added, persisted.tracks.len()));
+            self.add_debug(format!("Restored {}/{} tracks to MPD", added, total));
         }
 
-        if let Some(pos) = persisted.current_position {
-            if pos < added {
+        match (resume, resume_at) {
+            (Some(_), Some(pos)) => {
                 self.add_debug(format!("Resuming from track {}", pos + 1));
                 if let Err(e) = self.mpd_controller.play_position(pos, &mut self.debug_log).await {
                     self.add_debug(format!("Failed to resume playback: {}", e));
@@ -100,11 +171,59 @@ impl App {
                     }
                 }
             }
+            (Some(pos), None) => {
+                self.add_debug(format!("Track {} couldn't be restored, not resuming", pos + 1));
+            }
+            _ => {}
+        }
+    }
+
+    /// Bring the queue and Now Playing back in line with MPD after it was
+    /// unreachable. If MPD restarted with an empty queue, the local queue is
+    /// re-added and playback resumes where it was; otherwise the local queue
+    /// is cut down to what MPD still has.
+    pub async fn resync_after_reconnect(&mut self) {
+        self.add_debug("Resyncing with MPD after reconnect".to_string());
+        self.refresh_queue().await;
+
+        if self.queue.is_empty() && !self.local_queue.is_empty() {
+            let elapsed = self.current_song.as_ref().map(|song| song.elapsed.as_secs() as u32);
+            let persisted = PersistedQueue::from_tracks(&self.local_queue, self.playback.queue_position, elapsed);
+            self.restore_queue(persisted).await;
+            self.refresh_queue().await;
+        }
+        if self.local_queue.len() > self.queue.len() {
+            self.add_debug(format!(
+                "MPD has {} of {} queued tracks, dropping the rest",
+                self.queue.len(),
+                self.local_queue.len()
+            ));
+            self.local_queue.truncate(self.queue.len());
+            self.playback.queue_dirty = true;
+        }
+        self.playback.selected_queue_item = self
+            .playback
+            .selected_queue_item
+            .min(self.queue.len().saturating_sub(1));
+
+        let position = self.mpd_controller.get_playback_position().await.ok().flatten();
+        self.playback.queue_position = position.map(|(pos, _)| pos);
+        match position.and_then(|(pos, _)| self.local_queue.get(pos).cloned()) {
+            Some(track) => {
+                if self.current_track.as_ref().map(|t| t.id.as_str()) != Some(track.id.as_str()) {
+                    self.set_current_track(track);
+                }
+            }
+            None => {
+                self.current_track = None;
+                self.current_song = None;
+            }
         }
     }
 
     pub async fn add_track_to_queue(&mut self, track: Track) -> Result<()> {
         self.add_debug(format!("Adding to queue: {} - {}", track.artist, track.title));
+        self.remember_track_origins(std::slice::from_ref(&track));
 
         let play_url = match self.resolve_play_url(&track).await {
             Ok(Some(url)) => url,
@@ -127,10 +246,8 @@ impl App {
         self.add_debug(format!("Added to queue: {}", track.title));
         self.local_queue.push(track.clone());
 
-        if let Ok(queue) = self.mpd_controller.get_queue().await {
-            self.add_debug(format!("  Queue now has {} tracks", queue.len()));
-            self.queue = queue;
-        }
+        self.refresh_queue_after_edit().await;
+        self.add_debug(format!("  Queue now has {} tracks", self.queue.len()));
 
         if self.current_track.is_none() {
             self.current_track = Some(track);
@@ -151,24 +268,26 @@ impl App {
         Ok(())
     }
 
-    pub async fn add_selected_track_to_queue(&mut self) -> Result<()> {
-        let track = if self.view_mode == ViewMode::Browse {
-            if self.browse.selected_track < self.tracks.len() {
-                self.tracks[self.browse.selected_track].clone()
-            } else {
-                return Ok(());
-            }
-        } else if let Some(ref results) = self.search_results {
-            if self.search.tab == SearchTab::Tracks && self.search.selected_track < results.tracks.len() {
-                results.tracks[self.search.selected_track].clone()
-            } else {
-                return Ok(());
-            }
-        } else {
-            return Ok(());
+    /// Note which playlist `tracks` are being queued from, when they're
+    /// queued from a loaded playlist in Browse
+    pub(crate) fn remember_track_origins(&mut self, tracks: &[Track]) {
+        if self.view_mode != ViewMode::Browse || self.browse.selected_tab != 1 {
+            return;
+        }
+        let Some(ref playlist_id) = self.browse.loaded_playlist else {
+            return;
         };
 
-        self.add_track_to_queue(track).await
+        for track in tracks {
+            let Some(row) = self.tracks.iter().position(|t| t.id == track.id) else {
+                continue;
+            };
+            let index = self.browse.track_positions.get(row).copied().unwrap_or(row);
+            self.playback.track_origins.insert(track.id.clone(), TrackOrigin {
+                playlist_id: playlist_id.clone(),
+                index,
+            });
+        }
     }
 
     pub async fn add_all_tracks_to_queue(&mut self) -> Result<()> {
@@ -189,62 +308,115 @@ impl App {
         };
 
         self.add_debug(format!("Adding {} tracks to queue...", tracks_to_add.len()));
+        self.remember_track_origins(&tracks_to_add);
+        self.start_bulk_enqueue(tracks_to_add, None).await
+    }
+
+    /// Resolve `tracks` on a spawned task and add them as they're ready;
+    /// `note` is added to the final status message
+    async fn start_bulk_enqueue(&mut self, tracks_to_add: Vec<Track>, note: Option<String>) -> Result<()> {
+        if self.playback.bulk_enqueue.is_some() {
+            self.set_status_error("Still queuing the last batch of tracks".to_string());
+            return Ok(());
+        }
 
         let was_playing = self.mpd_controller.get_status(&mut self.debug_log).await?.is_playing;
+        let total = tracks_to_add.len();
+        let jobs: Vec<(Track, Option<String>)> = tracks_to_add
+            .into_iter()
+            .map(|track| {
+                let local_path = self.local_play_path(&track);
+                (track, local_path)
+            })
+            .collect();
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut service = self.music_service.clone();
+        service.set_background(true);
+        let offline = self.downloads.offline_mode;
+        let skip_unavailable = self.config.playback.skip_unavailable;
+        self.tasks.spawn(format!("Queuing {} tracks", total), move |task| {
+            resolve_for_enqueue(service, jobs, offline, skip_unavailable, tx, task)
+        });
+
+        self.playback.bulk_enqueue = Some(BulkEnqueue {
+            events: rx,
+            total,
+            done: 0,
+            added: 0,
+            was_playing,
+            note,
+        });
 
-        let mut added_count = 0;
-        for (i, track) in tracks_to_add.iter().enumerate() {
-            self.add_debug(format!("[{}/{}] {} - {}", i+1, tracks_to_add.len(), track.artist, track.title));
+        Ok(())
+    }
 
-            match self.resolve_play_url(track).await {
-                Ok(Some(url)) => {
+    /// Add the tracks a bulk enqueue's task has resolved since the last
+    /// main loop tick; its progress shows with the other background tasks
+    pub async fn process_bulk_enqueue(&mut self) {
+        let Some(mut job) = self.playback.bulk_enqueue.take() else {
+            return;
+        };
+
+        let mut finished = false;
+        let mut added_now = false;
+        loop {
+            match job.events.try_recv() {
+                Ok(EnqueueEvent::Resolved { track, url }) => {
+                    job.done += 1;
+                    self.add_debug(format!("[{}/{}] {} - {}", job.done, job.total, track.artist, track.title));
                     if let Err(e) = self.mpd_controller.add_track(&url, &mut self.debug_log).await {
                         self.add_debug(format!("  Failed to add: {}", e));
                     } else {
-                        self.local_queue.push(track.clone());
-                        added_count += 1;
+                        self.local_queue.push(track);
+                        job.added += 1;
+                        added_now = true;
                     }
                 }
-                Ok(None) => {} // offline, not downloaded — skip
-                Err(e) => {
-                    self.add_debug(format!("  Failed to get URL: {}", e));
+                Ok(EnqueueEvent::Skipped { track, reason }) => {
+                    job.done += 1;
+                    self.add_debug(format!("[{}/{}] Skipped {}: {}", job.done, job.total, track.title, reason));
+                }
+                Err(TryRecvError::Empty) => break,
+                // The task is done once it drops its sender
+                Err(TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
                 }
             }
         }
 
-        self.add_debug(format!("Added {}/{} tracks to queue", added_count, tracks_to_add.len()));
+        if added_now {
+            self.playback.queue_dirty = true;
+            self.refresh_queue_after_edit().await;
 
-        if let Ok(queue) = self.mpd_controller.get_queue().await {
-            self.queue = queue;
-            self.add_debug(format!("Queue now has {} total tracks", self.queue.len()));
+            if !job.was_playing {
+                self.add_debug("Starting playback...".to_string());
+                if let Err(e) = self.mpd_controller.play(&mut self.debug_log).await {
+                    self.add_debug(format!("Play failed: {}", e));
+                } else {
+                    self.playback.is_playing = true;
+                }
+                job.was_playing = true;
+            }
         }
 
-        if !was_playing && added_count > 0 {
-            self.add_debug("Starting playback...".to_string());
-            if let Err(e) = self.mpd_controller.play(&mut self.debug_log).await {
-                self.add_debug(format!("Play failed: {}", e));
-            } else {
-                self.playback.is_playing = true;
-            }
+        if !finished {
+            self.playback.bulk_enqueue = Some(job);
+            return;
         }
 
-        Ok(())
+        let mut msg = format!("Added {}/{} tracks to queue", job.added, job.total);
+        if let Some(note) = job.note {
+            msg = format!("{} ({})", msg, note);
+        }
+        self.add_debug(msg.clone());
+        self.set_status_info(msg);
     }
 
-    pub async fn add_album_to_queue(&mut self) -> Result<()> {
-        let album = if let Some(ref results) = self.search_results {
-            if self.search.tab == SearchTab::Albums && self.search.selected_album < results.albums.len() {
-                results.albums[self.search.selected_album].clone()
-            } else {
-                return Ok(());
-            }
-        } else {
-            return Ok(());
-        };
-
-        self.add_debug(format!("Fetching tracks for album: {} - {}", album.artist, album.title));
-
-        let tracks = match self.music_service.get_album_tracks(&album.id).await {
+    /// Add album tracks to queue by album ID (used from detail views)
+    pub async fn add_album_by_id(&mut self, album_id: &str) -> Result<()> {
+        let tracks = match self.music_service.get_album_tracks(album_id).await {
             Ok(t) => t,
             Err(e) => {
                 self.add_debug(format!("Failed to get album tracks: {}", e));
@@ -281,9 +453,7 @@ impl App {
 
         self.add_debug(format!("Added {}/{} tracks from album", added_count, tracks.len()));
 
-        if let Ok(queue) = self.mpd_controller.get_queue().await {
-            self.queue = queue;
-        }
+        self.refresh_queue_after_edit().await;
 
         if !was_playing && added_count > 0 {
             if let Err(e) = self.mpd_controller.play(&mut self.debug_log).await {
@@ -296,21 +466,14 @@ impl App {
         Ok(())
     }
 
-    /// Add album tracks to queue by album ID (used from detail views)
-    pub async fn add_album_by_id(&mut self, album_id: &str) -> Result<()> {
-        let tracks = match self.music_service.get_album_tracks(album_id).await {
-            Ok(t) => t,
-            Err(e) => {
-                self.add_debug(format!("Failed to get album tracks: {}", e));
-                return Ok(());
-            }
-        };
-
-        if tracks.is_empty() {
-            self.add_debug("No tracks found for album".to_string());
+    /// Add all tracks from album detail view to queue
+    pub async fn add_album_detail_tracks_to_queue(&mut self) -> Result<()> {
+        if self.album_detail.tracks.is_empty() {
+            self.add_debug("No tracks in album detail".to_string());
             return Ok(());
         }
 
+        let tracks = self.album_detail.tracks.clone();
         self.add_debug(format!("Adding {} tracks from album...", tracks.len()));
 
         let was_playing = self.mpd_controller.get_status(&mut self.debug_log).await?.is_playing;
@@ -333,11 +496,9 @@ impl App {
             }
         }
 
-        self.add_debug(format!("Added {}/{} tracks from album", added_count, tracks.len()));
+        self.add_debug(format!("Added {}/{} tracks", added_count, tracks.len()));
 
-        if let Ok(queue) = self.mpd_controller.get_queue().await {
-            self.queue = queue;
-        }
+        self.refresh_queue_after_edit().await;
 
         if !was_playing && added_count > 0 {
             if let Err(e) = self.mpd_controller.play(&mut self.debug_log).await {
@@ -350,61 +511,290 @@ impl App {
         Ok(())
     }
 
-    /// Add all tracks from album detail view to queue
-    pub async fn add_album_detail_tracks_to_queue(&mut self) -> Result<()> {
-        if self.album_detail.tracks.is_empty() {
-            self.add_debug("No tracks in album detail".to_string());
+    /// Queue the most recently played track (this session) to play next
+    pub async fn queue_previously_played(&mut self) -> Result<()> {
+        let Some(track) = self.playback.recently_played.front().cloned() else {
+            self.set_status_info("Nothing played yet this session".to_string());
             return Ok(());
+        };
+
+        let url = match self.resolve_play_url(&track).await? {
+            Some(url) => url,
+            None => {
+                self.set_status_error("Track not available offline".to_string());
+                return Ok(());
+            }
+        };
+        self.mpd_controller.add_track(&url, &mut self.debug_log).await?;
+        self.local_queue.push(track.clone());
+
+        // Move it from the end of the queue to just after the current track
+        let queue_len = self.mpd_controller.get_queue().await.map(|q| q.len()).unwrap_or(0);
+        if let Ok(Some((pos, _))) = self.mpd_controller.get_playback_position().await {
+            let last = queue_len.saturating_sub(1);
+            let target = pos + 1;
+            if target < last {
+                self.mpd_controller.move_in_queue(last, target, &mut self.debug_log).await?;
+                if let Some(t) = self.local_queue.pop() {
+                    let idx = target.min(self.local_queue.len());
+                    self.local_queue.insert(idx, t);
+                }
+            }
         }
 
-        let tracks = self.album_detail.tracks.clone();
-        self.add_debug(format!("Adding {} tracks from album...", tracks.len()));
+        self.refresh_queue_after_edit().await;
+        self.playback.queue_dirty = true;
+        self.set_status_info(format!("Queued '{}' to play next", track.title));
 
-        let was_playing = self.mpd_controller.get_status(&mut self.debug_log).await?.is_playing;
-        let mut added_count = 0;
+        if !self.playback.is_playing {
+            self.mpd_controller.play(&mut self.debug_log).await?;
+            self.playback.is_playing = true;
+        }
 
-        for track in &tracks {
-            match self.resolve_play_url(track).await {
-                Ok(Some(url)) => {
-                    if let Err(e) = self.mpd_controller.add_track(&url, &mut self.debug_log).await {
-                        self.add_debug(format!("Failed to add {}: {}", track.title, e));
-                    } else {
-                        self.local_queue.push(track.clone());
-                        added_count += 1;
+        Ok(())
+    }
+
+    /// Remove the selected track from the queue panel
+    pub async fn remove_selected_from_queue(&mut self) {
+        if self.playback.show_queue && !self.local_queue.is_empty()
+            && self.playback.selected_queue_item < self.local_queue.len() {
+                if let Err(e) = self.mpd_controller.remove_from_queue(self.playback.selected_queue_item, &mut self.debug_log).await {
+                    self.set_status_error(format!("Failed to remove track: {}", e));
+                } else {
+                    self.local_queue.remove(self.playback.selected_queue_item);
+                    if self.playback.selected_queue_item > 0 && self.playback.selected_queue_item >= self.local_queue.len() {
+                        self.playback.selected_queue_item -= 1;
                     }
-                }
-                Ok(None) => {} // offline, not downloaded
-                Err(e) => {
-                    self.add_debug(format!("Failed to get URL for {}: {}", track.title, e));
+                    self.add_debug(format!("Removed track from queue, {} remaining", self.local_queue.len()));
+                    self.playback.queue_dirty = true;
+                    self.refresh_queue_after_edit().await;
                 }
             }
+    }
+
+    /// Move the selected queue track one place up or down, in MPD and
+    /// locally, keeping it selected
+    pub async fn move_selected_in_queue(&mut self, up: bool) {
+        // Only works when queue is visible and has items
+        if !self.playback.show_queue || self.local_queue.is_empty() {
+            return;
         }
 
-        self.add_debug(format!("Added {}/{} tracks", added_count, tracks.len()));
+        let selected = self.playback.selected_queue_item;
 
-        if let Ok(queue) = self.mpd_controller.get_queue().await {
-            self.queue = queue;
+        // Can't move the first item up or the last down
+        if (up && selected == 0) || (!up && selected >= self.local_queue.len() - 1) {
+            return;
         }
 
-        if !was_playing && added_count > 0 {
-            if let Err(e) = self.mpd_controller.play(&mut self.debug_log).await {
-                self.add_debug(format!("Play failed: {}", e));
-            } else {
-                self.playback.is_playing = true;
+        let target = if up { selected - 1 } else { selected + 1 };
+
+        // Move in MPD first
+        if let Err(e) = self
+            .mpd_controller
+            .move_in_queue(selected, target, &mut self.debug_log)
+            .await
+        {
+            self.set_status_error(format!("Failed to move track {}: {}", if up { "up" } else { "down" }, e));
+            return;
+        }
+
+        // Update local queue
+        self.local_queue.swap(selected, target);
+
+        // Also update the QueueItem vec if populated
+        if !self.queue.is_empty() && selected < self.queue.len() && target < self.queue.len() {
+            self.queue.swap(selected, target);
+        }
+
+        // Move selection to follow the track
+        self.playback.selected_queue_item = target;
+        self.playback.queue_dirty = true;
+        self.refresh_queue_after_edit().await;
+    }
+
+    /// Sort the queue by the next key (artist, album, title, duration),
+    /// moving tracks in place so the playing track keeps playing
+    pub async fn sort_queue(&mut self) -> Result<()> {
+        if self.local_queue.len() < 2 {
+            return Ok(());
+        }
+
+        let mpd_len = self.mpd_controller.get_queue().await?.len();
+        if mpd_len != self.local_queue.len() {
+            self.set_status_error(format!(
+                "Can't sort: queue out of sync with MPD ({} vs {} tracks)",
+                self.local_queue.len(),
+                mpd_len
+            ));
+            return Ok(());
+        }
+
+        let mut sort = self.playback.queue_sort.next();
+        if sort == TrackSort::Playlist {
+            sort = sort.next();
+        }
+
+        let mut sorted = self.local_queue.clone();
+        let mut order: Vec<usize> = (0..sorted.len()).collect();
+        sort.apply(&mut sorted, &mut order);
+
+        // Replay the permutation as moves; `working` mirrors MPD's order
+        let mut working: Vec<usize> = (0..order.len()).collect();
+        let mut moves = 0;
+        for (target, original) in order.iter().enumerate() {
+            let from = working.iter().position(|i| i == original).unwrap_or(target);
+            if from != target {
+                self.mpd_controller.move_in_queue(from, target, &mut self.debug_log).await?;
+                let item = working.remove(from);
+                working.insert(target, item);
+                moves += 1;
             }
         }
 
+        self.local_queue = sorted;
+        self.playback.queue_sort = sort;
+        self.playback.queue_dirty = true;
+        self.refresh_queue_after_edit().await;
+        self.add_debug(format!("Queue sorted by {} ({} moves)", sort.label(), moves));
+        self.set_status_info(format!("Queue sorted by {}", sort.label()));
         Ok(())
     }
 
-    pub async fn add_artist_to_queue(&mut self) -> Result<()> {
-        let artist = if let Some(ref results) = self.search_results {
-            if self.search.tab == SearchTab::Artists && self.search.selected_artist < results.artists.len() {
-                results.artists[self.search.selected_artist].clone()
-            } else {
-                return Ok(());
+    /// Copy the queue to the clipboard as a numbered text list
+    pub fn copy_queue_to_clipboard(&mut self) {
+        if self.local_queue.is_empty() {
+            self.set_status_info("Queue is empty".to_string());
+            return;
+        }
+        match crate::clipboard::copy(&queue_as_text(&self.local_queue)) {
+            Ok(via) => {
+                self.add_debug(format!("Queue copied via {}", via));
+                self.set_status_info(format!("Copied {} queued tracks", self.local_queue.len()));
             }
-        } else {
+            Err(e) => self.set_status_error(format!("Copy failed: {}", e)),
+        }
+    }
+
+    /// Free queue slots under `playback.max_queue_length` (None = unlimited)
+    pub fn queue_room(&self) -> Option<usize> {
+        match self.config.playback.max_queue_length {
+            0 => None,
+            max => Some(max.saturating_sub(self.local_queue.len())),
+        }
+    }
+
+    /// Add every album from artist detail to the queue, in album then track
+    /// order. The albums are fetched on a spawned task, a few at a time, and
+    /// queued by [`App::process_discography_fetch`] once they're in.
+    pub async fn add_artist_discography_to_queue(&mut self) -> Result<()> {
+        let Some(artist) = self.artist_detail.artist.clone() else {
+            return Ok(());
+        };
+        let albums = self.artist_detail.albums.clone();
+        if albums.is_empty() {
+            self.set_status_error(format!("No albums loaded for {}", artist.name));
+            return Ok(());
+        }
+        if self.playback.bulk_enqueue.is_some() || self.playback.discography_fetch.is_some() {
+            self.set_status_error("Still queuing the last batch of tracks".to_string());
+            return Ok(());
+        }
+
+        let room = self.queue_room();
+        if room == Some(0) {
+            self.set_status_error(format!(
+                "Queue is full ({} tracks max)",
+                self.config.playback.max_queue_length
+            ));
+            return Ok(());
+        }
+
+        self.add_debug(format!("Fetching {} albums for {}...", albums.len(), artist.name));
+
+        // Freshly cached albums go along as they are, without a request
+        let albums: Vec<(Album, Option<Vec<Track>>)> = albums
+            .into_iter()
+            .map(|album| {
+                let cached = match self.metadata_cache.get_album_tracks(&album.id) {
+                    Ok(Some(hit)) if matches!(hit.status, crate::storage::metadata_cache::CacheStatus::Fresh) => {
+                        Some(hit.data)
+                    }
+                    _ => None,
+                };
+                (album, cached)
+            })
+            .collect();
+
+        let (tx, rx) = oneshot::channel();
+        let mut service = self.music_service.clone();
+        service.set_background(true);
+        self.tasks.spawn(format!("Fetching {} albums for {}", albums.len(), artist.name), move |task| async move {
+            let _ = tx.send(fetch_discography(service, albums, room, task).await);
+        });
+        self.playback.discography_fetch = Some(DiscographyFetch {
+            artist_name: artist.name,
+            room,
+            albums: rx,
+        });
+        Ok(())
+    }
+
+    /// Queue a discography whose albums have all been fetched
+    pub async fn process_discography_fetch(&mut self) {
+        let Some(mut job) = self.playback.discography_fetch.take() else {
+            return;
+        };
+        let albums = match job.albums.try_recv() {
+            Ok(albums) => albums,
+            Err(oneshot::error::TryRecvError::Empty) => {
+                self.playback.discography_fetch = Some(job);
+                return;
+            }
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.set_status_error(format!("Fetching albums for {} failed", job.artist_name));
+                return;
+            }
+        };
+
+        let mut tracks = Vec::new();
+        for FetchedAlbum { album, tracks: result, fetched } in albums {
+            match result {
+                Ok(album_tracks) => {
+                    if fetched {
+                        let _ = self.metadata_cache.set_album_tracks(&album.id, &album_tracks);
+                    }
+                    tracks.extend(album_tracks);
+                }
+                // A stale copy beats leaving the album out
+                Err(e) => match self.metadata_cache.get_album_tracks(&album.id) {
+                    Ok(Some(hit)) => tracks.extend(hit.data),
+                    _ => self.add_debug(format!("Failed to get tracks for {}: {}", album.title, e)),
+                },
+            }
+        }
+
+        if tracks.is_empty() {
+            self.set_status_error(format!("No tracks found for {}", job.artist_name));
+            return;
+        }
+
+        let mut note = None;
+        if let Some(r) = job.room {
+            if tracks.len() > r {
+                note = Some(format!("stopped at queue limit of {}", self.config.playback.max_queue_length));
+                tracks.truncate(r);
+            }
+        }
+
+        self.add_debug(format!("Adding {} tracks from {}...", tracks.len(), job.artist_name));
+        if let Err(e) = self.start_bulk_enqueue(tracks, note).await {
+            self.set_status_error(format!("Failed to queue {}: {}", job.artist_name, e));
+        }
+    }
+
+    pub async fn add_artist_to_queue(&mut self) -> Result<()> {
+        let Some(SelectedItem::Artist(artist)) = self.get_selected_item() else {
             return Ok(());
         };
 
@@ -447,9 +837,7 @@ impl App {
 
         self.add_debug(format!("Added {}/{} top tracks from artist", added_count, tracks.len()));
 
-        if let Ok(queue) = self.mpd_controller.get_queue().await {
-            self.queue = queue;
-        }
+        self.refresh_queue_after_edit().await;
 
         if !was_playing && added_count > 0 {
             if let Err(e) = self.mpd_controller.play(&mut self.debug_log).await {
@@ -462,3 +850,72 @@ impl App {
         Ok(())
     }
 }
+
+/// Fetch a discography's albums off the main loop, a few at a time but
+/// kept in album order. Stops once there are more tracks than `room`, so
+/// the caller can tell a cut-short discography from one that just fits.
+async fn fetch_discography(
+    service: MultiServiceManager,
+    albums: Vec<(Album, Option<Vec<Track>>)>,
+    room: Option<usize>,
+    task: TaskHandle,
+) -> Vec<FetchedAlbum> {
+    let total = albums.len() as u64;
+    let mut fetches = futures_util::stream::iter(albums)
+        .map(|(album, cached)| {
+            let mut service = service.clone();
+            async move {
+                match cached {
+                    Some(tracks) => FetchedAlbum { album, tracks: Ok(tracks), fetched: false },
+                    None => {
+                        let tracks = service.get_album_tracks(&album.id).await;
+                        FetchedAlbum { album, tracks, fetched: true }
+                    }
+                }
+            }
+        })
+        .buffered(ALBUM_FETCH_CONCURRENCY);
+
+    let mut fetched = Vec::new();
+    let mut track_count = 0;
+    while let Some(album) = fetches.next().await {
+        track_count += album.tracks.as_ref().map_or(0, Vec::len);
+        fetched.push(album);
+        task.progress(fetched.len() as u64, total);
+        if room.is_some_and(|r| track_count > r) {
+            break;
+        }
+    }
+    fetched
+}
+
+/// Resolve play URLs for a bulk enqueue off the main loop, sending each track
+/// back as it's ready. Stops early if the app drops the receiver.
+async fn resolve_for_enqueue(
+    mut service: MultiServiceManager,
+    jobs: Vec<(Track, Option<String>)>,
+    offline: bool,
+    skip_unavailable: bool,
+    tx: mpsc::UnboundedSender<EnqueueEvent>,
+    task: TaskHandle,
+) {
+    let total = jobs.len() as u64;
+    for (done, (track, local_path)) in (1..).zip(jobs) {
+        let event = if let Some(url) = local_path {
+            EnqueueEvent::Resolved { track, url }
+        } else if !track.available && skip_unavailable {
+            EnqueueEvent::Skipped { track, reason: "not available for streaming".to_string() }
+        } else if offline {
+            EnqueueEvent::Skipped { track, reason: "offline and not downloaded".to_string() }
+        } else {
+            match service.get_stream_url(&track.id).await {
+                Ok(url) => EnqueueEvent::Resolved { track, url },
+                Err(e) => EnqueueEvent::Skipped { track, reason: e.to_string() },
+            }
+        };
+        if tx.send(event).is_err() {
+            return;
+        }
+        task.progress(done, total);
+    }
+}
@@ -1,7 +1,14 @@
 use ratatui::layout::Rect;
+use std::collections::{HashMap, VecDeque};
 use std::time::Instant;
+use tokio::sync::{mpsc, oneshot};
 
-use crate::service::{Album, Artist, ServiceType, Track};
+use crate::history_db::HistoryEntry;
+use crate::metadata::EnrichedTrack;
+use crate::mpd::RepeatMode;
+use crate::playlist_view_state::TrackSort;
+use crate::album_art::FetchedArt;
+use crate::service::{Album, AlbumType, Artist, CoverArt, Playlist, SearchResults, ServiceType, Track};
 use crate::ui::{LibraryTab, SearchTab};
 
 /// Status message for display in the status bar
@@ -17,9 +24,23 @@ pub enum RadioSeed {
     Playlist(String),
     Artist(String),
     Album(String),
+    /// "My Station": radio from a rotating pick of favorite tracks and artists
+    Favorites,
 }
 
-#[derive(PartialEq, Clone, Copy)]
+/// Whatever the focused list has selected, so actions don't each have to
+/// work out which list that is
+#[derive(Debug, Clone)]
+pub enum SelectedItem {
+    /// Position in the queue, with its track
+    QueueItem(usize, Track),
+    Playlist(Playlist),
+    Track(Track),
+    Album(Album),
+    Artist(Artist),
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub enum ViewMode {
     Browse,
     Search,
@@ -43,6 +64,59 @@ pub struct BrowseState {
     pub selected_playlist: usize,
     pub selected_track: usize,
     pub selected_tab: usize,
+    /// Id of the playlist whose tracks are loaded
+    pub loaded_playlist: Option<String>,
+    /// Sort applied to the loaded tracks
+    pub sort: TrackSort,
+    /// Playlist index of each displayed track (differs from display order when sorted)
+    pub track_positions: Vec<usize>,
+    /// In-list filter typed after Ctrl+F (empty = show every track)
+    pub filter_query: String,
+    /// Typing into the filter
+    pub filter_active: bool,
+    /// Indices into the loaded tracks matching `filter_query`, best first
+    pub filtered_indices: Vec<usize>,
+}
+
+impl BrowseState {
+    /// Whether the track list is narrowed by a filter
+    pub fn is_filtered(&self) -> bool {
+        !self.filter_query.is_empty()
+    }
+
+    /// Rows in the track panel: the matches when filtered, else every track
+    pub fn row_count(&self, track_count: usize) -> usize {
+        if self.is_filtered() { self.filtered_indices.len() } else { track_count }
+    }
+
+    /// Track index drawn at `row` of the track panel
+    pub fn track_at_row(&self, row: usize, track_count: usize) -> Option<usize> {
+        if self.is_filtered() {
+            self.filtered_indices.get(row).copied()
+        } else {
+            (row < track_count).then_some(row)
+        }
+    }
+
+    /// Row the selected track is drawn at, None if the filter hides it
+    pub fn selected_row(&self) -> Option<usize> {
+        if self.is_filtered() {
+            self.filtered_indices.iter().position(|&i| i == self.selected_track)
+        } else {
+            Some(self.selected_track)
+        }
+    }
+
+    /// Select the track drawn at `row`, clamped to the rows there are
+    pub fn select_row(&mut self, row: usize, track_count: usize) {
+        let rows = self.row_count(track_count);
+        if rows == 0 {
+            return;
+        }
+        if let Some(index) = self.track_at_row(row.min(rows - 1), track_count) {
+            self.selected_track = index;
+        }
+    }
 }
 
 /// Search mode state
@@ -70,6 +144,33 @@ pub struct SearchState {
     pub service_filter: Option<ServiceType>,
     /// Show preview panel with album art (default: true)
     pub show_preview: bool,
+    /// Art fetch for the selected result, aborted when the selection moves on
+    pub preview_art: Option<PreviewArtFetch>,
+    /// Results hold trending tracks rather than a search (`search.show_trending`)
+    pub showing_trending: bool,
+    /// Search request still waiting on the service; Esc aborts it
+    pub pending: Option<PendingSearch>,
+}
+
+/// A search running on its own task, applied by the main loop once it ends.
+/// Only one runs at a time: starting another aborts it, so results from a
+/// superseded query never arrive to overwrite newer ones.
+pub struct PendingSearch {
+    pub query: String,
+    pub service_filter: Option<ServiceType>,
+    pub task: tokio::task::JoinHandle<anyhow::Result<SearchResults>>,
+}
+
+/// Search preview art being loaded on its own task
+pub struct PreviewArtFetch {
+    pub cover: CoverArt,
+    /// When the selection landed on this cover
+    pub selected_at: Instant,
+    /// Set once the selection has rested long enough for the fetch to start
+    pub requested: bool,
+    /// None until requested and again once finished, so a failed fetch isn't
+    /// retried until the selection changes
+    pub task: Option<tokio::task::JoinHandle<anyhow::Result<FetchedArt>>>,
 }
 
 impl SearchState {
@@ -120,9 +221,8 @@ impl Default for DownloadsState {
 pub struct PlaybackState {
     pub is_playing: bool,
     pub volume: u8,
-    pub repeat_mode: bool,
+    pub repeat_mode: RepeatMode,
     pub random_mode: bool,
-    pub single_mode: bool,
     pub selected_queue_item: usize,
     pub show_queue: bool,
     pub queue_dirty: bool,
@@ -130,8 +230,109 @@ pub struct PlaybackState {
     pub radio_fetching: bool,
     /// Video mode enabled (YouTube content plays in mpv window)
     pub video_mode: bool,
+    /// MPD queue position last seen playing
+    pub queue_position: Option<usize>,
+    /// When `queue` was last read from MPD
+    pub queue_refreshed_at: Option<Instant>,
+    /// When MPD's current song was last checked against the expected one
+    pub desync_checked_at: Option<Instant>,
+    /// File last found out of place, so a lasting desync is handled once
+    pub desynced_file: Option<String>,
+    /// Last MPD player error handled, so each failure is acted on once
+    pub last_error: Option<String>,
+    /// Key the queue was last sorted by
+    pub queue_sort: TrackSort,
+    /// Tracks played this session, most recent first (not persisted)
+    pub recently_played: VecDeque<Track>,
+    /// "Add all to queue" still working through its tracks
+    pub bulk_enqueue: Option<BulkEnqueue>,
+    /// Artist discography still being fetched before it's queued
+    pub discography_fetch: Option<DiscographyFetch>,
+    /// When MPD was first seen stopped, reset once it plays or pauses
+    pub stopped_since: Option<Instant>,
+    /// `playback.service_gain_db` offset the MPD volume currently includes
+    pub applied_gain_db: f64,
+    /// Volume the user chose, before the service gain; MPD's volume is
+    /// always set from this, so switching services can't drift it
+    pub base_volume: u8,
+    /// Playlist each track was queued from, by track id
+    pub track_origins: HashMap<String, TrackOrigin>,
+    /// Play of the current track not yet submitted to the scrobbler
+    pub pending_listen: Option<HistoryEntry>,
+    /// When each recently queued stream URL was resolved, so an expiring
+    /// one can be refreshed before it plays
+    pub stream_resolved_at: HashMap<String, Instant>,
+    /// When the sleep timer pauses playback
+    pub sleep_timer: Option<Instant>,
+    /// Volume before the sleep timer began fading it, restored once paused
+    pub sleep_fade_volume: Option<u8>,
+    /// When the queue and position were last saved
+    pub queue_saved_at: Option<Instant>,
+    /// Path and text last written to `integrations.nowplaying_file`
+    pub nowplaying_written: Option<(String, String)>,
+    /// MusicBrainz details for the track with this id
+    pub enrichment: Option<(String, EnrichedTrack)>,
+    /// MusicBrainz lookup for the current track, applied by the main loop
+    pub pending_enrichment: Option<PendingEnrichment>,
+}
+
+/// A MusicBrainz lookup running on its own task
+pub struct PendingEnrichment {
+    pub track_id: String,
+    pub service: ServiceType,
+    pub task: tokio::task::JoinHandle<anyhow::Result<EnrichedTrack>>,
+}
+
+/// Where in a playlist a queued track came from
+#[derive(Debug, Clone)]
+pub struct TrackOrigin {
+    pub playlist_id: String,
+    /// Position in the playlist (not the sorted display order)
+    pub index: usize,
+}
+
+/// A bulk add to the queue whose play URLs are resolved on a spawned task;
+/// the main loop adds each track to MPD as its event arrives
+pub struct BulkEnqueue {
+    pub events: mpsc::UnboundedReceiver<EnqueueEvent>,
+    pub total: usize,
+    /// Tracks handled so far, added or skipped
+    pub done: usize,
+    pub added: usize,
+    /// Whether MPD was playing; if not, playback starts with the first
+    /// track added
+    pub was_playing: bool,
+    /// Added to the final status message, e.g. that the queue limit cut
+    /// the batch short
+    pub note: Option<String>,
+}
+
+/// An artist's albums being fetched on a spawned task, queued through a
+/// [`BulkEnqueue`] once they arrive
+pub struct DiscographyFetch {
+    pub artist_name: String,
+    /// Queue room when the fetch started (None = unlimited)
+    pub room: Option<usize>,
+    pub albums: oneshot::Receiver<Vec<FetchedAlbum>>,
+}
+
+/// One album of a [`DiscographyFetch`]
+pub struct FetchedAlbum {
+    pub album: Album,
+    pub tracks: anyhow::Result<Vec<Track>>,
+    /// Came from the service rather than the cache, so it's worth caching
+    pub fetched: bool,
 }
 
+/// Sent by a bulk enqueue's task for each track, in order
+pub enum EnqueueEvent {
+    Resolved { track: Track, url: String },
+    Skipped { track: Track, reason: String },
+}
+
+/// How many tracks the in-session played list keeps
+pub const RECENTLY_PLAYED_MAX: usize = 20;
+
 impl PlaybackState {
     pub fn radio_mode(&self) -> bool {
         self.radio_seed.is_some()
@@ -143,15 +344,35 @@ impl Default for PlaybackState {
         Self {
             is_playing: false,
             volume: 80,
-            repeat_mode: false,
+            repeat_mode: RepeatMode::Off,
             random_mode: false,
-            single_mode: false,
             selected_queue_item: 0,
             show_queue: false,
             queue_dirty: false,
             radio_seed: None,
             radio_fetching: false,
             video_mode: false,
+            queue_position: None,
+            queue_refreshed_at: None,
+            desync_checked_at: None,
+            desynced_file: None,
+            last_error: None,
+            queue_sort: TrackSort::default(),
+            recently_played: VecDeque::new(),
+            bulk_enqueue: None,
+            discography_fetch: None,
+            stopped_since: None,
+            applied_gain_db: 0.0,
+            base_volume: 80,
+            track_origins: HashMap::new(),
+            pending_listen: None,
+            stream_resolved_at: HashMap::new(),
+            sleep_timer: None,
+            sleep_fade_volume: None,
+            queue_saved_at: None,
+            nowplaying_written: None,
+            enrichment: None,
+            pending_enrichment: None,
         }
     }
 }
@@ -161,6 +382,8 @@ impl Default for PlaybackState {
 pub struct KeyState {
     pub pending_key: Option<char>,
     pub space_pressed: bool,
+    /// When Space+q was first pressed, if `ui.confirm_quit` wants a second press
+    pub quit_armed_at: Option<std::time::Instant>,
 }
 
 /// Artist detail view state
@@ -168,7 +391,12 @@ pub struct KeyState {
 pub struct ArtistDetailState {
     pub artist: Option<Artist>,
     pub top_tracks: Vec<Track>,
+    /// Albums shown, after `album_filter`
     pub albums: Vec<Album>,
+    /// Every album the service returned
+    pub all_albums: Vec<Album>,
+    /// Only show albums of this type (None = all)
+    pub album_filter: Option<AlbumType>,
     pub selected_track: usize,
     pub selected_album: usize,
     pub selected_panel: usize, // 0 = top tracks, 1 = albums
@@ -188,6 +416,15 @@ pub struct HelpState {
     pub scroll_offset: usize,
 }
 
+/// Debug panel state
+#[derive(Default)]
+pub struct DebugPanelState {
+    /// j/k scroll the log instead of the main view
+    pub focused: bool,
+    /// Lines scrolled back from the newest message
+    pub scroll_offset: usize,
+}
+
 /// Dialog mode for text input and playlist selection
 #[derive(Clone, PartialEq)]
 #[derive(Default)]
@@ -207,11 +444,36 @@ pub enum DialogMode {
         playlist_id: String,
         playlist_title: String,
     },
-    /// Confirming playlist deletion
+    /// Confirming playlist deletion, showing what will be lost
     ConfirmDeletePlaylist {
         playlist_id: String,
         playlist_title: String,
+        track_count: usize,
+        synced: bool,
+    },
+    /// Confirming a playlist sync, showing the estimated download size
+    ConfirmSyncPlaylist {
+        playlist_title: String,
+        track_count: usize,
+        estimated_bytes: u64,
+    },
+    /// Confirming a batch download of the current track list
+    ConfirmDownloadAll {
+        title: String,
+        track_count: usize,
+        estimated_bytes: u64,
+    },
+    /// Asking whether to re-download tracks already downloaded
+    /// (`downloads.on_existing = "ask"`); the tracks wait in
+    /// `DialogState::pending_downloads`
+    ConfirmOverwriteDownloads {
+        track_count: usize,
+        existing: usize,
     },
+    /// Setting the sleep timer - text input for minutes
+    SleepTimer,
+    /// Command palette - the input filters actions, the selection picks one
+    CommandPalette,
 }
 
 
@@ -221,4 +483,6 @@ pub struct DialogState {
     pub mode: DialogMode,
     pub input_text: String,
     pub selected_index: usize,
+    /// Tracks held while ConfirmOverwriteDownloads asks about them
+    pub pending_downloads: Vec<Track>,
 }
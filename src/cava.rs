@@ -1,13 +1,38 @@
 use std::process::{Command, Stdio, Child};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use anyhow::{Result, Context};
 use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
 use std::path::PathBuf;
+use std::time::Duration;
+
+/// Bars the visualizer produces, matching the cava config below
+const BAR_COUNT: usize = 20;
+
+/// Samples per spectrum frame read from an httpd stream (~23ms at 44.1kHz)
+const FFT_SIZE: usize = 1024;
+
+/// How long to wait before reconnecting to an httpd stream that ended,
+/// e.g. because MPD stopped
+const HTTPD_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// How long startup waits to reach the httpd output before falling back to
+/// cava; a running MPD accepts at once even with nothing playing
+const HTTPD_PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Largest `fmt ` chunk accepted; PCM needs 16 bytes, extensible formats 40
+const MAX_FMT_CHUNK_SIZE: u64 = 64;
 
 pub struct CavaVisualizer {
     bars: Arc<Mutex<Vec<u8>>>,
+    /// Heights last drawn, held or decayed while paused
+    shown: Mutex<Vec<u8>>,
     process: Option<Child>,
+    /// Cleared to stop the httpd reader thread
+    httpd_running: Arc<AtomicBool>,
 }
 
 impl CavaVisualizer {
@@ -16,16 +41,17 @@ impl CavaVisualizer {
 
         Ok(Self {
             bars,
+            shown: Mutex::new(vec![0; 20]),
             process: None,
+            httpd_running: Arc::new(AtomicBool::new(false)),
         })
     }
 
     /// Get the cava config file path, creating default config if needed
     fn get_config_path() -> Result<PathBuf> {
         // Use XDG config directory
-        let config_dir = dirs::config_dir()
-            .context("Failed to get config directory")?
-            .join("drift");
+        let config_dir = crate::config::drift_config_dir()
+            .context("Failed to get config directory")?;
 
         fs::create_dir_all(&config_dir)
             .context("Failed to create config directory")?;
@@ -88,7 +114,6 @@ noise_reduction = 77
 
         // Spawn thread to read cava output
         thread::spawn(move || {
-            use std::io::Read;
             let mut reader = stdout;
             let mut buffer = [0u8; 20];
 
@@ -113,7 +138,40 @@ noise_reduction = 77
         Ok(())
     }
 
+    /// Compute the bars from an MPD `httpd` output instead of running cava.
+    /// The output must use `encoder "wave"` so the stream is plain PCM.
+    ///
+    /// Fails if the URL is unusable or nothing answers there, so the caller
+    /// can fall back to [`CavaVisualizer::start`]. The stream itself is read
+    /// on a thread that reconnects whenever it ends (e.g. MPD stopped), with
+    /// the bars at rest meanwhile, so startup never waits on audio.
+    pub fn start_httpd(&mut self, url: &str) -> Result<()> {
+        connect_http(url, HTTPD_PROBE_TIMEOUT)?;
+
+        let bars = Arc::clone(&self.bars);
+        let running = Arc::clone(&self.httpd_running);
+        running.store(true, Ordering::Relaxed);
+        let url = url.to_string();
+
+        thread::spawn(move || {
+            while running.load(Ordering::Relaxed) {
+                if let Ok((mut reader, format)) = open_wav_stream(&url) {
+                    read_pcm_spectrum(&mut reader, format, &bars, &running);
+                }
+                if let Ok(mut bars) = bars.lock() {
+                    bars.iter_mut().for_each(|b| *b = 0);
+                }
+                if !running.load(Ordering::Relaxed) {
+                    break;
+                }
+                thread::sleep(HTTPD_RETRY_DELAY);
+            }
+        });
+        Ok(())
+    }
+
     pub fn stop(&mut self) {
+        self.httpd_running.store(false, Ordering::Relaxed);
         if let Some(mut process) = self.process.take() {
             let _ = process.kill();
             let _ = process.wait();
@@ -128,8 +186,11 @@ noise_reduction = 77
     }
 
 
-    pub fn draw_bars(&self) -> String {
-        let bars = self.get_bars();
+    /// Render the bars. While paused, `on_pause` "freeze" holds the last
+    /// frame and "decay" lowers each bar a step per draw, since cava keeps
+    /// reporting whatever else is playing on the system.
+    pub fn draw_bars(&self, is_playing: bool, on_pause: &str) -> String {
+        let bars = self.heights(is_playing, on_pause);
         let bar_chars = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
         bars.iter()
@@ -139,10 +200,297 @@ noise_reduction = 77
             })
             .collect::<String>()
     }
+
+    /// Bar heights (0-7) to draw this frame, with the same pause handling
+    /// as [`CavaVisualizer::draw_bars`]
+    pub fn heights(&self, is_playing: bool, on_pause: &str) -> Vec<u8> {
+        match self.shown.lock() {
+            Ok(mut shown) => {
+                if is_playing {
+                    *shown = self.get_bars();
+                } else if on_pause == "decay" {
+                    for height in shown.iter_mut() {
+                        *height = height.saturating_sub(1);
+                    }
+                }
+                shown.clone()
+            }
+            Err(_) => self.get_bars(),
+        }
+    }
 }
 
 impl Drop for CavaVisualizer {
     fn drop(&mut self) {
         self.stop();
     }
-}
\ No newline at end of file
+}
+
+/// PCM layout from a WAV `fmt ` chunk
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct WavFormat {
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+}
+
+/// Host (with port) and path of a plain `http://` URL
+fn split_http_url(url: &str) -> Result<(&str, &str)> {
+    let rest = url.strip_prefix("http://")
+        .with_context(|| format!("Visualizer stream '{}' must be an http:// URL", url))?;
+    Ok(match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    })
+}
+
+/// Open a TCP connection to the host of a plain `http://` URL
+fn connect_http(url: &str, timeout: Duration) -> Result<TcpStream> {
+    let (authority, _) = split_http_url(url)?;
+    let address = if authority.contains(':') { authority.to_string() } else { format!("{}:80", authority) };
+    let socket = address.to_socket_addrs()?
+        .next()
+        .with_context(|| format!("Could not resolve {}", address))?;
+    TcpStream::connect_timeout(&socket, timeout)
+        .with_context(|| format!("Could not connect to {}", url))
+}
+
+/// GET a WAV stream over plain HTTP, returning it positioned at the first
+/// sample along with its format
+fn open_wav_stream(url: &str) -> Result<(BufReader<TcpStream>, WavFormat)> {
+    let (authority, path) = split_http_url(url)?;
+    let mut stream = connect_http(url, Duration::from_secs(2))?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    write!(stream, "GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: drift\r\n\r\n", path, authority)?;
+
+    let mut reader = BufReader::new(stream);
+    let mut status = String::new();
+    reader.read_line(&mut status)?;
+    if status.split_whitespace().nth(1) != Some("200") {
+        anyhow::bail!("{} answered '{}'", url, status.trim());
+    }
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+
+    let format = read_wav_header(&mut reader)
+        .with_context(|| format!("{} is not a WAV stream (set `encoder \"wave\"` on the httpd output)", url))?;
+    Ok((reader, format))
+}
+
+/// Read RIFF/WAVE chunks up to the start of `data`
+fn read_wav_header(reader: &mut impl Read) -> Result<WavFormat> {
+    let mut riff = [0u8; 12];
+    reader.read_exact(&mut riff)?;
+    if &riff[0..4] != b"RIFF" || &riff[8..12] != b"WAVE" {
+        anyhow::bail!("missing RIFF/WAVE header");
+    }
+
+    let mut format = None;
+    loop {
+        let mut chunk = [0u8; 8];
+        reader.read_exact(&mut chunk)?;
+        let size = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]) as u64;
+        match &chunk[0..4] {
+            b"fmt " => {
+                if size > MAX_FMT_CHUNK_SIZE {
+                    anyhow::bail!("fmt chunk of {} bytes", size);
+                }
+                let mut fmt = vec![0u8; size as usize];
+                reader.read_exact(&mut fmt)?;
+                if fmt.len() < 16 || u16::from_le_bytes([fmt[0], fmt[1]]) != 1 {
+                    anyhow::bail!("only integer PCM is supported");
+                }
+                let sample_rate = u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]);
+                if sample_rate == 0 {
+                    anyhow::bail!("sample rate of 0");
+                }
+                format = Some(WavFormat {
+                    channels: u16::from_le_bytes([fmt[2], fmt[3]]).max(1),
+                    sample_rate,
+                    bits_per_sample: u16::from_le_bytes([fmt[14], fmt[15]]),
+                });
+            }
+            b"data" => {
+                let format = format.context("data chunk before fmt chunk")?;
+                if ![8, 16, 24, 32].contains(&format.bits_per_sample) {
+                    anyhow::bail!("unsupported sample size {} bits", format.bits_per_sample);
+                }
+                return Ok(format);
+            }
+            _ => {
+                std::io::copy(&mut reader.by_ref().take(size), &mut std::io::sink())?;
+            }
+        }
+    }
+}
+
+/// Turn PCM from `reader` into bar heights until the stream ends or
+/// `running` is cleared
+fn read_pcm_spectrum(reader: &mut impl Read, format: WavFormat, bars: &Mutex<Vec<u8>>, running: &AtomicBool) {
+    let sample_bytes = (format.bits_per_sample / 8) as usize;
+    let frame_bytes = sample_bytes * format.channels as usize;
+    let mut buffer = vec![0u8; frame_bytes * FFT_SIZE];
+    let mut samples = vec![0f32; FFT_SIZE];
+
+    while running.load(Ordering::Relaxed) && reader.read_exact(&mut buffer).is_ok() {
+        for (sample, frame) in samples.iter_mut().zip(buffer.chunks_exact(frame_bytes)) {
+            let sum: f32 = frame.chunks_exact(sample_bytes).map(decode_sample).sum();
+            *sample = sum / format.channels as f32;
+        }
+        let heights = spectrum_bars(&samples, format.sample_rate, BAR_COUNT);
+        if let Ok(mut bars) = bars.lock() {
+            // Let bars fall a step at a time, like cava's smoothing
+            for (bar, height) in bars.iter_mut().zip(heights) {
+                *bar = height.max(bar.saturating_sub(1));
+            }
+        }
+    }
+}
+
+/// One little-endian sample scaled to -1.0..1.0 (8-bit WAV is unsigned)
+fn decode_sample(bytes: &[u8]) -> f32 {
+    match bytes.len() {
+        1 => (bytes[0] as f32 - 128.0) / 128.0,
+        2 => i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / 32768.0,
+        3 => (i32::from_le_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 8) as f32 / 8_388_608.0,
+        _ => i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32 / 2_147_483_648.0,
+    }
+}
+
+/// Bar heights (0-7) for `bars` log-spaced bands from 50Hz to 16kHz
+fn spectrum_bars(samples: &[f32], sample_rate: u32, bars: usize) -> Vec<u8> {
+    let n = samples.len();
+    let mut re: Vec<f32> = samples.iter()
+        .enumerate()
+        .map(|(i, s)| {
+            // Hann window
+            let w = 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / n as f32).cos();
+            s * w
+        })
+        .collect();
+    let mut im = vec![0f32; n];
+    fft(&mut re, &mut im);
+
+    let bin_hz = sample_rate as f32 / n as f32;
+    let (low, high) = (50f32, 16_000f32.min(sample_rate as f32 / 2.0));
+    (0..bars)
+        .map(|b| {
+            let from = low * (high / low).powf(b as f32 / bars as f32);
+            let to = low * (high / low).powf((b + 1) as f32 / bars as f32);
+            let first = ((from / bin_hz) as usize).max(1);
+            let last = ((to / bin_hz) as usize).max(first + 1).min(n / 2);
+            let peak = (first..last)
+                .map(|k| (re[k] * re[k] + im[k] * im[k]).sqrt())
+                .fold(0f32, f32::max);
+            // A full-scale sine peaks at n/4 after the Hann window; show
+            // the top 60dB below that
+            let db = 20.0 * (peak / (n as f32 / 4.0)).max(1e-6).log10();
+            ((db + 60.0) / 60.0 * 8.0).clamp(0.0, 7.0) as u8
+        })
+        .collect()
+}
+
+/// In-place radix-2 FFT; the length must be a power of two
+fn fft(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * std::f32::consts::PI / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let tr = re[b] * cos - im[b] * sin;
+                let ti = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - tr;
+                im[b] = im[a] - ti;
+                re[a] += tr;
+                im[a] += ti;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_wav_header() {
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF\xff\xff\xff\xffWAVE");
+        wav.extend_from_slice(b"fmt \x10\0\0\0");
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&44100u32.to_le_bytes());
+        wav.extend_from_slice(&(44100u32 * 4).to_le_bytes());
+        wav.extend_from_slice(&4u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data\xff\xff\xff\xff");
+        wav.extend_from_slice(&[1, 2, 3, 4]);
+
+        let mut reader = &wav[..];
+        let format = read_wav_header(&mut reader).unwrap();
+        assert_eq!(format, WavFormat { channels: 2, sample_rate: 44100, bits_per_sample: 16 });
+        assert_eq!(reader, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_rejects_oversized_fmt_chunk() {
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF\xff\xff\xff\xffWAVE");
+        wav.extend_from_slice(b"fmt \xff\xff\xff\xff");
+        assert!(read_wav_header(&mut &wav[..]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_zero_sample_rate() {
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF\xff\xff\xff\xffWAVE");
+        wav.extend_from_slice(b"fmt \x10\0\0\0");
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&0u32.to_le_bytes());
+        wav.extend_from_slice(&0u32.to_le_bytes());
+        wav.extend_from_slice(&4u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data\xff\xff\xff\xff");
+        assert!(read_wav_header(&mut &wav[..]).is_err());
+    }
+
+    #[test]
+    fn test_spectrum_peaks_at_tone() {
+        let rate = 44100;
+        let tone: Vec<f32> = (0..FFT_SIZE)
+            .map(|i| (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / rate as f32).sin())
+            .collect();
+        let bars = spectrum_bars(&tone, rate, BAR_COUNT);
+        let loudest = bars.iter().enumerate().max_by_key(|(_, h)| **h).unwrap().0;
+
+        // 1kHz sits a little over halfway up a 50Hz-16kHz log scale
+        assert_eq!(bars[loudest], 7);
+        assert!((9..=11).contains(&loudest), "peak in bar {}", loudest);
+        assert_eq!(bars[0], 0);
+
+        assert!(spectrum_bars(&[0.0; FFT_SIZE], rate, BAR_COUNT).iter().all(|&h| h == 0));
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use base64::{engine::general_purpose, Engine as _};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Clipboard tools tried in order; the first one installed and working wins
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("pbcopy", &[]),
+];
+
+/// Put `text` on the system clipboard. Returns how it got there.
+///
+/// Uses the first clipboard tool that works, falling back to the OSC 52
+/// terminal escape, which most terminals (and tmux with `set-clipboard on`)
+/// honor even over SSH.
+pub fn copy(text: &str) -> Result<&'static str> {
+    for (program, args) in CLIPBOARD_COMMANDS {
+        if pipe_to(program, args, text).is_ok() {
+            return Ok(program);
+        }
+    }
+
+    let encoded = general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", encoded)?;
+    stdout.flush()?;
+    Ok("terminal (OSC 52)")
+}
+
+fn pipe_to(program: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(anyhow::anyhow!("{} exited with {}", program, status));
+    }
+    Ok(())
+}
@@ -1,12 +1,79 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
+use crate::keymap::KeyList;
+use crate::service::ServiceType;
 use crate::ui::theme::Theme;
 
 const CONFIG_FILE_NAME: &str = "config.toml";
 
+/// Env var that relocates all persistent data (also set by `--data-dir`)
+pub const DATA_DIR_ENV: &str = "DRIFT_DATA_DIR";
+
+/// `$DRIFT_DATA_DIR`, when set and non-empty
+fn data_dir_override() -> Option<PathBuf> {
+    std::env::var_os(DATA_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Directory for config, credentials and small state files:
+/// `$DRIFT_DATA_DIR` or `~/.config/drift`
+pub fn drift_config_dir() -> Option<PathBuf> {
+    data_dir_override().or_else(|| dirs::config_dir().map(|d| d.join("drift")))
+}
+
+/// Directory for databases: `$DRIFT_DATA_DIR` or `~/.local/share/drift`
+pub fn drift_data_dir() -> Option<PathBuf> {
+    data_dir_override().or_else(|| dirs::data_dir().map(|d| d.join("drift")))
+}
+
+/// Directory for caches: `$DRIFT_DATA_DIR/cache` or `~/.cache/drift`
+pub fn drift_cache_dir() -> Option<PathBuf> {
+    data_dir_override()
+        .map(|d| d.join("cache"))
+        .or_else(|| dirs::cache_dir().map(|d| d.join("drift")))
+}
+
+/// Fully-commented default config written by `drift --init-config`
+const CONFIG_TEMPLATE: &str = include_str!("config.template.toml");
+
+/// Accepted `playback.audio_quality` values (including service-specific aliases)
+const AUDIO_QUALITIES: &[&str] = &["low", "high", "lossless", "master", "hifi", "hi_res"];
+
+/// Accepted `playback.on_failure` values
+const ON_FAILURE_MODES: &[&str] = &["skip", "stop"];
+
+/// Accepted `playback.replay_gain` values, as MPD's `replay_gain_mode`
+const REPLAY_GAIN_MODES: &[&str] = &["off", "track", "album", "auto"];
+
+/// Accepted `search.tab_action` values
+const TAB_ACTIONS: &[&str] = &["results", "filter"];
+
+/// Accepted `search.default_tab` values
+const SEARCH_TABS: &[&str] = &["tracks", "albums", "artists"];
+
+/// Accepted `ui.visualizer_on_pause` values
+const VISUALIZER_ON_PAUSE: &[&str] = &["freeze", "decay", "hide"];
+
+/// Accepted `downloads.on_existing` values
+const ON_EXISTING_MODES: &[&str] = &["skip", "overwrite", "ask"];
+
+/// Accepted `ui.visualizer_source` values
+const VISUALIZER_SOURCES: &[&str] = &["cava", "httpd"];
+
+/// Accepted `ui.scroll_style` values
+const SCROLL_STYLES: &[&str] = &["edge", "centered"];
+
+/// Accepted `ui.queue_layout` values
+const QUEUE_LAYOUTS: &[&str] = &["horizontal", "vertical"];
+
+/// Accepted `storage.backend` values
+const STORAGE_BACKENDS: &[&str] = &["local", "aspen"];
+
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -18,10 +85,19 @@ pub struct Config {
     pub downloads: DownloadsConfig,
     pub theme: Theme,
     pub service: ServiceConfig,
+    pub tidal: TidalSettings,
     pub bandcamp: BandcampConfig,
+    pub network: NetworkConfig,
     pub search: SearchConfig,
     pub video: VideoConfig,
     pub storage: StorageConfig,
+    pub listenbrainz: ListenBrainzConfig,
+    pub musicbrainz: MusicBrainzConfig,
+    pub integrations: IntegrationsConfig,
+    pub debug: DebugConfig,
+    /// Action name → keys, replacing that action's default keys; see
+    /// `crate::app::ACTIONS` for the names
+    pub keybindings: BTreeMap<String, KeyList>,
 }
 
 /// Storage backend configuration
@@ -113,6 +189,18 @@ pub struct SearchConfig {
     pub cache_enabled: bool,
     /// Cache TTL in seconds (default: 3600 = 1 hour)
     pub cache_ttl_seconds: u64,
+    /// What Tab does in search: "results" cycles result tabs, "filter" cycles
+    /// the service filter. The other action moves to '`'.
+    pub tab_action: String,
+    /// Pre-fill the search box with the last query (and its cached results)
+    pub restore_last: bool,
+    /// Result tab searches open on: "tracks", "albums" or "artists"
+    pub default_tab: String,
+    /// Stay on the last-used result tab for the rest of the session instead
+    /// of going back to `default_tab`
+    pub remember_tab: bool,
+    /// Show trending tracks in place of the empty-search hint
+    pub show_trending: bool,
 }
 
 impl Default for SearchConfig {
@@ -127,6 +215,11 @@ impl Default for SearchConfig {
             min_chars: 2,
             cache_enabled: true,
             cache_ttl_seconds: 3600,
+            tab_action: "results".to_string(),
+            restore_last: false,
+            default_tab: "tracks".to_string(),
+            remember_tab: false,
+            show_trending: false,
         }
     }
 }
@@ -159,6 +252,26 @@ impl Default for ServiceConfig {
     }
 }
 
+/// Tidal-specific configuration (credentials live in `credentials.json`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TidalSettings {
+    /// Most albums loaded for an artist's discography, fetched in pages
+    /// (0 = all)
+    pub artist_albums_limit: usize,
+    /// Top tracks shown in artist detail (1-100)
+    pub artist_top_tracks_limit: usize,
+}
+
+impl Default for TidalSettings {
+    fn default() -> Self {
+        Self {
+            artist_albums_limit: 0,
+            artist_top_tracks_limit: 20,
+        }
+    }
+}
+
 /// Bandcamp-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -184,6 +297,45 @@ impl Default for BandcampConfig {
     }
 }
 
+/// HTTP identity and request pacing used when talking to services
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+    /// User-Agent for all service requests (each service has its own default)
+    pub user_agent: Option<String>,
+    /// Extra request headers per service name, e.g. `[network.headers.bandcamp]`
+    pub headers: BTreeMap<String, BTreeMap<String, String>>,
+    /// Cap on HTTP requests per second to each service (0 = unlimited)
+    pub requests_per_second: f64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            user_agent: None,
+            headers: BTreeMap::new(),
+            requests_per_second: 5.0,
+        }
+    }
+}
+
+impl NetworkConfig {
+    /// Configured User-Agent, or the service's own default
+    pub fn user_agent_or<'a>(&'a self, default: Option<&'a str>) -> Option<&'a str> {
+        self.user_agent.as_deref().or(default)
+    }
+
+    /// Header overrides for a service, or another site drift talks to
+    /// (e.g. "listenbrainz")
+    pub fn headers_for(&self, service: impl std::fmt::Display) -> impl Iterator<Item = (&str, &str)> {
+        self.headers
+            .get(&service.to_string())
+            .into_iter()
+            .flatten()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
 /// Video playback configuration (for YouTube video mode)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -212,6 +364,87 @@ impl Default for VideoConfig {
     }
 }
 
+/// ListenBrainz listen submission
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListenBrainzConfig {
+    /// User token from listenbrainz.org/settings (unset = don't submit)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// API root, for self-hosted instances
+    pub api_url: String,
+}
+
+impl Default for ListenBrainzConfig {
+    fn default() -> Self {
+        Self {
+            token: None,
+            api_url: "https://api.listenbrainz.org".to_string(),
+        }
+    }
+}
+
+/// MusicBrainz lookups for details services leave out, like release year
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MusicBrainzConfig {
+    /// Look up each played track (one request per second at most)
+    pub enabled: bool,
+    /// API root, for mirrors
+    pub api_url: String,
+}
+
+impl Default for MusicBrainzConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            api_url: "https://musicbrainz.org".to_string(),
+        }
+    }
+}
+
+/// Files kept up to date for other programs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IntegrationsConfig {
+    /// Text file holding the playing track, for OBS and other stream
+    /// overlays (unset = off)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nowplaying_file: Option<String>,
+    /// What's written there, e.g. `{artist} — {title} [{album}]`; takes the
+    /// placeholders download paths are built from
+    pub nowplaying_format: String,
+    /// Written once playback stops (empty = clear the file)
+    pub nowplaying_idle: String,
+}
+
+impl Default for IntegrationsConfig {
+    fn default() -> Self {
+        Self {
+            nowplaying_file: None,
+            nowplaying_format: "{artist} – {title}".to_string(),
+            nowplaying_idle: String::new(),
+        }
+    }
+}
+
+/// Debug log settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebugConfig {
+    /// Debug messages kept in memory for scrolling back in the debug panel
+    pub memory_lines: usize,
+    /// Turn on developer commands, e.g. Space+U to copy the playing
+    /// track's stream URL
+    pub enabled: bool,
+}
+
+impl Default for DebugConfig {
+    fn default() -> Self {
+        Self { memory_lines: 100, enabled: false }
+    }
+}
+
 /// MPD connection settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -241,6 +474,39 @@ pub struct PlaybackConfig {
     pub audio_quality: String,
     /// Resume playback on startup
     pub resume_on_startup: bool,
+    /// Largest queue bulk actions may build (0 = unlimited)
+    pub max_queue_length: usize,
+    /// Seconds into a track after which "previous" restarts it instead of
+    /// going back (0 = always go back)
+    pub previous_restart_threshold: u64,
+    /// Refuse to queue tracks the service marks as unstreamable
+    /// (downloaded copies still play)
+    pub skip_unavailable: bool,
+    /// What to do when a queued track fails to play: "skip" or "stop"
+    pub on_failure: String,
+    /// Enter in album detail plays from the selected track to the end of the
+    /// album; false plays only the selected track
+    pub play_album_from_track: bool,
+    /// Reset Now Playing to the idle view once MPD stops (e.g. at the end of
+    /// the queue); false (the default) keeps the last track shown
+    pub clear_on_stop: bool,
+    /// Seconds over which the sleep timer fades the volume out before
+    /// pausing (0 = pause without fading)
+    pub sleep_fade_seconds: u64,
+    /// MPD crossfade between tracks in seconds, set on startup and used by
+    /// the Space+X toggle (0 = leave MPD's setting; the toggle uses 5)
+    pub crossfade_seconds: u32,
+    /// MPD `mixrampdb`: overlap tracks where their MixRamp tags reach this
+    /// volume in dB, e.g. -17 (unset = leave MPD's setting)
+    pub mixramp_db: Option<f64>,
+    /// MPD `mixrampdelay` in seconds; MixRamp is off until this is set
+    pub mixramp_delay: Option<f64>,
+    /// MPD `replay_gain_mode` set on startup: "off", "track", "album" or
+    /// "auto" (unset = leave MPD's own setting alone)
+    pub replay_gain: Option<String>,
+    /// Volume offset in dB per service name, applied when playback moves
+    /// to a track from that service, e.g. `youtube = 3.0`
+    pub service_gain_db: BTreeMap<String, f64>,
 }
 
 impl Default for PlaybackConfig {
@@ -249,10 +515,29 @@ impl Default for PlaybackConfig {
             default_volume: 80,
             audio_quality: "high".to_string(),
             resume_on_startup: true,
+            max_queue_length: 2000,
+            previous_restart_threshold: 3,
+            skip_unavailable: true,
+            on_failure: "skip".to_string(),
+            play_album_from_track: true,
+            clear_on_stop: false,
+            sleep_fade_seconds: 30,
+            crossfade_seconds: 0,
+            mixramp_db: None,
+            mixramp_delay: None,
+            replay_gain: None,
+            service_gain_db: BTreeMap::new(),
         }
     }
 }
 
+impl PlaybackConfig {
+    /// Configured volume offset for a service, 0 dB when it has none
+    pub fn gain_db_for(&self, service: ServiceType) -> f64 {
+        self.service_gain_db.get(&service.to_string()).copied().unwrap_or(0.0)
+    }
+}
+
 /// UI customization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -263,10 +548,52 @@ pub struct UiConfig {
     pub show_album_art: bool,
     /// Number of visualizer bars
     pub visualizer_bars: u8,
+    /// What the visualizer shows while paused: "freeze" keeps the last
+    /// bars, "decay" drops them to zero, "hide" removes the panel
+    pub visualizer_on_pause: String,
+    /// Where the visualizer gets its audio: "cava", or "httpd" to compute
+    /// the spectrum from an MPD httpd output streaming WAV
+    pub visualizer_source: String,
+    /// URL of the MPD httpd output read when `visualizer_source = "httpd"`
+    pub visualizer_httpd_url: String,
     /// Status check interval in milliseconds
     pub status_interval_ms: u64,
     /// Maximum album art images to keep in memory (LRU eviction)
     pub album_art_cache_size: usize,
+    /// How long a search selection must rest before its preview art is
+    /// fetched, in milliseconds (0 = fetch immediately)
+    pub art_prefetch_delay_ms: u64,
+    /// Re-read the queue from MPD right after add/remove/move instead of
+    /// waiting for the next status poll
+    pub refresh_queue_on_edit: bool,
+    /// Number of recent messages shown in the debug panel
+    pub debug_lines: usize,
+    /// Cut long debug lines to one row with '…' instead of wrapping them
+    pub debug_truncate: bool,
+    /// Show the header row; when off its connection/mode text moves to the
+    /// status bar
+    pub show_header: bool,
+    /// Require pressing Space+q twice to quit
+    pub confirm_quit: bool,
+    /// Capture the mouse for clicks; off restores the terminal's text selection
+    pub mouse: bool,
+    /// Scroll Now Playing text that's too long for the panel
+    pub marquee: bool,
+    /// Share of the window the queue panel takes, in percent (20-70)
+    pub queue_width_percent: u16,
+    /// "horizontal" puts the queue beside the content, "vertical" below it
+    pub queue_layout: String,
+    /// "edge" scrolls lists when the selection reaches an edge, "centered"
+    /// keeps the selection mid-panel
+    pub scroll_style: String,
+    /// Rows kept visible beyond the selection before a list scrolls
+    pub scrolloff: usize,
+    /// Load the first playlist's tracks on startup; false leaves the track
+    /// panel empty until a playlist is selected
+    pub autoload_first_playlist: bool,
+    /// Seconds without input while playing before the full-screen
+    /// visualizer screensaver comes up (0 = never)
+    pub screensaver_timeout: u64,
 }
 
 impl Default for UiConfig {
@@ -275,8 +602,25 @@ impl Default for UiConfig {
             show_visualizer: true,
             show_album_art: true,
             visualizer_bars: 20,
+            visualizer_on_pause: "decay".to_string(),
+            visualizer_source: "cava".to_string(),
+            visualizer_httpd_url: "http://localhost:8000".to_string(),
             status_interval_ms: 200,
             album_art_cache_size: 50,
+            art_prefetch_delay_ms: 300,
+            refresh_queue_on_edit: true,
+            debug_lines: 10,
+            debug_truncate: false,
+            show_header: true,
+            confirm_quit: false,
+            mouse: true,
+            marquee: false,
+            queue_width_percent: 40,
+            queue_layout: "horizontal".to_string(),
+            scroll_style: "edge".to_string(),
+            scrolloff: 0,
+            autoload_first_playlist: true,
+            screensaver_timeout: 0,
         }
     }
 }
@@ -294,6 +638,15 @@ pub struct DownloadsConfig {
     /// Auto-sync interval in minutes for synced playlists (0 = disabled)
     /// Periodically re-checks synced playlists for new tracks and queues them
     pub sync_interval_minutes: u64,
+    /// Free space (MB) to keep on the download filesystem; downloads that
+    /// would eat into this margin are refused
+    pub min_free_space_mb: u64,
+    /// Downloading a track that's already downloaded: "skip" it,
+    /// "overwrite" it (e.g. to re-fetch at a higher quality) or "ask"
+    pub on_existing: String,
+    /// Download bandwidth cap in bytes per second, shared by all running
+    /// downloads (0 = unlimited)
+    pub max_bytes_per_sec: u64,
 }
 
 impl Default for DownloadsConfig {
@@ -303,6 +656,9 @@ impl Default for DownloadsConfig {
             download_dir: None,
             auto_tag: true,
             sync_interval_minutes: 30,
+            min_free_space_mb: 1024,
+            on_existing: "ask".to_string(),
+            max_bytes_per_sec: 0,
         }
     }
 }
@@ -310,9 +666,8 @@ impl Default for DownloadsConfig {
 impl Config {
     /// Get the configuration file path
     pub fn config_path() -> Result<PathBuf> {
-        let config_dir = dirs::config_dir()
-            .context("Failed to get config directory")?
-            .join("drift");
+        let config_dir = drift_config_dir()
+            .context("Failed to get config directory")?;
 
         fs::create_dir_all(&config_dir)
             .context("Failed to create config directory")?;
@@ -321,23 +676,369 @@ impl Config {
     }
 
     /// Load configuration from file, or create default if not exists
+    ///
+    /// Validation warnings are logged; use [`Config::load_with_warnings`]
+    /// to surface them in the UI.
+    #[allow(dead_code)]
     pub fn load() -> Result<Self> {
+        let (config, warnings) = Self::load_with_warnings()?;
+        for warning in &warnings {
+            tracing::warn!("config: {}", warning);
+        }
+        Ok(config)
+    }
+
+    /// Load configuration, returning any validation warnings alongside it.
+    ///
+    /// Only a TOML syntax error fails outright. A wrongly typed value, like
+    /// unknown values for enum-like settings, falls back to the default for
+    /// that key.
+    pub fn load_with_warnings() -> Result<(Self, Vec<String>)> {
         let path = Self::config_path()?;
 
         if path.exists() {
             let contents = fs::read_to_string(&path)
                 .context("Failed to read config file")?;
 
-            let config: Config = toml::from_str(&contents)
-                .context("Failed to parse config file")?;
+            let (mut config, mut warnings) = Self::parse_lenient(&contents)?;
+            warnings.extend(config.validate());
 
-            Ok(config)
+            config.resolve_paths()?;
+            Ok((config, warnings))
         } else {
             // Create default config and save it
             let config = Config::default();
             config.save()?;
-            Ok(config)
+            Ok((config, Vec::new()))
+        }
+    }
+
+    /// Parse config TOML, falling back to defaults per key rather than for
+    /// the whole file when a value doesn't deserialize.
+    fn parse_lenient(contents: &str) -> Result<(Self, Vec<String>)> {
+        let table: toml::Table = contents.parse()
+            .context("Failed to parse config file")?;
+
+        if let Ok(config) = toml::Value::Table(table.clone()).try_into::<Config>() {
+            return Ok((config, Vec::new()));
+        }
+
+        // Driven by the file's own sections rather than a list of Config's
+        // fields, so a newly added section can't be left out
+        let mut warnings = Vec::new();
+        let mut valid = toml::Table::new();
+        for (name, value) in table {
+            if section_error(&name, &value).is_none() {
+                valid.insert(name, value);
+                continue;
+            }
+            // Keep the keys that are fine on their own, so one mistyped
+            // value doesn't reset the rest of its section
+            let toml::Value::Table(keys) = value else {
+                warnings.push(format!("invalid [{}] section, using defaults", name));
+                continue;
+            };
+            let mut section = toml::Table::new();
+            for (key, value) in keys {
+                let single = toml::Value::Table(toml::Table::from_iter([(key.clone(), value.clone())]));
+                match section_error(&name, &single) {
+                    Some(e) => warnings.push(format!("invalid {}.{} ({}), using its default", name, key, e)),
+                    None => {
+                        section.insert(key, value);
+                    }
+                }
+            }
+            let section = toml::Value::Table(section);
+            match section_error(&name, &section) {
+                Some(e) => warnings.push(format!("invalid [{}] section ({}), using defaults", name, e)),
+                None => {
+                    valid.insert(name, section);
+                }
+            }
+        }
+        let config = toml::Value::Table(valid).try_into()
+            .context("Failed to parse config file")?;
+        Ok((config, warnings))
+    }
+
+    /// Check enum-like and ranged settings, replacing invalid values with
+    /// defaults. Returns a human-readable warning for each fix.
+    pub fn validate(&mut self) -> Vec<String> {
+        let defaults = Config::default();
+        let mut warnings = Vec::new();
+
+        let quality = self.playback.audio_quality.to_lowercase();
+        if !AUDIO_QUALITIES.contains(&quality.as_str()) {
+            warnings.push(format!(
+                "unknown audio_quality '{}', using '{}'",
+                self.playback.audio_quality, defaults.playback.audio_quality
+            ));
+            self.playback.audio_quality = defaults.playback.audio_quality.clone();
+        }
+
+        if self.playback.default_volume > 100 {
+            warnings.push(format!(
+                "default_volume {} is above 100, using 100",
+                self.playback.default_volume
+            ));
+            self.playback.default_volume = 100;
+        }
+
+        if !ON_FAILURE_MODES.contains(&self.playback.on_failure.as_str()) {
+            warnings.push(format!(
+                "unknown on_failure '{}', using '{}'",
+                self.playback.on_failure, defaults.playback.on_failure
+            ));
+            self.playback.on_failure = defaults.playback.on_failure.clone();
+        }
+
+        if let Some(ref mode) = self.playback.replay_gain {
+            if !REPLAY_GAIN_MODES.contains(&mode.as_str()) {
+                warnings.push(format!("unknown playback.replay_gain '{}', leaving MPD's setting", mode));
+                self.playback.replay_gain = None;
+            }
+        }
+
+        // Key gains by canonical service name so aliases like "ytmusic" apply
+        for (name, db) in std::mem::take(&mut self.playback.service_gain_db) {
+            match name.parse::<ServiceType>() {
+                Ok(service) => {
+                    self.playback.service_gain_db.insert(service.to_string(), db);
+                }
+                Err(_) => warnings.push(format!("unknown service '{}' in playback.service_gain_db, ignoring it", name)),
+            }
+        }
+
+        if !TAB_ACTIONS.contains(&self.search.tab_action.as_str()) {
+            warnings.push(format!(
+                "unknown search.tab_action '{}', using '{}'",
+                self.search.tab_action, defaults.search.tab_action
+            ));
+            self.search.tab_action = defaults.search.tab_action.clone();
+        }
+
+        if !SEARCH_TABS.contains(&self.search.default_tab.as_str()) {
+            warnings.push(format!(
+                "unknown search.default_tab '{}', using '{}'",
+                self.search.default_tab, defaults.search.default_tab
+            ));
+            self.search.default_tab = defaults.search.default_tab.clone();
+        }
+
+        if !(20..=70).contains(&self.ui.queue_width_percent) {
+            let clamped = self.ui.queue_width_percent.clamp(20, 70);
+            warnings.push(format!(
+                "ui.queue_width_percent {} is outside 20-70, using {}",
+                self.ui.queue_width_percent, clamped
+            ));
+            self.ui.queue_width_percent = clamped;
+        }
+
+        if !QUEUE_LAYOUTS.contains(&self.ui.queue_layout.as_str()) {
+            warnings.push(format!(
+                "unknown ui.queue_layout '{}', using '{}'",
+                self.ui.queue_layout, defaults.ui.queue_layout
+            ));
+            self.ui.queue_layout = defaults.ui.queue_layout.clone();
+        }
+
+        if !SCROLL_STYLES.contains(&self.ui.scroll_style.as_str()) {
+            warnings.push(format!(
+                "unknown ui.scroll_style '{}', using '{}'",
+                self.ui.scroll_style, defaults.ui.scroll_style
+            ));
+            self.ui.scroll_style = defaults.ui.scroll_style.clone();
+        }
+
+        if !VISUALIZER_ON_PAUSE.contains(&self.ui.visualizer_on_pause.as_str()) {
+            warnings.push(format!(
+                "unknown ui.visualizer_on_pause '{}', using '{}'",
+                self.ui.visualizer_on_pause, defaults.ui.visualizer_on_pause
+            ));
+            self.ui.visualizer_on_pause = defaults.ui.visualizer_on_pause.clone();
+        }
+
+        if !ON_EXISTING_MODES.contains(&self.downloads.on_existing.as_str()) {
+            warnings.push(format!(
+                "unknown downloads.on_existing '{}', using '{}'",
+                self.downloads.on_existing, defaults.downloads.on_existing
+            ));
+            self.downloads.on_existing = defaults.downloads.on_existing.clone();
+        }
+
+        let unknown = crate::nowplaying::unknown_placeholders(&self.integrations.nowplaying_format);
+        if !unknown.is_empty() {
+            warnings.push(format!(
+                "unknown placeholder {{{}}} in integrations.nowplaying_format, using '{}'",
+                unknown.join("}, {"),
+                defaults.integrations.nowplaying_format
+            ));
+            self.integrations.nowplaying_format = defaults.integrations.nowplaying_format.clone();
+        }
+
+        if !VISUALIZER_SOURCES.contains(&self.ui.visualizer_source.as_str()) {
+            warnings.push(format!(
+                "unknown ui.visualizer_source '{}', using '{}'",
+                self.ui.visualizer_source, defaults.ui.visualizer_source
+            ));
+            self.ui.visualizer_source = defaults.ui.visualizer_source.clone();
+        }
+
+        if self.service.primary.parse::<ServiceType>().is_err() {
+            warnings.push(format!(
+                "unknown service.primary '{}', using '{}'",
+                self.service.primary, defaults.service.primary
+            ));
+            self.service.primary = defaults.service.primary.clone();
+        }
+
+        let (valid, invalid): (Vec<String>, Vec<String>) = std::mem::take(&mut self.service.enabled)
+            .into_iter()
+            .partition(|s| s.parse::<ServiceType>().is_ok());
+        for name in invalid {
+            warnings.push(format!("unknown service '{}' in service.enabled, ignoring it", name));
+        }
+        self.service.enabled = valid;
+
+        if !STORAGE_BACKENDS.contains(&self.storage.backend.as_str()) {
+            warnings.push(format!(
+                "unknown storage.backend '{}', using '{}'",
+                self.storage.backend, defaults.storage.backend
+            ));
+            self.storage.backend = defaults.storage.backend.clone();
+        }
+
+        warnings.extend(crate::keymap::validate(&mut self.keybindings));
+        warnings.extend(self.theme.validate());
+        warnings
+    }
+
+    /// Expand `~`, `$VAR` and XDG defaults in every path setting, and check
+    /// that paths which must already exist do.
+    ///
+    /// Errors name the offending config key so a bad path is reported at
+    /// startup instead of surfacing later as a bare IO error.
+    pub fn resolve_paths(&mut self) -> Result<()> {
+        // An empty download_dir means "use the default cache dir"
+        if self.downloads.download_dir.as_deref() == Some("") {
+            self.downloads.download_dir = None;
+        }
+        if let Some(ref mut dir) = self.downloads.download_dir {
+            resolve_path_setting("downloads.download_dir", dir)?;
+            if std::path::Path::new(dir.as_str()).is_file() {
+                return Err(InvalidPathError::new(
+                    "downloads.download_dir",
+                    dir,
+                    "exists but is not a directory",
+                ).into());
+            }
+        }
+
+        if let Some(ref mut cookie_file) = self.bandcamp.cookie_file {
+            resolve_path_setting("bandcamp.cookie_file", cookie_file)?;
+            if !std::path::Path::new(cookie_file.as_str()).is_file() {
+                return Err(InvalidPathError::new(
+                    "bandcamp.cookie_file",
+                    cookie_file,
+                    "file does not exist",
+                ).into());
+            }
+        }
+
+        if self.integrations.nowplaying_file.as_deref() == Some("") {
+            self.integrations.nowplaying_file = None;
+        }
+        if let Some(ref mut file) = self.integrations.nowplaying_file {
+            resolve_path_setting("integrations.nowplaying_file", file)?;
+        }
+
+        resolve_path_setting("video.socket_path", &mut self.video.socket_path)?;
+
+        // These may be bare names (looked up in PATH / resolved as hostnames),
+        // so only expand values that are written as paths.
+        if looks_like_path(&self.video.mpv_path) {
+            resolve_path_setting("video.mpv_path", &mut self.video.mpv_path)?;
+        }
+        if looks_like_path(&self.mpd.host) {
+            resolve_path_setting("mpd.host", &mut self.mpd.host)?;
+        }
+
+        Ok(())
+    }
+
+    /// Config keys that differ from `other` but only take effect on restart.
+    ///
+    /// Everything else (theme, UI toggles, search and sync settings) is read
+    /// from the live config on each use and applies immediately.
+    pub fn restart_required_changes(&self, other: &Config) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        if self.mpd.host != other.mpd.host || self.mpd.port != other.mpd.port {
+            changed.push("mpd");
+        }
+        if self.service.primary != other.service.primary
+            || self.service.enabled != other.service.enabled
+            || self.service.auto_detect != other.service.auto_detect
+        {
+            changed.push("service");
+        }
+        if self.storage.wants_sync() != other.storage.wants_sync()
+            || self.storage.cluster_ticket != other.storage.cluster_ticket
+            || self.storage.user_id != other.storage.user_id
+        {
+            changed.push("storage");
+        }
+        if self.playback.replay_gain != other.playback.replay_gain {
+            changed.push("playback.replay_gain");
+        }
+        if self.playback.crossfade_seconds != other.playback.crossfade_seconds
+            || self.playback.mixramp_db != other.playback.mixramp_db
+            || self.playback.mixramp_delay != other.playback.mixramp_delay
+        {
+            changed.push("playback.crossfade_seconds/mixramp");
+        }
+        if self.downloads.download_dir != other.downloads.download_dir
+            || self.downloads.max_concurrent != other.downloads.max_concurrent
+        {
+            changed.push("downloads.download_dir/max_concurrent");
+        }
+        if self.tidal.artist_albums_limit != other.tidal.artist_albums_limit
+            || self.tidal.artist_top_tracks_limit != other.tidal.artist_top_tracks_limit
+        {
+            changed.push("tidal");
         }
+        if self.bandcamp.cookie_file != other.bandcamp.cookie_file
+            || self.bandcamp.cookies_from_browser != other.bandcamp.cookies_from_browser
+        {
+            changed.push("bandcamp");
+        }
+        if self.network.user_agent != other.network.user_agent
+            || self.network.headers != other.network.headers
+            || self.network.requests_per_second != other.network.requests_per_second
+        {
+            changed.push("network");
+        }
+        if self.listenbrainz.token != other.listenbrainz.token
+            || self.listenbrainz.api_url != other.listenbrainz.api_url
+        {
+            changed.push("listenbrainz");
+        }
+        if self.musicbrainz.enabled != other.musicbrainz.enabled
+            || self.musicbrainz.api_url != other.musicbrainz.api_url
+        {
+            changed.push("musicbrainz");
+        }
+        if self.video.mpv_path != other.video.mpv_path || self.video.socket_path != other.video.socket_path {
+            changed.push("video");
+        }
+        if self.ui.visualizer_source != other.ui.visualizer_source
+            || self.ui.visualizer_httpd_url != other.ui.visualizer_httpd_url
+        {
+            changed.push("ui.visualizer_source");
+        }
+        if self.ui.album_art_cache_size != other.ui.album_art_cache_size {
+            changed.push("ui.album_art_cache_size");
+        }
+        changed
     }
 
     /// Save configuration to file
@@ -353,6 +1054,25 @@ impl Config {
         Ok(())
     }
 
+    /// Write the commented default config template to the config path.
+    ///
+    /// Returns the path and whether it was written; an existing config is
+    /// never overwritten.
+    pub fn write_template() -> Result<(PathBuf, bool)> {
+        use std::io::Write;
+
+        let path = Self::config_path()?;
+        let mut file = match fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => return Ok((path, false)),
+            Err(e) => return Err(e).context("Failed to create config file"),
+        };
+        file.write_all(CONFIG_TEMPLATE.as_bytes())
+            .context("Failed to write config file")?;
+
+        Ok((path, true))
+    }
+
     /// Get the modification time of the config file
     pub fn get_mtime() -> Option<std::time::SystemTime> {
         Self::config_path()
@@ -362,6 +1082,135 @@ impl Config {
     }
 }
 
+/// Why `value` won't deserialize as top-level section `name`, if it won't
+fn section_error(name: &str, value: &toml::Value) -> Option<String> {
+    let mut table = toml::Table::new();
+    table.insert(name.to_string(), value.clone());
+    toml::Value::Table(table).try_into::<Config>().err().map(|e| e.message().to_string())
+}
+
+/// A path setting in `config.toml` that couldn't be resolved.
+#[derive(Debug)]
+pub struct InvalidPathError {
+    pub key: &'static str,
+    pub value: String,
+    pub reason: String,
+}
+
+impl InvalidPathError {
+    fn new(key: &'static str, value: &str, reason: impl Into<String>) -> Self {
+        Self {
+            key,
+            value: value.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid path for '{}' ({:?}): {}", self.key, self.value, self.reason)
+    }
+}
+
+impl std::error::Error for InvalidPathError {}
+
+/// Expand a single path setting in place, tagging failures with its key.
+fn resolve_path_setting(key: &'static str, value: &mut String) -> Result<(), InvalidPathError> {
+    match expand_path(value) {
+        Ok(expanded) => {
+            *value = expanded;
+            Ok(())
+        }
+        Err(e) => Err(InvalidPathError::new(key, value, e.to_string())),
+    }
+}
+
+/// Whether a setting that may be a bare name is written as a filesystem path.
+fn looks_like_path(value: &str) -> bool {
+    value.starts_with('/') || value.starts_with('~') || value.starts_with('$') || value.starts_with('.')
+}
+
+/// Default for an unset XDG base directory variable, per the XDG spec.
+fn xdg_default(name: &str) -> Option<PathBuf> {
+    match name {
+        "XDG_CONFIG_HOME" => dirs::home_dir().map(|h| h.join(".config")),
+        "XDG_CACHE_HOME" => dirs::home_dir().map(|h| h.join(".cache")),
+        "XDG_DATA_HOME" => dirs::home_dir().map(|h| h.join(".local/share")),
+        "XDG_STATE_HOME" => dirs::home_dir().map(|h| h.join(".local/state")),
+        "XDG_RUNTIME_DIR" => dirs::runtime_dir(),
+        "HOME" => dirs::home_dir(),
+        _ => None,
+    }
+}
+
+/// Expand a leading `~` and `$VAR` / `${VAR}` references in a path setting.
+///
+/// Unset XDG base directory variables fall back to their spec defaults
+/// (`$XDG_CONFIG_HOME` → `~/.config`, etc). Other unset variables are an
+/// error rather than being left in the path, so a typo doesn't silently
+/// create a literal `$FOO` directory.
+pub fn expand_path(path: &str) -> Result<String> {
+    expand_path_with(path, |name| std::env::var(name).ok())
+}
+
+/// [`expand_path`] reading variables through `var`, so tests don't have to
+/// touch the process environment
+fn expand_path_with(path: &str, var: impl Fn(&str) -> Option<String>) -> Result<String> {
+    let mut expanded = String::with_capacity(path.len());
+    let mut rest = path;
+
+    if rest == "~" || rest.starts_with("~/") {
+        let home = dirs::home_dir().context("Failed to determine home directory")?;
+        expanded.push_str(&home.to_string_lossy());
+        rest = &rest[1..];
+    }
+
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            expanded.push(c);
+            continue;
+        }
+
+        let braced = chars.peek() == Some(&'{');
+        if braced {
+            chars.next();
+        }
+
+        let mut name = String::new();
+        while let Some(&n) = chars.peek() {
+            if n.is_ascii_alphanumeric() || n == '_' {
+                name.push(n);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        if braced && chars.next() != Some('}') {
+            anyhow::bail!("Unterminated '${{' in path");
+        }
+
+        if name.is_empty() {
+            // A lone '$' is kept as-is
+            expanded.push('$');
+            continue;
+        }
+
+        match var(&name) {
+            Some(value) if !value.is_empty() => expanded.push_str(&value),
+            _ => {
+                let fallback = xdg_default(&name)
+                    .with_context(|| format!("Environment variable ${} is not set", name))?;
+                expanded.push_str(&fallback.to_string_lossy());
+            }
+        }
+    }
+
+    Ok(expanded)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -450,6 +1299,237 @@ auto_tag = false
         assert!(!config.downloads.auto_tag);
     }
 
+    #[test]
+    fn test_expand_path_tilde() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(expand_path("~").unwrap(), home.to_string_lossy());
+        assert_eq!(
+            expand_path("~/Music/drift").unwrap(),
+            format!("{}/Music/drift", home.to_string_lossy())
+        );
+        // Only a leading tilde is expanded
+        assert_eq!(expand_path("/data/~music").unwrap(), "/data/~music");
+    }
+
+    #[test]
+    fn test_expand_path_env_vars() {
+        let expand = |path: &str| expand_path_with(path, |name| (name == "MUSIC").then(|| "/mnt/music".to_string()));
+        assert_eq!(expand("$MUSIC/drift").unwrap(), "/mnt/music/drift");
+        assert_eq!(expand("${MUSIC}drift").unwrap(), "/mnt/musicdrift");
+        assert_eq!(expand("/cost$").unwrap(), "/cost$");
+        assert!(expand("$UNSET_VAR/x").is_err());
+        assert!(expand("${MUSIC").is_err());
+    }
+
+    #[test]
+    fn test_expand_path_home_and_xdg_defaults() {
+        let home = std::env::var("HOME").unwrap();
+        assert_eq!(expand_path("$HOME/Music").unwrap(), format!("{}/Music", home));
+
+        let state = format!("{}/.local/state/drift", dirs::home_dir().unwrap().to_string_lossy());
+        assert_eq!(expand_path_with("$XDG_STATE_HOME/drift", |_| None).unwrap(), state);
+        // Set but empty counts as unset
+        let empty = |_: &str| Some(String::new());
+        assert_eq!(expand_path_with("$XDG_STATE_HOME/drift", empty).unwrap(), state);
+    }
+
+    #[test]
+    fn test_resolve_paths_names_offending_key() {
+        let mut config = Config::default();
+        config.downloads.download_dir = Some("$DRIFT_TEST_SURELY_UNSET_VAR/music".to_string());
+
+        let err = config.resolve_paths().unwrap_err();
+        let path_err = err.downcast_ref::<InvalidPathError>().unwrap();
+        assert_eq!(path_err.key, "downloads.download_dir");
+        assert!(err.to_string().contains("downloads.download_dir"));
+    }
+
+    #[test]
+    fn test_resolve_paths_missing_cookie_file() {
+        let mut config = Config::default();
+        config.bandcamp.cookie_file = Some("/nonexistent/drift-cookies.txt".to_string());
+
+        let err = config.resolve_paths().unwrap_err();
+        assert_eq!(err.downcast_ref::<InvalidPathError>().unwrap().key, "bandcamp.cookie_file");
+    }
+
+    #[test]
+    fn test_resolve_paths_leaves_bare_names() {
+        let mut config = Config::default();
+        config.resolve_paths().unwrap();
+        assert_eq!(config.mpd.host, "localhost");
+        assert_eq!(config.video.mpv_path, "mpv");
+        assert!(config.downloads.download_dir.is_none());
+    }
+
+    #[test]
+    fn test_restart_required_changes() {
+        let old = Config::default();
+        let mut new = Config::default();
+        assert!(old.restart_required_changes(&new).is_empty());
+
+        // Hot-reloadable settings don't need a restart
+        new.ui.show_visualizer = false;
+        new.playback.audio_quality = "lossless".to_string();
+        new.search.max_results = 10;
+        assert!(old.restart_required_changes(&new).is_empty());
+
+        new.mpd.port = 6601;
+        new.ui.album_art_cache_size = 10;
+        assert_eq!(
+            old.restart_required_changes(&new),
+            vec!["mpd", "ui.album_art_cache_size"]
+        );
+    }
+
+    #[test]
+    fn test_validate_fixes_unknown_audio_quality() {
+        let mut config: Config = toml::from_str(
+            r#"
+[playback]
+audio_quality = "lossles"
+default_volume = 50
+"#,
+        )
+        .unwrap();
+
+        let warnings = config.validate();
+        assert_eq!(warnings, vec!["unknown audio_quality 'lossles', using 'high'".to_string()]);
+        assert_eq!(config.playback.audio_quality, "high");
+        // Other values in the section are kept
+        assert_eq!(config.playback.default_volume, 50);
+    }
+
+    #[test]
+    fn test_validate_services_and_backend() {
+        let mut config = Config::default();
+        config.service.primary = "spotify".to_string();
+        config.service.enabled = vec!["tidal".to_string(), "napster".to_string()];
+        config.storage.backend = "s3".to_string();
+        config.playback.on_failure = "retry".to_string();
+
+        let warnings = config.validate();
+        assert_eq!(warnings.len(), 4);
+        assert_eq!(config.playback.on_failure, "skip");
+        assert_eq!(config.service.primary, "tidal");
+        assert_eq!(config.service.enabled, vec!["tidal".to_string()]);
+        assert_eq!(config.storage.backend, "local");
+
+        assert!(Config::default().validate().is_empty());
+    }
+
+    #[test]
+    fn test_replay_gain_and_service_gain() {
+        let mut config = Config::default();
+        config.playback.replay_gain = Some("loud".to_string());
+        assert_eq!(config.validate().len(), 1);
+        assert_eq!(config.playback.replay_gain, None);
+
+        config.playback.replay_gain = Some("album".to_string());
+        config.playback.service_gain_db.insert("ytmusic".to_string(), 3.0);
+        config.playback.service_gain_db.insert("spotify".to_string(), -2.0);
+        assert_eq!(config.validate().len(), 1);
+        assert_eq!(config.playback.gain_db_for(ServiceType::YouTube), 3.0);
+        assert_eq!(config.playback.gain_db_for(ServiceType::Tidal), 0.0);
+    }
+
+    #[test]
+    fn test_validate_nowplaying_format() {
+        let mut config = Config::default();
+        config.integrations.nowplaying_format = "{artist} — {title} [{album}]".to_string();
+        assert!(config.validate().is_empty());
+
+        config.integrations.nowplaying_format = "{Artist} - {title}".to_string();
+        assert_eq!(config.validate().len(), 1);
+        assert_eq!(config.integrations.nowplaying_format, "{artist} – {title}");
+    }
+
+    #[test]
+    fn test_validate_clamps_queue_width() {
+        let mut config = Config::default();
+        config.ui.queue_width_percent = 90;
+        assert_eq!(config.validate().len(), 1);
+        assert_eq!(config.ui.queue_width_percent, 70);
+
+        config.ui.queue_width_percent = 5;
+        config.validate();
+        assert_eq!(config.ui.queue_width_percent, 20);
+    }
+
+    #[test]
+    fn test_parse_lenient_falls_back_per_key() {
+        let toml = r#"
+[mpd]
+host = "remote-server"
+port = "not-a-number"
+
+[playback]
+default_volume = 40
+"#;
+        let (config, warnings) = Config::parse_lenient(toml).unwrap();
+
+        // The broken key falls back to its default...
+        assert_eq!(config.mpd.port, 6600);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("mpd.port"));
+        // ...but the rest of its section and the file still apply
+        assert_eq!(config.mpd.host, "remote-server");
+        assert_eq!(config.playback.default_volume, 40);
+    }
+
+    #[test]
+    fn test_parse_lenient_keeps_later_sections() {
+        let toml = r#"
+[mpd]
+port = "not-a-number"
+
+[tidal]
+artist_top_tracks_limit = 50
+
+[musicbrainz]
+enabled = true
+"#;
+        let (config, warnings) = Config::parse_lenient(toml).unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(config.tidal.artist_top_tracks_limit, 50);
+        assert!(config.musicbrainz.enabled);
+    }
+
+    #[test]
+    fn test_parse_lenient_rejects_bad_syntax() {
+        assert!(Config::parse_lenient("this is not valid [[ toml").is_err());
+    }
+
+    #[test]
+    fn test_template_matches_defaults() {
+        let (mut config, warnings) = Config::parse_lenient(CONFIG_TEMPLATE).unwrap();
+        assert!(warnings.is_empty());
+        assert!(config.validate().is_empty());
+
+        let from_template = toml::to_string(&config).unwrap();
+        let defaults = toml::to_string(&Config::default()).unwrap();
+        assert_eq!(from_template, defaults);
+    }
+
+    #[test]
+    fn test_template_documents_every_option() {
+        let template: toml::Table = CONFIG_TEMPLATE.parse().unwrap();
+        let defaults = toml::Value::try_from(Config::default()).unwrap();
+
+        for (section, values) in defaults.as_table().unwrap() {
+            let documented = template.get(section).and_then(|v| v.as_table()).unwrap();
+            for key in values.as_table().unwrap().keys() {
+                assert!(
+                    documented.contains_key(key),
+                    "config template is missing {}.{}",
+                    section,
+                    key
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_invalid_toml_returns_error() {
         let invalid_toml = "this is not valid [[ toml";
@@ -47,6 +47,8 @@ pub enum DownloadStatus {
     Completed,
     Failed,
     Paused,
+    /// Finished, but the file failed verification (truncated or not audio)
+    Corrupt,
 }
 
 impl DownloadStatus {
@@ -58,6 +60,7 @@ impl DownloadStatus {
             Self::Completed => "completed",
             Self::Failed => "failed",
             Self::Paused => "paused",
+            Self::Corrupt => "corrupt",
         }
     }
 
@@ -68,6 +71,7 @@ impl DownloadStatus {
             "completed" => Self::Completed,
             "failed" => Self::Failed,
             "paused" => Self::Paused,
+            "corrupt" => Self::Corrupt,
             _ => Self::Pending,
         }
     }
@@ -77,7 +81,7 @@ impl DownloadStatus {
             Self::Downloading => 1,
             Self::Pending => 2,
             Self::Paused => 3,
-            Self::Failed => 4,
+            Self::Failed | Self::Corrupt => 4,
             Self::Completed => 5,
         }
     }
@@ -131,9 +135,11 @@ impl From<&DownloadRecord> for Track {
             title: record.title.clone(),
             artist: record.artist.clone(),
             album: record.album.clone(),
+            album_id: None,
             duration_seconds: record.duration_seconds,
             cover_art: CoverArt::from_tidal_option(record.cover_art_id.clone()),
             service: record.service,
+            available: true,
         }
     }
 }
@@ -213,9 +219,8 @@ impl DownloadDb {
     }
 
     fn get_db_path() -> Result<PathBuf> {
-        let data_dir = dirs::data_dir()
-            .context("Failed to get data directory")?
-            .join("drift");
+        let data_dir = crate::config::drift_data_dir()
+            .context("Failed to get data directory")?;
         std::fs::create_dir_all(&data_dir)
             .context("Failed to create data directory")?;
         Ok(data_dir.join("downloads.redb"))
@@ -304,6 +309,18 @@ impl DownloadDb {
         })
     }
 
+    /// Record a finished download whose file failed verification, keeping
+    /// its path so deleting the record also removes the file
+    pub fn mark_corrupt(&self, track_id: &str, file_path: &str, error: &str) -> Result<()> {
+        let fp = file_path.to_string();
+        let err = error.to_string();
+        self.modify_download(track_id, "mark_corrupt", |r| {
+            r.status = "corrupt".to_string();
+            r.file_path = Some(fp);
+            r.error_message = Some(err);
+        })
+    }
+
     #[allow(dead_code)]
     pub fn mark_paused(&self, track_id: &str) -> Result<()> {
         self.modify_download(track_id, "mark_paused", |r| {
@@ -315,11 +332,22 @@ impl DownloadDb {
         self.get_by_status("pending")
     }
 
-    #[allow(dead_code)]
     pub fn get_downloading(&self) -> Result<Vec<DownloadRecord>> {
         self.get_by_status("downloading")
     }
 
+    /// Put downloads cut off by the last exit back in the pending list
+    pub fn requeue_interrupted(&self) -> Result<usize> {
+        let interrupted = self.get_downloading()?;
+        for record in &interrupted {
+            self.modify_download(&record.track_id, "requeue", |r| {
+                r.status = "pending".to_string();
+                r.progress_bytes = 0;
+            })?;
+        }
+        Ok(interrupted.len())
+    }
+
     #[allow(dead_code)]
     pub fn get_completed(&self) -> Result<Vec<DownloadRecord>> {
         self.get_by_status("completed")
@@ -408,7 +436,7 @@ impl DownloadDb {
             match stored.status.as_str() {
                 "pending" | "downloading" => pending += 1,
                 "completed" => completed += 1,
-                "failed" => failed += 1,
+                "failed" | "corrupt" => failed += 1,
                 _ => {}
             }
         }
@@ -424,7 +452,7 @@ impl DownloadDb {
                 None => return Ok(()),
             };
             let mut stored: StoredDownloadRecord = serde_json::from_slice(&bytes)?;
-            if stored.status == "failed" {
+            if stored.status == "failed" || stored.status == "corrupt" {
                 stored.status = "pending".to_string();
                 stored.error_message = None;
                 stored.progress_bytes = 0;
@@ -668,9 +696,11 @@ mod tests {
             title: title.to_string(),
             artist: artist.to_string(),
             album: "Test Album".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art: CoverArt::tidal("cover-123".to_string()),
             service: ServiceType::Tidal,
+            available: true,
         }
     }
 
@@ -691,6 +721,7 @@ mod tests {
         assert_eq!(DownloadStatus::Completed.as_str(), "completed");
         assert_eq!(DownloadStatus::Failed.as_str(), "failed");
         assert_eq!(DownloadStatus::Paused.as_str(), "paused");
+        assert_eq!(DownloadStatus::Corrupt.as_str(), "corrupt");
     }
 
     #[test]
@@ -700,6 +731,7 @@ mod tests {
         assert_eq!(DownloadStatus::from_str("completed"), DownloadStatus::Completed);
         assert_eq!(DownloadStatus::from_str("failed"), DownloadStatus::Failed);
         assert_eq!(DownloadStatus::from_str("paused"), DownloadStatus::Paused);
+        assert_eq!(DownloadStatus::from_str("corrupt"), DownloadStatus::Corrupt);
         assert_eq!(DownloadStatus::from_str("unknown"), DownloadStatus::Pending);
     }
 
@@ -783,6 +815,19 @@ mod tests {
         assert_eq!(all[0].status, DownloadStatus::Downloading);
     }
 
+    #[test]
+    fn test_requeue_interrupted() {
+        let db = DownloadDb::new_in_memory().unwrap();
+        db.queue_download(&create_test_track("1", "Song One", "Artist One")).unwrap();
+        db.queue_download(&create_test_track("2", "Song Two", "Artist Two")).unwrap();
+        db.update_progress("1", 500, 1000).unwrap();
+
+        assert_eq!(db.requeue_interrupted().unwrap(), 1);
+        let pending = db.get_pending().unwrap();
+        assert_eq!(pending.len(), 2);
+        assert!(pending.iter().all(|r| r.progress_bytes == 0));
+    }
+
     #[test]
     fn test_mark_completed() {
         let db = DownloadDb::new_in_memory().unwrap();
@@ -835,6 +880,19 @@ mod tests {
         assert!(failed_after.is_empty());
     }
 
+    #[test]
+    fn test_retry_corrupt() {
+        let db = DownloadDb::new_in_memory().unwrap();
+        db.queue_download(&create_test_track("1", "Song One", "Artist One")).unwrap();
+        db.mark_corrupt("1", "/tmp/song.flac", "Truncated").unwrap();
+
+        assert!(!db.is_downloaded("1"));
+        assert_eq!(db.get_download_count().unwrap(), (0, 0, 1));
+
+        db.retry_failed("1").unwrap();
+        assert_eq!(db.get_pending().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_delete_download() {
         let db = DownloadDb::new_in_memory().unwrap();
@@ -1001,9 +1059,11 @@ mod tests {
             title: "日本語タイトル".to_string(),
             artist: "アーティスト".to_string(),
             album: "Альбом".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art: CoverArt::None,
             service: ServiceType::Tidal,
+            available: true,
         };
 
         db.queue_download(&track).unwrap();
@@ -1,18 +1,26 @@
 use anyhow::{Context, Result};
 use futures_util::StreamExt;
 use std::collections::VecDeque;
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::{mpsc, Semaphore};
 
 use crate::config::DownloadsConfig;
 use crate::download_db::{DownloadDb, DownloadRecord, SyncedPlaylist};
+use crate::service::rate_limit::RateLimiter;
 use crate::service::{MusicService, Playlist, Track};
+use crate::tasks::{TaskHandle, TaskManager};
 use crate::tidal_db::TidalDb;
 
 const DEFAULT_MAX_CONCURRENT_DOWNLOADS: usize = 2;
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// How long a free-space reading is reused before `df` runs again, so
+/// queuing tracks one at a time doesn't fork a process for each
+const DISK_SPACE_CACHE_TTL: Duration = Duration::from_secs(30);
 
 // DownloadEvent is prepared for future download progress notifications
 #[derive(Debug, Clone)]
@@ -22,21 +30,45 @@ pub enum DownloadEvent {
     Progress { track_id: String, downloaded: u64, total: u64 },
     Completed { track_id: String, path: String },
     Failed { track_id: String, error: String },
+    /// A download finished but failed verification
+    Corrupt { track_id: String, title: String, error: String },
     QueueUpdated,
     PlaylistSynced { playlist_id: String, name: String, new_tracks: usize },
     /// A download completed and is ready for blob upload to the cluster.
     BlobUploadReady { track_id: String, file_path: String },
 }
 
+/// What queueing tracks for download did, per `downloads.on_existing`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueOutcome {
+    /// `queued` tracks were queued; `skipped` were already downloaded and
+    /// left alone
+    Queued { queued: usize, skipped: usize },
+    /// `on_existing = "ask"` and `existing` of the tracks are already
+    /// downloaded, so nothing was queued until the user decides
+    NeedsConfirmation { existing: usize },
+}
+
 // DownloadManager provides async download infrastructure, currently accessed via DownloadDb directly
 #[allow(dead_code)]
 pub struct DownloadManager {
-    db: DownloadDb,
+    /// Shared with downloads running on their own tasks
+    db: Arc<DownloadDb>,
     tidal_db: Option<TidalDb>,
     download_dir: PathBuf,
     semaphore: Arc<Semaphore>,
     event_tx: mpsc::UnboundedSender<DownloadEvent>,
     is_paused: bool,
+    /// Audio quality used for size estimates (matches `playback.audio_quality`)
+    audio_quality: String,
+    /// Free space (bytes) that must remain on the download filesystem
+    min_free_bytes: u64,
+    /// `downloads.on_existing`: "skip", "overwrite" or "ask"
+    on_existing: String,
+    /// `downloads.max_bytes_per_sec`, shared by every running download
+    bandwidth: Arc<RateLimiter>,
+    /// Last free-space reading, less what has been queued since
+    disk_space: Mutex<Option<(Instant, u64)>>,
 }
 
 #[allow(dead_code)]
@@ -65,13 +97,25 @@ impl DownloadManager {
                 }
             });
 
+        let db = DownloadDb::new()?;
+        match db.requeue_interrupted() {
+            Ok(0) => {}
+            Ok(n) => tracing::info!("Requeued {} interrupted download(s)", n),
+            Err(e) => tracing::warn!("Could not requeue interrupted downloads: {}", e),
+        }
+
         let manager = Self {
-            db: DownloadDb::new()?,
+            db: Arc::new(db),
             tidal_db,
             download_dir,
             semaphore: Arc::new(Semaphore::new(max_concurrent)),
             event_tx,
             is_paused: false,
+            audio_quality: "high".to_string(),
+            min_free_bytes: config.min_free_space_mb * BYTES_PER_MB,
+            on_existing: config.on_existing.clone(),
+            bandwidth: Arc::new(RateLimiter::new(config.max_bytes_per_sec as f64)),
+            disk_space: Mutex::new(None),
         };
 
         Ok((manager, event_rx))
@@ -82,9 +126,8 @@ impl DownloadManager {
         let download_dir = if let Some(ref custom_dir) = config.download_dir {
             PathBuf::from(custom_dir)
         } else {
-            dirs::cache_dir()
+            crate::config::drift_cache_dir()
                 .context("Failed to get cache directory")?
-                .join("drift")
                 .join("downloads")
         };
 
@@ -93,21 +136,99 @@ impl DownloadManager {
         Ok(download_dir)
     }
 
-    pub fn queue_track(&self, track: &Track) -> Result<()> {
-        self.db.queue_download(track)?;
-        let _ = self.event_tx.send(DownloadEvent::QueueUpdated);
+    /// Set the audio quality used when estimating download sizes.
+    pub fn set_audio_quality(&mut self, quality: &str) {
+        self.audio_quality = quality.to_string();
+    }
+
+    pub fn set_on_existing(&mut self, on_existing: &str) {
+        self.on_existing = on_existing.to_string();
+    }
+
+    /// Downloads started from now on share the new cap; running ones keep
+    /// the old one
+    pub fn set_max_bytes_per_sec(&mut self, max_bytes_per_sec: u64) {
+        self.bandwidth = Arc::new(RateLimiter::new(max_bytes_per_sec as f64));
+    }
+
+    /// Refuse to queue `tracks` if their estimated size would eat into the
+    /// configured free-space margin on the download filesystem.
+    ///
+    /// If free space can't be determined, the download is allowed. The
+    /// reading is cached for [`DISK_SPACE_CACHE_TTL`], with each queued
+    /// batch's estimate taken off it.
+    fn ensure_disk_space(&self, tracks: &[Track]) -> Result<()> {
+        let needed = self.estimate_size(tracks);
+        if needed == 0 {
+            return Ok(());
+        }
+
+        let mut disk_space = self.disk_space.lock().unwrap();
+        let available = match *disk_space {
+            Some((read_at, available)) if read_at.elapsed() < DISK_SPACE_CACHE_TTL => available,
+            _ => {
+                let Some(available) = available_disk_space(&self.download_dir) else {
+                    return Ok(());
+                };
+                *disk_space = Some((Instant::now(), available));
+                available
+            }
+        };
+
+        if needed.saturating_add(self.min_free_bytes) > available {
+            return Err(anyhow::anyhow!(
+                "Not enough disk space: need ~{}, {} free on {} (keeping {} free)",
+                format_bytes(needed),
+                format_bytes(available),
+                self.download_dir.display(),
+                format_bytes(self.min_free_bytes)
+            ));
+        }
+        if let Some((_, cached)) = disk_space.as_mut() {
+            *cached = available - needed;
+        }
         Ok(())
     }
 
-    pub fn queue_tracks(&self, tracks: &[Track]) -> Result<usize> {
-        let mut count = 0;
-        for track in tracks {
+    pub fn queue_track(&self, track: &Track) -> Result<QueueOutcome> {
+        self.queue_tracks(std::slice::from_ref(track))
+    }
+
+    /// Queue `tracks`, handling ones already downloaded per
+    /// `downloads.on_existing`. With "ask", nothing is queued if any are;
+    /// the caller asks and then calls [`DownloadManager::queue_tracks_with`].
+    pub fn queue_tracks(&self, tracks: &[Track]) -> Result<QueueOutcome> {
+        if self.on_existing == "ask" {
+            let existing = tracks.iter().filter(|t| self.is_downloaded(&t.id)).count();
+            if existing > 0 {
+                return Ok(QueueOutcome::NeedsConfirmation { existing });
+            }
+        }
+        self.queue_tracks_with(tracks, self.on_existing == "overwrite")
+    }
+
+    /// Queue `tracks`, re-downloading ones already downloaded if `overwrite`
+    /// and skipping them otherwise
+    pub fn queue_tracks_with(&self, tracks: &[Track], overwrite: bool) -> Result<QueueOutcome> {
+        let to_queue: Vec<Track> = tracks
+            .iter()
+            .filter(|t| overwrite || !self.is_downloaded(&t.id))
+            .cloned()
+            .collect();
+        let skipped = tracks.len() - to_queue.len();
+        if to_queue.is_empty() {
+            return Ok(QueueOutcome::Queued { queued: 0, skipped });
+        }
+
+        self.ensure_disk_space(&to_queue)?;
+        let mut queued = 0;
+        for track in &to_queue {
             if self.db.queue_download(track).is_ok() {
-                count += 1;
+                queued += 1;
             }
         }
         let _ = self.event_tx.send(DownloadEvent::QueueUpdated);
-        Ok(count)
+        Ok(QueueOutcome::Queued { queued, skipped })
     }
 
     pub fn has_tidal_db(&self) -> bool {
@@ -182,9 +303,12 @@ impl DownloadManager {
         self.is_paused
     }
 
-    pub async fn process_next_download<S: MusicService>(
+    /// Start the next pending download on its own task, if a download slot
+    /// is free. Returns whether one was started.
+    pub fn process_next_download<S: MusicService + Clone + 'static>(
         &self,
-        music_service: &mut S,
+        music_service: &S,
+        tasks: &mut TaskManager,
         debug_log: &mut VecDeque<String>,
     ) -> Result<bool> {
         if self.is_paused {
@@ -210,103 +334,54 @@ impl DownloadManager {
         let track = Track::from(&record);
         debug_log.push_back(format!("Starting download: {} - {}", track.artist, track.title));
 
+        // Out of the pending list, so the next tick picks another track
+        self.db.update_progress(&track.id, 0, 0)?;
         let _ = self.event_tx.send(DownloadEvent::Started {
             track_id: track.id.clone(),
             title: track.title.clone(),
         });
 
-        // Perform the download
-        match self.download_track(&track, music_service, debug_log).await {
-            Ok(path) => {
-                debug_log.push_back(format!("Download complete: {}", track.title));
-                let _ = self.event_tx.send(DownloadEvent::Completed {
-                    track_id: track.id.clone(),
-                    path,
-                });
-            }
-            Err(e) => {
-                let error = e.to_string();
-                debug_log.push_back(format!("Download failed: {} - {}", track.title, error));
-                self.db.mark_failed(&track.id, &error)?;
-                let _ = self.event_tx.send(DownloadEvent::Failed {
-                    track_id: track.id.clone(),
-                    error,
-                });
-            }
-        }
-
-        drop(permit);
-        Ok(true)
-    }
-
-    async fn download_track<S: MusicService>(
-        &self,
-        track: &Track,
-        music_service: &mut S,
-        debug_log: &mut VecDeque<String>,
-    ) -> Result<String> {
-        // Get stream URL (time-limited, must download immediately)
-        debug_log.push_back(format!("Getting stream URL for: {}", track.title));
-        let stream_url = music_service.get_stream_url(&track.id).await?;
-
-        // Determine file path
-        let file_path = self.get_download_path(track);
-        if let Some(parent) = file_path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-
-        // Download the file
-        debug_log.push_back(format!("Downloading to: {}", file_path.display()));
-
-        let client = reqwest::Client::new();
-        let response = client.get(&stream_url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(anyhow::anyhow!("HTTP error: {}", response.status()));
-        }
-
-        let total_size = response.content_length().unwrap_or(0);
-        self.db.update_progress(&track.id, 0, total_size)?;
-
-        let mut file = File::create(&file_path).await?;
-        let mut stream = response.bytes_stream();
-        let mut downloaded: u64 = 0;
-        let mut last_progress_update: u64 = 0;
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            file.write_all(&chunk).await?;
-            downloaded += chunk.len() as u64;
-
-            // Update progress every ~256KB
-            if downloaded - last_progress_update > 256 * 1024 {
-                self.db.update_progress(&track.id, downloaded, total_size)?;
-                let _ = self.event_tx.send(DownloadEvent::Progress {
-                    track_id: track.id.clone(),
-                    downloaded,
-                    total: total_size,
-                });
-                last_progress_update = downloaded;
+        let db = self.db.clone();
+        let event_tx = self.event_tx.clone();
+        let file_path = self.get_download_path(&track);
+        let mut service = music_service.clone();
+        service.set_background(true);
+        let bandwidth = self.bandwidth.clone();
+        tasks.spawn(format!("Downloading {}", track.title), move |task| async move {
+            match download_track(&track, &file_path, &mut service, &db, &event_tx, &bandwidth, &task).await {
+                Ok(path) => {
+                    let _ = event_tx.send(DownloadEvent::Completed {
+                        track_id: track.id.clone(),
+                        path,
+                    });
+                }
+                Err(e) if e.is::<CorruptDownload>() => {
+                    let error = e.to_string();
+                    let path = file_path.to_string_lossy();
+                    if let Err(e) = db.mark_corrupt(&track.id, &path, &error) {
+                        tracing::warn!("Could not mark {} corrupt: {}", track.id, e);
+                    }
+                    let _ = event_tx.send(DownloadEvent::Corrupt {
+                        track_id: track.id.clone(),
+                        title: track.title.clone(),
+                        error,
+                    });
+                }
+                Err(e) => {
+                    let error = e.to_string();
+                    if let Err(e) = db.mark_failed(&track.id, &error) {
+                        tracing::warn!("Could not mark {} failed: {}", track.id, e);
+                    }
+                    let _ = event_tx.send(DownloadEvent::Failed {
+                        track_id: track.id.clone(),
+                        error,
+                    });
+                }
             }
-        }
-
-        file.flush().await?;
-        drop(file);
-
-        // Tag the file with metadata
-        self.tag_file(&file_path, track)?;
-
-        // Mark complete in database
-        let path_str = file_path.to_string_lossy().to_string();
-        self.db.mark_completed(&track.id, &path_str)?;
-
-        // Signal that this file is ready for blob upload to the cluster
-        let _ = self.event_tx.send(DownloadEvent::BlobUploadReady {
-            track_id: track.id.clone(),
-            file_path: path_str.clone(),
+            drop(permit);
         });
 
-        Ok(path_str)
+        Ok(true)
     }
 
     fn get_download_path(&self, track: &Track) -> PathBuf {
@@ -321,20 +396,20 @@ impl DownloadManager {
             .join(format!("{}.flac", title))
     }
 
-    fn tag_file(&self, path: &PathBuf, track: &Track) -> Result<()> {
+    fn tag_file(path: &PathBuf, track: &Track) -> Result<()> {
         let extension = path.extension()
             .and_then(|e| e.to_str())
             .unwrap_or("")
             .to_lowercase();
 
         match extension.as_str() {
-            "flac" => self.tag_flac(path, track),
-            "mp3" | "m4a" => self.tag_mp3(path, track),
+            "flac" => Self::tag_flac(path, track),
+            "mp3" | "m4a" => Self::tag_mp3(path, track),
             _ => Ok(()), // Unknown format, skip tagging
         }
     }
 
-    fn tag_flac(&self, path: &PathBuf, track: &Track) -> Result<()> {
+    fn tag_flac(path: &PathBuf, track: &Track) -> Result<()> {
         match metaflac::Tag::read_from_path(path) {
             Ok(mut tag) => {
                 tag.set_vorbis("TITLE", vec![&track.title]);
@@ -350,7 +425,7 @@ impl DownloadManager {
         Ok(())
     }
 
-    fn tag_mp3(&self, path: &PathBuf, track: &Track) -> Result<()> {
+    fn tag_mp3(path: &PathBuf, track: &Track) -> Result<()> {
         use id3::{Tag, TagLike, Version};
 
         let mut tag = Tag::new();
@@ -415,6 +490,9 @@ impl DownloadManager {
     // Playlist sync methods
 
     pub fn sync_playlist(&self, playlist: &Playlist, tracks: &[Track]) -> Result<usize> {
+        let new_tracks = self.db.get_playlist_new_tracks(&playlist.id, tracks)?;
+        self.ensure_disk_space(&new_tracks)?;
+
         let new_count = self.db.sync_playlist(playlist, tracks)?;
 
         let _ = self.event_tx.send(DownloadEvent::PlaylistSynced {
@@ -448,6 +526,193 @@ impl DownloadManager {
     pub fn get_downloaded_track_ids(&self) -> Result<std::collections::HashSet<String>> {
         self.db.get_downloaded_track_ids()
     }
+
+    /// Estimate the on-disk size of downloading `tracks` at the configured quality.
+    ///
+    /// Tracks that are already downloaded are skipped, so the figure reflects
+    /// what a sync or batch download would actually add to disk.
+    pub fn estimate_size(&self, tracks: &[Track]) -> u64 {
+        let pending: Vec<Track> = tracks
+            .iter()
+            .filter(|t| !self.is_downloaded(&t.id))
+            .cloned()
+            .collect();
+        estimate_download_size(&pending, &self.audio_quality)
+    }
+}
+
+/// Fetch `track` to `file_path`, tag it and mark it complete. Runs on its own
+/// task, reporting byte progress through `task` and reading no faster
+/// than `bandwidth` allows.
+async fn download_track<S: MusicService>(
+    track: &Track,
+    file_path: &PathBuf,
+    music_service: &mut S,
+    db: &DownloadDb,
+    event_tx: &mpsc::UnboundedSender<DownloadEvent>,
+    bandwidth: &RateLimiter,
+    task: &TaskHandle,
+) -> Result<String> {
+    // Get stream URL (time-limited, must download immediately)
+    tracing::debug!("Getting stream URL for: {}", track.title);
+    let stream_url = music_service.get_stream_url(&track.id).await?;
+
+    if let Some(parent) = file_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    // Download the file
+    tracing::debug!("Downloading to: {}", file_path.display());
+
+    let client = reqwest::Client::new();
+    let response = client.get(&stream_url).send().await?;
+
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("HTTP error: {}", response.status()));
+    }
+
+    let total_size = response.content_length().unwrap_or(0);
+    db.update_progress(&track.id, 0, total_size)?;
+
+    let mut file = File::create(file_path).await?;
+    let mut stream = response.bytes_stream();
+    let mut downloaded: u64 = 0;
+    let mut last_progress_update: u64 = 0;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        bandwidth.acquire_many(chunk.len() as f64).await;
+        file.write_all(&chunk).await?;
+        downloaded += chunk.len() as u64;
+
+        // Update progress every ~256KB
+        if downloaded - last_progress_update > 256 * 1024 {
+            db.update_progress(&track.id, downloaded, total_size)?;
+            let _ = event_tx.send(DownloadEvent::Progress {
+                track_id: track.id.clone(),
+                downloaded,
+                total: total_size,
+            });
+            task.progress(downloaded, total_size);
+            last_progress_update = downloaded;
+        }
+    }
+
+    file.flush().await?;
+    drop(file);
+
+    // Before tagging, which changes the size
+    verify_download(file_path, downloaded, total_size).map_err(CorruptDownload)?;
+
+    // Tag the file with metadata
+    DownloadManager::tag_file(file_path, track)?;
+
+    // Mark complete in database
+    let path_str = file_path.to_string_lossy().to_string();
+    db.mark_completed(&track.id, &path_str)?;
+
+    // Signal that this file is ready for blob upload to the cluster
+    let _ = event_tx.send(DownloadEvent::BlobUploadReady {
+        track_id: track.id.clone(),
+        file_path: path_str.clone(),
+    });
+
+    Ok(path_str)
+}
+
+/// A download that finished but failed [`verify_download`]
+#[derive(Debug)]
+struct CorruptDownload(String);
+
+impl std::fmt::Display for CorruptDownload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CorruptDownload {}
+
+/// Check a finished download: all `expected` bytes arrived (when the server
+/// sent a Content-Length) and, if it's a FLAC, its metadata blocks parse.
+/// Other containers, including ones [`audio_format`] doesn't know, pass.
+fn verify_download(path: &Path, written: u64, expected: u64) -> std::result::Result<(), String> {
+    if expected > 0 && written != expected {
+        return Err(format!(
+            "truncated: got {} of {}",
+            format_bytes(written),
+            format_bytes(expected)
+        ));
+    }
+
+    let mut header = [0u8; 12];
+    let read = std::fs::File::open(path)
+        .and_then(|mut file| std::io::Read::read(&mut file, &mut header))
+        .map_err(|e| format!("unreadable: {}", e))?;
+    match audio_format(&header[..read]) {
+        Some("flac") => metaflac::Tag::read_from_path(path)
+            .map(|_| ())
+            .map_err(|e| format!("bad FLAC metadata: {}", e)),
+        _ => Ok(()),
+    }
+}
+
+/// Container recognised from a file's first bytes
+fn audio_format(header: &[u8]) -> Option<&'static str> {
+    match header {
+        [b'f', b'L', b'a', b'C', ..] => Some("flac"),
+        [b'I', b'D', b'3', ..] => Some("mp3"),
+        [0xFF, b, ..] if b & 0xE0 == 0xE0 => Some("mp3"),
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => Some("mp4"),
+        [b'O', b'g', b'g', b'S', ..] => Some("ogg"),
+        [0x1A, 0x45, 0xDF, 0xA3, ..] => Some("webm"),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => Some("wav"),
+        _ => None,
+    }
+}
+
+/// Free bytes on the filesystem containing `path`.
+///
+/// Uses POSIX `df -Pk` so it works on Linux and macOS without extra crates.
+/// Returns `None` if `df` is unavailable or its output can't be parsed.
+pub fn available_disk_space(path: &Path) -> Option<u64> {
+    let output = std::process::Command::new("df")
+        .arg("-Pk")
+        .arg(path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_df_available(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the "Available" column (in 1K blocks) from `df -Pk` output.
+fn parse_df_available(output: &str) -> Option<u64> {
+    let line = output.lines().nth(1)?;
+    let kb: u64 = line.split_whitespace().nth(3)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Approximate average bitrate in kbps for an `audio_quality` setting.
+///
+/// Lossless figures are typical FLAC averages rather than raw PCM rates.
+pub fn estimated_bitrate_kbps(quality: &str) -> u64 {
+    match quality.to_lowercase().as_str() {
+        "low" => 96,
+        "high" => 320,
+        "lossless" => 1000,
+        "master" | "hifi" | "hi_res" => 2500,
+        _ => 320,
+    }
+}
+
+/// Sum estimated sizes (duration × bitrate) for a set of tracks.
+pub fn estimate_download_size(tracks: &[Track], quality: &str) -> u64 {
+    let bytes_per_second = estimated_bitrate_kbps(quality) * 1000 / 8;
+    tracks
+        .iter()
+        .map(|t| t.duration_seconds as u64 * bytes_per_second)
+        .sum()
 }
 
 pub fn sanitize_filename(name: &str) -> String {
@@ -477,3 +742,81 @@ pub fn format_bytes(bytes: u64) -> String {
         format!("{} B", bytes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::{CoverArt, ServiceType};
+
+    fn track_with_duration(id: &str, duration_seconds: u32) -> Track {
+        Track {
+            id: id.to_string(),
+            title: "Title".to_string(),
+            artist: "Artist".to_string(),
+            album: "Album".to_string(),
+            album_id: None,
+            duration_seconds,
+            cover_art: CoverArt::None,
+            service: ServiceType::Tidal,
+            available: true,
+        }
+    }
+
+    #[test]
+    fn test_estimate_download_size_scales_with_quality() {
+        let tracks = vec![track_with_duration("1", 200), track_with_duration("2", 100)];
+
+        // 300s at 320 kbps = 300 * 40_000 bytes
+        assert_eq!(estimate_download_size(&tracks, "high"), 12_000_000);
+        assert!(estimate_download_size(&tracks, "lossless") > estimate_download_size(&tracks, "high"));
+        assert!(estimate_download_size(&tracks, "low") < estimate_download_size(&tracks, "high"));
+    }
+
+    #[test]
+    fn test_parse_df_available() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                      /dev/nvme0n1p2   490617784 312345678 153241234      68% /\n";
+        assert_eq!(parse_df_available(output), Some(153241234 * 1024));
+        assert_eq!(parse_df_available("Filesystem\n"), None);
+        assert_eq!(parse_df_available(""), None);
+    }
+
+    #[test]
+    fn test_estimate_download_size_unknown_quality_uses_high() {
+        let tracks = vec![track_with_duration("1", 60)];
+        assert_eq!(
+            estimate_download_size(&tracks, "lossles"),
+            estimate_download_size(&tracks, "high")
+        );
+        assert_eq!(estimate_download_size(&[], "high"), 0);
+    }
+
+    #[test]
+    fn test_audio_format() {
+        assert_eq!(audio_format(b"fLaC\0\0\0\x22"), Some("flac"));
+        assert_eq!(audio_format(b"ID3\x04\0"), Some("mp3"));
+        assert_eq!(audio_format(&[0xFF, 0xFB, 0x90, 0x64]), Some("mp3"));
+        assert_eq!(audio_format(b"\0\0\0\x20ftypM4A "), Some("mp4"));
+        assert_eq!(audio_format(&[0x1A, 0x45, 0xDF, 0xA3, 0x9F, 0x42, 0x86]), Some("webm"));
+        assert_eq!(audio_format(b"RIFF\x24\0\0\0WAVEfmt "), Some("wav"));
+        assert_eq!(audio_format(b"<!DOCTYPE html>"), None);
+        assert_eq!(audio_format(b""), None);
+    }
+
+    #[test]
+    fn test_verify_download_catches_truncation() {
+        let path = std::env::temp_dir().join(format!("drift-verify-{}.mp3", std::process::id()));
+        std::fs::write(&path, b"ID3\x04\0\0\0\0\0\0").unwrap();
+
+        assert!(verify_download(&path, 10, 10).is_ok());
+        assert!(verify_download(&path, 10, 0).is_ok());
+        assert!(verify_download(&path, 10, 4096).unwrap_err().starts_with("truncated"));
+
+        // Only a FLAC is parsed; anything else that arrived whole passes
+        std::fs::write(&path, [0x1A, 0x45, 0xDF, 0xA3, 0x9F, 0x42, 0x86, 0x81]).unwrap();
+        assert!(verify_download(&path, 8, 8).is_ok());
+        std::fs::write(&path, b"<html>403</html>").unwrap();
+        assert!(verify_download(&path, 16, 16).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+}
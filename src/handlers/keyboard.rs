@@ -1,12 +1,14 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::app::{App, DialogMode, ViewMode};
-use crate::app::state::RadioSeed;
+use crate::app::{ActionKind, App, DialogMode, ViewMode};
+use crate::keymap::Prefix;
 use crate::service::ServiceType;
-use crate::ui::library::LibraryTab;
-use crate::ui::search::SearchTab;
 use crate::ui::help_content_height;
 
+/// How long a first quit key (Space+q) stays armed when `ui.confirm_quit`
+/// is set
+const QUIT_CONFIRM_WINDOW: std::time::Duration = std::time::Duration::from_secs(2);
+
 pub enum KeyAction {
     Continue,
     Quit,
@@ -18,16 +20,19 @@ pub async fn handle_key_event(app: &mut App, key: KeyEvent) -> KeyAction {
         return handle_dialog_input(app, key).await;
     }
 
+    // Whatever move_up/move_down are bound to (j/k) scrolls help and debug
+    let movement = app.keymap.action(app.view_mode, Prefix::None, key);
+
     // Handle help panel - any key dismisses it (except j/k for scrolling)
     if app.show_help {
-        match key.code {
-            KeyCode::Char('j') => {
+        match movement {
+            Some(ActionKind::MoveDown) => {
                 let max_scroll = help_content_height().saturating_sub(20);
                 if app.help.scroll_offset < max_scroll {
                     app.help.scroll_offset += 1;
                 }
             }
-            KeyCode::Char('k') => {
+            Some(ActionKind::MoveUp) => {
                 app.help.scroll_offset = app.help.scroll_offset.saturating_sub(1);
             }
             _ => {
@@ -49,6 +54,50 @@ pub async fn handle_key_event(app: &mut App, key: KeyEvent) -> KeyAction {
         return handle_filter_input(app, key);
     }
 
+    // Handle track filter input in browse view
+    if app.view_mode == ViewMode::Browse && app.browse.filter_active {
+        return handle_browse_filter_input(app, key);
+    }
+
+    // Debug panel scrolling (PageUp/PageDown always, j/k when focused)
+    if app.show_debug && !app.key_state.space_pressed && app.key_state.pending_key.is_none() {
+        let page = app.config.ui.debug_lines.max(1) as isize;
+        match key.code {
+            KeyCode::PageUp => {
+                app.scroll_debug(page);
+                return KeyAction::Continue;
+            }
+            KeyCode::PageDown => {
+                app.scroll_debug(-page);
+                return KeyAction::Continue;
+            }
+            KeyCode::Up if app.debug_panel.focused => {
+                app.scroll_debug(1);
+                return KeyAction::Continue;
+            }
+            KeyCode::Down if app.debug_panel.focused => {
+                app.scroll_debug(-1);
+                return KeyAction::Continue;
+            }
+            KeyCode::Esc if app.debug_panel.focused => {
+                app.debug_panel.focused = false;
+                return KeyAction::Continue;
+            }
+            _ if app.debug_panel.focused => match movement {
+                Some(ActionKind::MoveUp) => {
+                    app.scroll_debug(1);
+                    return KeyAction::Continue;
+                }
+                Some(ActionKind::MoveDown) => {
+                    app.scroll_debug(-1);
+                    return KeyAction::Continue;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
     // Handle Space-prefixed commands
     if app.key_state.space_pressed {
         return handle_space_command(app, key).await;
@@ -56,13 +105,39 @@ pub async fn handle_key_event(app: &mut App, key: KeyEvent) -> KeyAction {
 
     // Handle 'g' prefix for jump commands
     if app.key_state.pending_key == Some('g') {
-        return handle_g_command(app, key);
+        return handle_g_command(app, key).await;
     }
 
     // Main helix-style commands
     handle_normal_mode(app, key).await
 }
 
+/// Insert bracketed-paste text into whichever text box has focus, as one
+/// edit. Line breaks become spaces since every box is single-line.
+pub fn handle_paste(app: &mut App, text: &str) {
+    let text = text.replace(['\r', '\n'], " ");
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+
+    if matches!(app.dialog.mode, DialogMode::CreatePlaylist | DialogMode::RenamePlaylist { .. }) {
+        app.dialog.input_text.push_str(text);
+    } else if app.dialog.mode == DialogMode::CommandPalette {
+        app.dialog.input_text.push_str(text);
+        app.dialog.selected_index = 0;
+    } else if app.search.is_active {
+        app.search.query.push_str(text);
+        app.search.show_suggestions = true;
+        app.search.history_index = -1;
+    } else if app.view_mode == ViewMode::Search && app.search.filter_active {
+        app.search.filter_query.push_str(text);
+    } else if app.view_mode == ViewMode::Browse && app.browse.filter_active {
+        app.browse.filter_query.push_str(text);
+        app.track_filter_changed();
+    }
+}
+
 async fn handle_dialog_input(app: &mut App, key: KeyEvent) -> KeyAction {
     match &app.dialog.mode {
         DialogMode::None => {}
@@ -94,6 +169,64 @@ async fn handle_dialog_input(app: &mut App, key: KeyEvent) -> KeyAction {
             }
         }
 
+        DialogMode::SleepTimer => {
+            match key.code {
+                KeyCode::Enter => {
+                    app.set_sleep_timer_from_dialog().await;
+                }
+                KeyCode::Esc => {
+                    app.close_dialog();
+                }
+                KeyCode::Backspace => {
+                    app.dialog.input_text.pop();
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() => {
+                    app.dialog.input_text.push(c);
+                }
+                _ => {}
+            }
+        }
+
+        DialogMode::CommandPalette => {
+            // Letters type into the filter, so only arrows and Ctrl+j/k move
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                KeyCode::Enter => {
+                    let action = app.selected_palette_action();
+                    app.close_dialog();
+                    match action {
+                        Some(ActionKind::Quit) => return KeyAction::Quit,
+                        Some(action) => app.run_action(action).await,
+                        None => {}
+                    }
+                }
+                KeyCode::Esc => {
+                    app.close_dialog();
+                }
+                KeyCode::Down | KeyCode::Tab => {
+                    app.move_palette_selection(1);
+                }
+                KeyCode::Up | KeyCode::BackTab => {
+                    app.move_palette_selection(-1);
+                }
+                KeyCode::Char('j') | KeyCode::Char('n') if ctrl => {
+                    app.move_palette_selection(1);
+                }
+                KeyCode::Char('k') | KeyCode::Char('p') if ctrl => {
+                    app.move_palette_selection(-1);
+                }
+                KeyCode::Backspace => {
+                    app.dialog.input_text.pop();
+                    app.dialog.selected_index = 0;
+                }
+                KeyCode::Char(c) if !ctrl => {
+                    app.dialog.input_text.push(c);
+                    app.dialog.selected_index = 0;
+                }
+                _ => {}
+            }
+        }
+
         DialogMode::AddToPlaylist { .. } => {
             // Playlist selection mode
             match key.code {
@@ -129,6 +262,39 @@ async fn handle_dialog_input(app: &mut App, key: KeyEvent) -> KeyAction {
                 _ => {}
             }
         }
+
+        DialogMode::ConfirmOverwriteDownloads { .. } => {
+            match key.code {
+                KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    app.resolve_overwrite_dialog(true);
+                }
+                KeyCode::Char('s') | KeyCode::Char('S') => {
+                    app.resolve_overwrite_dialog(false);
+                }
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
+                    app.close_dialog();
+                }
+                _ => {}
+            }
+        }
+
+        DialogMode::ConfirmSyncPlaylist { .. } | DialogMode::ConfirmDownloadAll { .. } => {
+            match key.code {
+                KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    let is_sync = matches!(app.dialog.mode, DialogMode::ConfirmSyncPlaylist { .. });
+                    app.close_dialog();
+                    if is_sync {
+                        app.sync_selected_playlist();
+                    } else {
+                        app.download_all_tracks();
+                    }
+                }
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
+                    app.close_dialog();
+                }
+                _ => {}
+            }
+        }
     }
 
     KeyAction::Continue
@@ -156,6 +322,9 @@ async fn handle_search_input(app: &mut App, key: KeyEvent) -> KeyAction {
             app.search.show_suggestions = false;
             app.search.history_index = -1;
             app.search.query.clear();
+            if app.cancel_search() {
+                app.set_status_info("Search cancelled".to_string());
+            }
         }
         KeyCode::Backspace => {
             app.search.query.pop();
@@ -234,1032 +403,142 @@ fn handle_filter_input(app: &mut App, key: KeyEvent) -> KeyAction {
         KeyCode::Char('k') | KeyCode::Up => {
             app.move_up();
         }
-        KeyCode::Tab => {
-            // Cycle through search tabs while filtering
-            app.search.tab = match app.search.tab {
-                SearchTab::Tracks => SearchTab::Albums,
-                SearchTab::Albums => SearchTab::Artists,
-                SearchTab::Artists => SearchTab::Tracks,
-            };
-        }
-        _ => {}
-    }
-    KeyAction::Continue
-}
-
-async fn handle_space_command(app: &mut App, key: KeyEvent) -> KeyAction {
-    app.key_state.space_pressed = false;
-
-    match key.code {
-        KeyCode::Char('q') => {
-            if app.playback.queue_dirty {
-                app.save_queue_state().await;
-            }
-            return KeyAction::Quit;
-        }
-        KeyCode::Char('p') => {
-            if let Err(e) = app.toggle_playback().await {
-                app.set_status_error(format!("Error toggling playback: {}", e));
-            }
-        }
-        KeyCode::Char('n') => {
-            app.add_debug("Next track".to_string());
-            if let Err(e) = app.mpd_controller.next(&mut app.debug_log).await {
-                app.set_status_error(format!("Next failed: {}", e));
-            }
-        }
-        KeyCode::Char('b') => {
-            app.add_debug("Previous track".to_string());
-            if let Err(e) = app.mpd_controller.previous(&mut app.debug_log).await {
-                app.set_status_error(format!("Previous failed: {}", e));
-            }
-        }
-        KeyCode::Char('v') => {
-            app.show_visualizer = !app.show_visualizer;
-            app.add_debug(format!("Visualizer {}", if app.show_visualizer { "enabled" } else { "disabled" }));
-        }
-        KeyCode::Char('c') => {
-            app.debug_log.clear();
-            app.add_debug("Debug log cleared".to_string());
-        }
-        KeyCode::Char('d') => {
-            app.show_debug = !app.show_debug;
-            app.add_debug(format!("Debug log {}", if app.show_debug { "shown" } else { "hidden" }));
-        }
-        KeyCode::Char('e') => {
-            let export_path = "/tmp/drift-export.log";
-            let mut content = String::new();
-            for line in &app.debug_log {
-                content.push_str(line);
-                content.push('\n');
-            }
-            if let Err(e) = std::fs::write(export_path, content) {
-                app.set_status_error(format!("Failed to export log: {}", e));
-            } else {
-                app.add_debug(format!("Debug log exported to {}", export_path));
-            }
-        }
-        _ => {}
-    }
-    KeyAction::Continue
-}
-
-fn handle_g_command(app: &mut App, key: KeyEvent) -> KeyAction {
-    app.key_state.pending_key = None;
-
-    match key.code {
-        KeyCode::Char('g') => {
-            app.jump_to_top();
+        KeyCode::Tab | KeyCode::BackTab if app.tab_cycles_search_filter() => {
+            app.cycle_search_service_filter(key.code == KeyCode::Tab);
         }
-        KeyCode::Char('e') => {
-            app.jump_to_end();
+        KeyCode::Tab | KeyCode::BackTab => {
+            // Cycle through search tabs while filtering
+            app.cycle_search_tab(key.code == KeyCode::Tab);
         }
         _ => {}
     }
     KeyAction::Continue
 }
 
-async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> KeyAction {
+fn handle_browse_filter_input(app: &mut App, key: KeyEvent) -> KeyAction {
     match key.code {
-        // Navigation
-        KeyCode::Char('h') => app.move_left(),
-        KeyCode::Char('j') => app.move_down(),
-        KeyCode::Char('k') => app.move_up(),
-        KeyCode::Char('l') => app.move_right(),
-
-        // Jump commands (prefix)
-        KeyCode::Char('g') => {
-            app.key_state.pending_key = Some('g');
-        }
-
-        // Space prefix for commands
-        KeyCode::Char(' ') => {
-            app.key_state.space_pressed = true;
+        KeyCode::Esc => {
+            app.clear_track_filter();
         }
-
-        // Enter: load playlist or play track
         KeyCode::Enter => {
-            handle_enter(app).await;
-        }
-
-        // y: yank/add to queue
-        KeyCode::Char('y') => {
-            handle_yank(app).await;
-        }
-
-        // Y: yank all
-        KeyCode::Char('Y') => {
-            if app.view_mode == ViewMode::AlbumDetail {
-                if let Err(e) = app.add_album_detail_tracks_to_queue().await {
-                    app.set_status_error(format!("Failed to add tracks: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            } else if let Err(e) = app.add_all_tracks_to_queue().await {
-                app.set_status_error(format!("Failed to add tracks: {}", e));
-            } else {
-                app.playback.queue_dirty = true;
-            }
-        }
-
-        // p: play selected
-        KeyCode::Char('p') => {
-            handle_play(app).await;
-        }
-
-        // P: toggle preview panel in search view, toggle download pause in downloads view
-        KeyCode::Char('P') => {
-            if app.view_mode == ViewMode::Search {
-                app.search.show_preview = !app.search.show_preview;
-                app.add_debug(format!("Preview panel {}", if app.search.show_preview { "ON" } else { "OFF" }));
-            } else if app.view_mode == ViewMode::Downloads {
-                app.toggle_download_pause();
-            }
-        }
-
-        // d: delete/remove from queue
-        KeyCode::Char('d') => {
-            handle_delete(app).await;
-        }
-
-        // D: clear entire queue
-        KeyCode::Char('D') => {
-            if let Err(e) = app.mpd_controller.clear_queue(&mut app.debug_log).await {
-                app.set_status_error(format!("Failed to clear queue: {}", e));
-            } else {
-                app.queue.clear();
-                app.local_queue.clear();
-                app.add_debug("Queue cleared".to_string());
-                app.playback.queue_dirty = true;
-            }
-        }
-
-        // J: Move selected track down in queue
-        KeyCode::Char('J') => {
-            handle_queue_move_down(app).await;
+            // Stop typing but keep the list narrowed
+            app.browse.filter_active = false;
         }
-
-        // K: Move selected track up in queue
-        KeyCode::Char('K') => {
-            handle_queue_move_up(app).await;
-        }
-
-        // /: search
-        KeyCode::Char('/') => {
-            app.view_mode = ViewMode::Search;
-            app.search.is_active = true;
-            app.search.query.clear();
-            app.search.show_suggestions = false;
-            app.search.history_index = -1;
-            app.add_debug("Search mode activated".to_string());
-        }
-
-        // Ctrl+F: toggle filter mode in search view
         KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            if app.view_mode == ViewMode::Search {
-                app.search.filter_active = !app.search.filter_active;
-                if !app.search.filter_active {
-                    app.search.filter_query.clear();
-                }
-                app.add_debug(format!("Filter mode {}", if app.search.filter_active { "ON" } else { "OFF" }));
-            }
-        }
-
-        // b: browse mode
-        KeyCode::Char('b') => {
-            app.view_mode = ViewMode::Browse;
-            app.add_debug("Browse mode activated".to_string());
-        }
-
-        // w: toggle queue
-        KeyCode::Char('w') => {
-            app.playback.show_queue = !app.playback.show_queue;
-            if app.playback.show_queue {
-                match app.mpd_controller.get_queue().await {
-                    Ok(queue) => {
-                        app.queue = queue;
-                        app.add_debug(format!("Queue loaded: {} tracks", app.queue.len()));
-                    }
-                    Err(e) => {
-                        app.set_status_error(format!("Failed to load queue: {}", e));
-                    }
-                }
-            }
-            app.add_debug(format!("Queue {}", if app.playback.show_queue { "shown" } else { "hidden" }));
-        }
-
-        // Tab: cycle through tabs
-        KeyCode::Tab => {
-            handle_tab(app);
-        }
-
-        // Volume controls
-        KeyCode::Char('=') | KeyCode::Char('+') => {
-            if let Err(e) = app.mpd_controller.volume_up(&mut app.debug_log).await {
-                app.set_status_error(format!("Volume error: {}", e));
-            }
-        }
-        KeyCode::Char('-') | KeyCode::Char('_') => {
-            if let Err(e) = app.mpd_controller.volume_down(&mut app.debug_log).await {
-                app.set_status_error(format!("Volume error: {}", e));
-            }
-        }
-
-        // Seek controls
-        KeyCode::Char('>') | KeyCode::Char('.') | KeyCode::Char(']') => {
-            if let Err(e) = app.mpd_controller.seek_forward(&mut app.debug_log).await {
-                app.set_status_error(format!("Seek error: {}", e));
-            }
-        }
-        KeyCode::Char('<') | KeyCode::Char(',') | KeyCode::Char('[') => {
-            if let Err(e) = app.mpd_controller.seek_backward(&mut app.debug_log).await {
-                app.set_status_error(format!("Seek error: {}", e));
-            }
-        }
-
-        // Playback mode toggles
-        KeyCode::Char('r') => {
-            if app.view_mode == ViewMode::Library {
-                app.library.loaded = false;
-                app.add_debug("Refreshing favorites...".to_string());
-            } else if let Err(e) = app.mpd_controller.toggle_repeat(&mut app.debug_log).await {
-                app.set_status_error(format!("Repeat toggle error: {}", e));
-            }
-        }
-        KeyCode::Char('s') => {
-            if let Err(e) = app.mpd_controller.toggle_random(&mut app.debug_log).await {
-                app.set_status_error(format!("Shuffle toggle error: {}", e));
-            }
-        }
-        KeyCode::Char('1') => {
-            if app.view_mode == ViewMode::Search {
-                // Toggle Tidal filter
-                app.search.service_filter = if app.search.service_filter == Some(ServiceType::Tidal) {
-                    None
-                } else {
-                    Some(ServiceType::Tidal)
-                };
-                app.search.selected_track = 0;
-                app.search.selected_album = 0;
-                app.search.selected_artist = 0;
-                app.add_debug(format!("Service filter: {:?}", app.search.service_filter));
-            } else if app.view_mode == ViewMode::Library {
-                app.library.service_filter = if app.library.service_filter == Some(ServiceType::Tidal) {
-                    None
-                } else {
-                    Some(ServiceType::Tidal)
-                };
-                app.library.selected_track = 0;
-                app.library.selected_album = 0;
-                app.library.selected_artist = 0;
-                app.add_debug(format!("Library filter: {:?}", app.library.service_filter));
-            } else if let Err(e) = app.mpd_controller.toggle_single(&mut app.debug_log).await {
-                app.set_status_error(format!("Single toggle error: {}", e));
-            }
-        }
-
-        KeyCode::Char('2') => {
-            if app.view_mode == ViewMode::Search {
-                // Toggle YouTube filter
-                app.search.service_filter = if app.search.service_filter == Some(ServiceType::YouTube) {
-                    None
-                } else {
-                    Some(ServiceType::YouTube)
-                };
-                app.search.selected_track = 0;
-                app.search.selected_album = 0;
-                app.search.selected_artist = 0;
-                app.add_debug(format!("Service filter: {:?}", app.search.service_filter));
-            } else if app.view_mode == ViewMode::Library {
-                app.library.service_filter = if app.library.service_filter == Some(ServiceType::YouTube) {
-                    None
-                } else {
-                    Some(ServiceType::YouTube)
-                };
-                app.library.selected_track = 0;
-                app.library.selected_album = 0;
-                app.library.selected_artist = 0;
-                app.add_debug(format!("Library filter: {:?}", app.library.service_filter));
-            }
-        }
-
-        KeyCode::Char('3') => {
-            if app.view_mode == ViewMode::Search {
-                // Toggle Bandcamp filter
-                app.search.service_filter = if app.search.service_filter == Some(ServiceType::Bandcamp) {
-                    None
-                } else {
-                    Some(ServiceType::Bandcamp)
-                };
-                app.search.selected_track = 0;
-                app.search.selected_album = 0;
-                app.search.selected_artist = 0;
-                app.add_debug(format!("Service filter: {:?}", app.search.service_filter));
-            } else if app.view_mode == ViewMode::Library {
-                app.library.service_filter = if app.library.service_filter == Some(ServiceType::Bandcamp) {
-                    None
-                } else {
-                    Some(ServiceType::Bandcamp)
-                };
-                app.library.selected_track = 0;
-                app.library.selected_album = 0;
-                app.library.selected_artist = 0;
-                app.add_debug(format!("Library filter: {:?}", app.library.service_filter));
-            }
-        }
-
-        KeyCode::Char('0') => {
-            if app.view_mode == ViewMode::Search {
-                // Clear service filter (show all)
-                app.search.service_filter = None;
-                app.add_debug("Service filter: All".to_string());
-            } else if app.view_mode == ViewMode::Library {
-                app.library.service_filter = None;
-                app.add_debug("Library filter: All".to_string());
-            }
-        }
-
-        // Download controls
-        KeyCode::Char('O') => {
-            app.download_selected_track();
-        }
-
-        KeyCode::Char('S') => {
-            app.sync_selected_playlist();
-        }
-
-        KeyCode::Char('o') => {
-            app.downloads.offline_mode = !app.downloads.offline_mode;
-            app.add_debug(format!("Offline mode: {}", if app.downloads.offline_mode { "ON" } else { "OFF" }));
-        }
-
-        KeyCode::Char('W') => {
-            app.view_mode = ViewMode::Downloads;
-            app.refresh_download_list();
-            app.add_debug("Downloads view".to_string());
-        }
-
-        KeyCode::Char('x') => {
-            if app.view_mode == ViewMode::Downloads {
-                app.delete_selected_download();
-            }
-        }
-
-        KeyCode::Char('R') => {
-            if app.view_mode == ViewMode::Downloads {
-                app.retry_selected_download();
-            } else if app.playback.radio_mode() {
-                // Turn off radio mode
-                app.playback.radio_seed = None;
-                app.add_debug("Radio mode OFF".to_string());
-            } else {
-                // Turn on radio - determine seed based on context
-                // Priority: ArtistDetail, AlbumDetail, Library tabs, Search tabs, Browse playlists, current track
-                if app.view_mode == ViewMode::ArtistDetail {
-                    // Artist detail view - use the viewed artist
-                    if let Some(ref artist) = app.artist_detail.artist {
-                        app.playback.radio_seed = Some(RadioSeed::Artist(artist.id.clone()));
-                        app.add_debug(format!("Artist Radio ON ({})", artist.name));
-                    } else {
-                        app.add_debug("No artist loaded for Radio".to_string());
-                    }
-                } else if app.view_mode == ViewMode::AlbumDetail {
-                    // Album detail view - use the viewed album
-                    if let Some(ref album) = app.album_detail.album {
-                        app.playback.radio_seed = Some(RadioSeed::Album(album.id.clone()));
-                        app.add_debug(format!("Album Radio ON ({})", album.title));
-                    } else {
-                        app.add_debug("No album loaded for Radio".to_string());
-                    }
-                } else if app.view_mode == ViewMode::Library && app.library.tab == LibraryTab::Artists {
-                    // Library Artists tab - use selected favorite artist
-                    if app.library.selected_artist < app.favorite_artists.len() {
-                        let artist = &app.favorite_artists[app.library.selected_artist];
-                        app.playback.radio_seed = Some(RadioSeed::Artist(artist.id.clone()));
-                        app.add_debug(format!("Artist Radio ON ({})", artist.name));
-                    } else {
-                        app.add_debug("No artist selected for Radio".to_string());
-                    }
-                } else if app.view_mode == ViewMode::Library && app.library.tab == LibraryTab::Albums {
-                    // Library Albums tab - use selected favorite album
-                    if app.library.selected_album < app.favorite_albums.len() {
-                        let album = &app.favorite_albums[app.library.selected_album];
-                        app.playback.radio_seed = Some(RadioSeed::Album(album.id.clone()));
-                        app.add_debug(format!("Album Radio ON ({})", album.title));
-                    } else {
-                        app.add_debug("No album selected for Radio".to_string());
-                    }
-                } else if app.view_mode == ViewMode::Search {
-                    if let Some(ref results) = app.search_results {
-                        match app.search.tab {
-                            SearchTab::Artists => {
-                                if app.search.selected_artist < results.artists.len() {
-                                    let artist = &results.artists[app.search.selected_artist];
-                                    app.playback.radio_seed = Some(RadioSeed::Artist(artist.id.clone()));
-                                    app.add_debug(format!("Artist Radio ON ({})", artist.name));
-                                } else {
-                                    app.add_debug("No artist selected for Radio".to_string());
-                                }
-                            }
-                            SearchTab::Albums => {
-                                if app.search.selected_album < results.albums.len() {
-                                    let album = &results.albums[app.search.selected_album];
-                                    app.playback.radio_seed = Some(RadioSeed::Album(album.id.clone()));
-                                    app.add_debug(format!("Album Radio ON ({})", album.title));
-                                } else {
-                                    app.add_debug("No album selected for Radio".to_string());
-                                }
-                            }
-                            _ => {
-                                // Fall through to track-based radio
-                                if let Some(ref track) = app.current_track {
-                                    app.playback.radio_seed = Some(RadioSeed::Track(track.id.clone()));
-                                    app.add_debug(format!("Radio ON (seed: {})", track.title));
-                                } else {
-                                    app.add_debug("No track playing for Radio seed".to_string());
-                                }
-                            }
-                        }
-                    } else if let Some(ref track) = app.current_track {
-                        app.playback.radio_seed = Some(RadioSeed::Track(track.id.clone()));
-                        app.add_debug(format!("Radio ON (seed: {})", track.title));
-                    } else {
-                        app.add_debug("No track playing for Radio seed".to_string());
-                    }
-                } else if app.view_mode == ViewMode::Browse && app.browse.selected_tab == 0 {
-                    // Playlist tab selected - use playlist as seed for mix radio
-                    if app.browse.selected_playlist < app.playlists.len() {
-                        let playlist = &app.playlists[app.browse.selected_playlist];
-                        app.playback.radio_seed = Some(RadioSeed::Playlist(playlist.id.clone()));
-                        app.add_debug(format!("Mix Radio ON (playlist: {})", playlist.title));
-                    } else {
-                        app.add_debug("No playlist selected for Mix Radio".to_string());
-                    }
-                } else if let Some(ref track) = app.current_track {
-                    // Fallback: use current playing track as seed
-                    app.playback.radio_seed = Some(RadioSeed::Track(track.id.clone()));
-                    app.add_debug(format!("Radio ON (seed: {})", track.title));
-                } else {
-                    app.add_debug("No track playing for Radio seed".to_string());
-                }
-            }
-        }
-
-        KeyCode::Char('L') => {
-            app.view_mode = ViewMode::Library;
-            if !app.library.loaded {
-                app.add_debug("Loading favorites...".to_string());
-            }
-            app.add_debug("Library view".to_string());
-        }
-
-        KeyCode::Char('f') => {
-            if app.view_mode == ViewMode::Library && app.library.tab == LibraryTab::Tracks {
-                // Remove from favorites
-                if !app.favorite_tracks.is_empty() && app.library.selected_track < app.favorite_tracks.len() {
-                    app.remove_favorite_track(app.library.selected_track).await;
-                }
-            } else if app.view_mode == ViewMode::Library && app.library.tab == LibraryTab::History {
-                // Add history track to favorites
-                if app.library.selected_history < app.history_entries.len() {
-                    let track = crate::service::Track::from(&app.history_entries[app.library.selected_history]);
-                    app.add_favorite_track(track).await;
-                }
-            } else {
-                // Add selected track to favorites
-                if let Some(track) = app.get_selected_track() {
-                    app.add_favorite_track(track).await;
-                } else {
-                    app.add_debug("No track selected to favorite".to_string());
-                }
-            }
-        }
-
-        // v: view detail (open artist/album detail view)
-        KeyCode::Char('v') => {
-            handle_view_detail(app).await;
-        }
-
-        // V: toggle video mode (YouTube videos play in mpv window)
-        KeyCode::Char('V') => {
-            if app.video_controller.is_some() {
-                app.playback.video_mode = !app.playback.video_mode;
-                let status = if app.playback.video_mode { "ON" } else { "OFF" };
-                app.add_debug(format!("Video mode: {}", status));
-                app.set_status_info(format!("Video mode: {}", status));
-            } else {
-                app.set_status_error("mpv not available for video mode".to_string());
-            }
-        }
-
-        // Esc: back navigation for detail views
-        KeyCode::Esc => {
-            if app.view_mode == ViewMode::ArtistDetail || app.view_mode == ViewMode::AlbumDetail {
-                app.pop_view();
-                app.add_debug("Back to previous view".to_string());
-            }
-        }
-
-        // ?: show help
-        KeyCode::Char('?') => {
-            app.show_help = true;
-            app.help.scroll_offset = 0;
-        }
-
-        // C: create new playlist
-        KeyCode::Char('C') => {
-            app.open_create_playlist_dialog();
-        }
-
-        // a: add track to playlist
-        KeyCode::Char('a') => {
-            handle_add_to_playlist(app);
+            app.clear_track_filter();
         }
-
-        // e: rename/edit playlist (when on playlists panel)
-        KeyCode::Char('e') => {
-            if app.view_mode == ViewMode::Browse && app.browse.selected_tab == 0
-                && app.browse.selected_playlist < app.playlists.len() {
-                    let playlist = app.playlists[app.browse.selected_playlist].clone();
-                    if playlist.id.starts_with("demo-") {
-                        app.add_debug("Cannot rename demo playlists".to_string());
-                    } else {
-                        app.open_rename_playlist_dialog(&playlist);
-                    }
-                }
+        KeyCode::Backspace => {
+            app.browse.filter_query.pop();
+            app.track_filter_changed();
         }
-
-        // X: delete playlist (when on playlists panel) or remove track from playlist (when on tracks panel)
-        KeyCode::Char('X') => {
-            if app.view_mode == ViewMode::Browse {
-                if app.browse.selected_tab == 0 {
-                    // Delete playlist
-                    if app.browse.selected_playlist < app.playlists.len() {
-                        let playlist = app.playlists[app.browse.selected_playlist].clone();
-                        if playlist.id.starts_with("demo-") {
-                            app.add_debug("Cannot delete demo playlists".to_string());
-                        } else {
-                            app.open_delete_playlist_dialog(&playlist);
-                        }
-                    }
-                } else if app.browse.selected_tab == 1 {
-                    // Remove track from current playlist
-                    app.remove_track_from_current_playlist().await;
-                }
-            }
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.browse.filter_query.push(c);
+            app.track_filter_changed();
         }
-
+        KeyCode::Down => app.move_down(),
+        KeyCode::Up => app.move_up(),
         _ => {}
     }
-
     KeyAction::Continue
 }
 
-fn handle_add_to_playlist(app: &mut App) {
-    // Find the track to add based on current view/context
-    let track = match app.view_mode {
-        ViewMode::Browse => {
-            if app.browse.selected_tab == 1 && app.browse.selected_track < app.tracks.len() {
-                Some(app.tracks[app.browse.selected_track].clone())
-            } else {
-                None
-            }
-        }
-        ViewMode::Search => {
-            if let Some(ref results) = app.search_results {
-                if app.search.tab == SearchTab::Tracks && app.search.selected_track < results.tracks.len() {
-                    Some(results.tracks[app.search.selected_track].clone())
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        }
-        ViewMode::Library => {
-            if app.library.tab == LibraryTab::Tracks && app.library.selected_track < app.favorite_tracks.len() {
-                Some(app.favorite_tracks[app.library.selected_track].clone())
-            } else if app.library.tab == LibraryTab::History && app.library.selected_history < app.history_entries.len() {
-                Some(crate::service::Track::from(&app.history_entries[app.library.selected_history]))
-            } else {
-                None
-            }
-        }
-        ViewMode::ArtistDetail => {
-            if app.artist_detail.selected_panel == 0 && app.artist_detail.selected_track < app.artist_detail.top_tracks.len() {
-                Some(app.artist_detail.top_tracks[app.artist_detail.selected_track].clone())
-            } else {
-                None
-            }
-        }
-        ViewMode::AlbumDetail => {
-            if app.album_detail.selected_track < app.album_detail.tracks.len() {
-                Some(app.album_detail.tracks[app.album_detail.selected_track].clone())
-            } else {
-                None
-            }
-        }
-        _ => None,
-    };
+async fn handle_space_command(app: &mut App, key: KeyEvent) -> KeyAction {
+    app.key_state.space_pressed = false;
 
-    if let Some(track) = track {
-        app.open_add_to_playlist_dialog(&track);
-    } else {
-        app.add_debug("No track selected to add to playlist".to_string());
+    match app.keymap.action(app.view_mode, Prefix::Space, key) {
+        Some(action) => run_key_action(app, action).await,
+        None => KeyAction::Continue,
     }
 }
 
-async fn handle_enter(app: &mut App) {
-    if app.playback.show_queue && !app.local_queue.is_empty() && app.playback.selected_queue_item < app.local_queue.len() {
-        app.add_debug(format!("Playing from queue position {}", app.playback.selected_queue_item + 1));
-        if let Err(e) = app.mpd_controller.play_position(app.playback.selected_queue_item, &mut app.debug_log).await {
-            app.set_status_error(format!("Failed to play from queue: {}", e));
-        }
-    } else if app.view_mode == ViewMode::ArtistDetail {
-        if app.artist_detail.selected_panel == 0 {
-            // Play selected top track
-            if app.artist_detail.selected_track < app.artist_detail.top_tracks.len() {
-                let track = app.artist_detail.top_tracks[app.artist_detail.selected_track].clone();
-                if let Err(e) = app.play_track(track).await {
-                    app.set_status_error(format!("Error playing track: {}", e));
-                }
-            }
-        } else {
-            // Queue entire album
-            if app.artist_detail.selected_album < app.artist_detail.albums.len() {
-                let album = app.artist_detail.albums[app.artist_detail.selected_album].clone();
-                app.add_debug(format!("Adding album to queue: {}", album.title));
-                if let Err(e) = app.add_album_by_id(&album.id).await {
-                    app.set_status_error(format!("Error adding album: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-        }
-    } else if app.view_mode == ViewMode::AlbumDetail {
-        // Play selected track
-        if app.album_detail.selected_track < app.album_detail.tracks.len() {
-            let track = app.album_detail.tracks[app.album_detail.selected_track].clone();
-            if let Err(e) = app.play_track(track).await {
-                app.set_status_error(format!("Error playing track: {}", e));
-            }
-        }
-    } else if app.view_mode == ViewMode::Browse {
-        if app.browse.selected_tab == 0 {
-            if let Err(e) = app.load_playlist(app.browse.selected_playlist).await {
-                app.set_status_error(format!("Error loading playlist: {}", e));
-            }
-        } else if app.browse.selected_tab == 1 {
-            if let Err(e) = app.play_selected_track().await {
-                app.set_status_error(format!("Error playing track: {}", e));
-            }
-        }
-    } else if app.view_mode == ViewMode::Search {
-        match app.search.tab {
-            SearchTab::Tracks => {
-                if let Err(e) = app.play_selected_track().await {
-                    app.set_status_error(format!("Error playing track: {}", e));
-                }
-            }
-            SearchTab::Albums => {
-                if let Err(e) = app.add_album_to_queue().await {
-                    app.set_status_error(format!("Error adding album: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-            SearchTab::Artists => {
-                if let Err(e) = app.add_artist_to_queue().await {
-                    app.set_status_error(format!("Error adding artist: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-        }
+async fn handle_g_command(app: &mut App, key: KeyEvent) -> KeyAction {
+    app.key_state.pending_key = None;
+
+    match app.keymap.action(app.view_mode, Prefix::G, key) {
+        Some(action) => run_key_action(app, action).await,
+        None => KeyAction::Continue,
     }
 }
 
-async fn handle_yank(app: &mut App) {
-    if app.view_mode == ViewMode::ArtistDetail {
-        if app.artist_detail.selected_panel == 0 {
-            // Add selected top track to queue
-            if app.artist_detail.selected_track < app.artist_detail.top_tracks.len() {
-                let track = app.artist_detail.top_tracks[app.artist_detail.selected_track].clone();
-                if let Err(e) = app.add_track_to_queue(track).await {
-                    app.set_status_error(format!("Failed to add track: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-        } else {
-            // Add all album tracks to queue
-            if app.artist_detail.selected_album < app.artist_detail.albums.len() {
-                let album = app.artist_detail.albums[app.artist_detail.selected_album].clone();
-                if let Err(e) = app.add_album_by_id(&album.id).await {
-                    app.set_status_error(format!("Failed to add album: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-        }
-    } else if app.view_mode == ViewMode::AlbumDetail {
-        // Add selected track to queue
-        if app.album_detail.selected_track < app.album_detail.tracks.len() {
-            let track = app.album_detail.tracks[app.album_detail.selected_track].clone();
-            if let Err(e) = app.add_track_to_queue(track).await {
-                app.set_status_error(format!("Failed to add track: {}", e));
-            } else {
-                app.playback.queue_dirty = true;
-            }
-        }
-    } else if app.view_mode == ViewMode::Search {
-        match app.search.tab {
-            SearchTab::Tracks => {
-                if let Err(e) = app.add_selected_track_to_queue().await {
-                    app.set_status_error(format!("Failed to add track: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-            SearchTab::Albums => {
-                if let Err(e) = app.add_album_to_queue().await {
-                    app.set_status_error(format!("Failed to add album: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-            SearchTab::Artists => {
-                if let Err(e) = app.add_artist_to_queue().await {
-                    app.set_status_error(format!("Failed to add artist: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-        }
-    } else if app.view_mode == ViewMode::Library && app.library.tab == LibraryTab::History {
-        // Add history track to queue
-        if app.library.selected_history < app.history_entries.len() {
-            let track = crate::service::Track::from(&app.history_entries[app.library.selected_history]);
-            if let Err(e) = app.add_track_to_queue(track).await {
-                app.set_status_error(format!("Failed to add track: {}", e));
-            } else {
-                app.playback.queue_dirty = true;
+async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> KeyAction {
+    if key.modifiers.is_empty() {
+        match key.code {
+            // Jump commands (prefix)
+            KeyCode::Char('g') => {
+                app.key_state.pending_key = Some('g');
+                return KeyAction::Continue;
+            }
+            // Space prefix for commands
+            KeyCode::Char(' ') => {
+                app.key_state.space_pressed = true;
+                return KeyAction::Continue;
+            }
+            // 0-3 pick the service filter in search and library, whatever
+            // they're bound to elsewhere
+            KeyCode::Char(c @ '0'..='3') if matches!(app.view_mode, ViewMode::Search | ViewMode::Library) => {
+                let service = match c {
+                    '1' => Some(ServiceType::Tidal),
+                    '2' => Some(ServiceType::YouTube),
+                    '3' => Some(ServiceType::Bandcamp),
+                    _ => None,
+                };
+                set_service_filter(app, service);
+                return KeyAction::Continue;
             }
+            _ => {}
         }
-    } else if let Err(e) = app.add_selected_track_to_queue().await {
-        app.set_status_error(format!("Failed to add track: {}", e));
-    } else {
-        app.playback.queue_dirty = true;
     }
-}
 
-async fn handle_play(app: &mut App) {
-    if app.playback.show_queue && !app.local_queue.is_empty() && app.playback.selected_queue_item < app.local_queue.len() {
-        app.add_debug(format!("Playing from queue position {}", app.playback.selected_queue_item + 1));
-        if let Err(e) = app.mpd_controller.play_position(app.playback.selected_queue_item, &mut app.debug_log).await {
-            app.set_status_error(format!("Failed to play from queue: {}", e));
-        }
-    } else if app.view_mode == ViewMode::ArtistDetail {
-        if app.artist_detail.selected_panel == 0 {
-            // Play selected top track
-            if app.artist_detail.selected_track < app.artist_detail.top_tracks.len() {
-                let track = app.artist_detail.top_tracks[app.artist_detail.selected_track].clone();
-                if let Err(e) = app.play_track(track).await {
-                    app.set_status_error(format!("Error playing track: {}", e));
-                }
-            }
-        } else {
-            // Queue and play album
-            if app.artist_detail.selected_album < app.artist_detail.albums.len() {
-                let album = app.artist_detail.albums[app.artist_detail.selected_album].clone();
-                if let Err(e) = app.add_album_by_id(&album.id).await {
-                    app.set_status_error(format!("Error adding album: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-        }
-    } else if app.view_mode == ViewMode::AlbumDetail {
-        // Play selected track
-        if app.album_detail.selected_track < app.album_detail.tracks.len() {
-            let track = app.album_detail.tracks[app.album_detail.selected_track].clone();
-            if let Err(e) = app.play_track(track).await {
-                app.set_status_error(format!("Error playing track: {}", e));
-            }
-        }
-    } else if app.view_mode == ViewMode::Browse && app.browse.selected_tab == 1 {
-        if let Err(e) = app.play_selected_track().await {
-            app.set_status_error(format!("Error playing track: {}", e));
-        }
-    } else if app.view_mode == ViewMode::Search {
-        match app.search.tab {
-            SearchTab::Tracks => {
-                if let Err(e) = app.play_selected_track().await {
-                    app.set_status_error(format!("Error playing track: {}", e));
-                }
-            }
-            SearchTab::Albums => {
-                if let Err(e) = app.add_album_to_queue().await {
-                    app.set_status_error(format!("Error adding album: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-            SearchTab::Artists => {
-                if let Err(e) = app.add_artist_to_queue().await {
-                    app.set_status_error(format!("Error adding artist: {}", e));
-                } else {
-                    app.playback.queue_dirty = true;
-                }
-            }
-        }
+    match app.keymap.action(app.view_mode, Prefix::None, key) {
+        Some(action) => run_key_action(app, action).await,
+        None => KeyAction::Continue,
     }
 }
 
-async fn handle_delete(app: &mut App) {
-    if app.playback.show_queue && !app.local_queue.is_empty()
-        && app.playback.selected_queue_item < app.local_queue.len() {
-            if let Err(e) = app.mpd_controller.remove_from_queue(app.playback.selected_queue_item, &mut app.debug_log).await {
-                app.set_status_error(format!("Failed to remove track: {}", e));
-            } else {
-                app.local_queue.remove(app.playback.selected_queue_item);
-                if app.playback.selected_queue_item > 0 && app.playback.selected_queue_item >= app.local_queue.len() {
-                    app.playback.selected_queue_item -= 1;
+/// Run a bound action; quitting may want a second press
+async fn run_key_action(app: &mut App, action: ActionKind) -> KeyAction {
+    match action {
+        ActionKind::Quit => {
+            if app.config.ui.confirm_quit {
+                // Require a second press within the window
+                let armed = app.key_state.quit_armed_at.take();
+                if !armed.is_some_and(|at| at.elapsed() < QUIT_CONFIRM_WINDOW) {
+                    app.key_state.quit_armed_at = Some(std::time::Instant::now());
+                    let key = app.keymap.label(ActionKind::Quit).unwrap_or("quit").to_string();
+                    app.set_status_info(format!("Press {} again to quit", key));
+                    return KeyAction::Continue;
                 }
-                app.add_debug(format!("Removed track from queue, {} remaining", app.local_queue.len()));
-                app.playback.queue_dirty = true;
             }
+            return KeyAction::Quit;
         }
-}
-
-async fn handle_queue_move_up(app: &mut App) {
-    // Only works when queue is visible and has items
-    if !app.playback.show_queue || app.local_queue.is_empty() {
-        return;
-    }
-
-    let selected = app.playback.selected_queue_item;
-
-    // Can't move first item up
-    if selected == 0 {
-        return;
-    }
-
-    let target = selected - 1;
-
-    // Move in MPD first
-    if let Err(e) = app
-        .mpd_controller
-        .move_in_queue(selected, target, &mut app.debug_log)
-        .await
-    {
-        app.set_status_error(format!("Failed to move track up: {}", e));
-        return;
-    }
-
-    // Update local queue
-    app.local_queue.swap(selected, target);
-
-    // Also update the QueueItem vec if populated
-    if !app.queue.is_empty() && selected < app.queue.len() && target < app.queue.len() {
-        app.queue.swap(selected, target);
-    }
-
-    // Move selection to follow the track
-    app.playback.selected_queue_item = target;
-    app.playback.queue_dirty = true;
-}
-
-async fn handle_queue_move_down(app: &mut App) {
-    // Only works when queue is visible and has items
-    if !app.playback.show_queue || app.local_queue.is_empty() {
-        return;
-    }
-
-    let selected = app.playback.selected_queue_item;
-
-    // Can't move last item down
-    if selected >= app.local_queue.len() - 1 {
-        return;
-    }
-
-    let target = selected + 1;
-
-    // Move in MPD first
-    if let Err(e) = app
-        .mpd_controller
-        .move_in_queue(selected, target, &mut app.debug_log)
-        .await
-    {
-        app.set_status_error(format!("Failed to move track down: {}", e));
-        return;
-    }
-
-    // Update local queue
-    app.local_queue.swap(selected, target);
-
-    // Also update the QueueItem vec if populated
-    if !app.queue.is_empty() && selected < app.queue.len() && target < app.queue.len() {
-        app.queue.swap(selected, target);
+        action => app.run_action(action).await,
     }
-
-    // Move selection to follow the track
-    app.playback.selected_queue_item = target;
-    app.playback.queue_dirty = true;
+    KeyAction::Continue
 }
 
-fn handle_tab(app: &mut App) {
-    if app.view_mode == ViewMode::Browse {
-        app.browse.selected_tab = (app.browse.selected_tab + 1) % 2;
-        app.add_debug(format!("Switched to {} panel",
-            if app.browse.selected_tab == 0 { "playlists" } else { "tracks" }));
-    } else if app.view_mode == ViewMode::ArtistDetail {
-        app.artist_detail.selected_panel = (app.artist_detail.selected_panel + 1) % 2;
-        app.add_debug(format!("Switched to {} panel",
-            if app.artist_detail.selected_panel == 0 { "top tracks" } else { "albums" }));
+/// Toggle the search or library service filter to `service`; None shows all
+fn set_service_filter(app: &mut App, service: Option<ServiceType>) {
+    if app.view_mode == ViewMode::Search {
+        if service.is_none() {
+            app.search.service_filter = None;
+            app.add_debug("Service filter: All".to_string());
+            return;
+        }
+        app.search.service_filter = if app.search.service_filter == service { None } else { service };
+        app.search.selected_track = 0;
+        app.search.selected_album = 0;
+        app.search.selected_artist = 0;
+        app.add_debug(format!("Service filter: {:?}", app.search.service_filter));
     } else if app.view_mode == ViewMode::Library {
-        app.library.tab = match app.library.tab {
-            LibraryTab::Tracks => LibraryTab::Albums,
-            LibraryTab::Albums => LibraryTab::Artists,
-            LibraryTab::Artists => LibraryTab::History,
-            LibraryTab::History => LibraryTab::Tracks,
-        };
-        app.add_debug(format!("Switched to {:?} tab", app.library.tab));
-    } else if app.view_mode == ViewMode::Search {
-        app.search.tab = match app.search.tab {
-            SearchTab::Tracks => SearchTab::Albums,
-            SearchTab::Albums => SearchTab::Artists,
-            SearchTab::Artists => SearchTab::Tracks,
-        };
-        app.add_debug(format!("Switched to {:?} results", app.search.tab));
-    }
-}
-
-async fn handle_view_detail(app: &mut App) {
-    match app.view_mode {
-        ViewMode::Search => {
-            if let Some(ref results) = app.search_results {
-                match app.search.tab {
-                    SearchTab::Artists => {
-                        if app.search.selected_artist < results.artists.len() {
-                            let artist = results.artists[app.search.selected_artist].clone();
-                            app.add_debug(format!("Opening artist: {}", artist.name));
-                            app.push_view(ViewMode::ArtistDetail);
-                            app.load_artist_detail(artist).await;
-                        }
-                    }
-                    SearchTab::Albums => {
-                        if app.search.selected_album < results.albums.len() {
-                            let album = results.albums[app.search.selected_album].clone();
-                            app.add_debug(format!("Opening album: {}", album.title));
-                            app.push_view(ViewMode::AlbumDetail);
-                            app.load_album_detail(album).await;
-                        }
-                    }
-                    _ => {
-                        app.add_debug("Use 'v' on Artists or Albums tab".to_string());
-                    }
-                }
-            }
-        }
-        ViewMode::Library => {
-            match app.library.tab {
-                LibraryTab::Artists => {
-                    if app.library.selected_artist < app.favorite_artists.len() {
-                        let artist = app.favorite_artists[app.library.selected_artist].clone();
-                        app.add_debug(format!("Opening artist: {}", artist.name));
-                        app.push_view(ViewMode::ArtistDetail);
-                        app.load_artist_detail(artist).await;
-                    }
-                }
-                LibraryTab::Albums => {
-                    if app.library.selected_album < app.favorite_albums.len() {
-                        let album = app.favorite_albums[app.library.selected_album].clone();
-                        app.add_debug(format!("Opening album: {}", album.title));
-                        app.push_view(ViewMode::AlbumDetail);
-                        app.load_album_detail(album).await;
-                    }
-                }
-                _ => {
-                    app.add_debug("Use 'v' on Artists or Albums tab".to_string());
-                }
-            }
-        }
-        ViewMode::ArtistDetail => {
-            // From artist detail, 'v' on an album opens album detail
-            if app.artist_detail.selected_panel == 1 {
-                if app.artist_detail.selected_album < app.artist_detail.albums.len() {
-                    let album = app.artist_detail.albums[app.artist_detail.selected_album].clone();
-                    app.add_debug(format!("Opening album: {}", album.title));
-                    app.push_view(ViewMode::AlbumDetail);
-                    app.load_album_detail(album).await;
-                }
-            } else {
-                app.add_debug("Switch to albums panel (h/l) to view album details".to_string());
-            }
-        }
-        _ => {
-            app.add_debug("Use 'v' in Search or Library view on artists/albums".to_string());
-        }
+        if service.is_none() {
+            app.library.service_filter = None;
+            app.add_debug("Library filter: All".to_string());
+            return;
+        }
+        app.library.service_filter = if app.library.service_filter == service { None } else { service };
+        app.library.selected_track = 0;
+        app.library.selected_album = 0;
+        app.library.selected_artist = 0;
+        app.add_debug(format!("Library filter: {:?}", app.library.service_filter));
     }
 }
@@ -1,3 +1,3 @@
 mod keyboard;
 
-pub use keyboard::{handle_key_event, KeyAction};
+pub use keyboard::{handle_key_event, handle_paste, KeyAction};
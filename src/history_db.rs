@@ -39,6 +39,13 @@ pub struct HistoryEntry {
     pub played_at: DateTime<Utc>,
 }
 
+impl HistoryEntry {
+    /// Entry for a play of `track` starting at `played_at`, not yet stored
+    pub fn new(track: &Track, played_at: DateTime<Utc>) -> Self {
+        StoredEntry::from_track(track, played_at.timestamp_millis() as u64).to_history_entry(0)
+    }
+}
+
 impl From<&HistoryEntry> for Track {
     fn from(entry: &HistoryEntry) -> Self {
         Track {
@@ -46,9 +53,11 @@ impl From<&HistoryEntry> for Track {
             title: entry.title.clone(),
             artist: entry.artist.clone(),
             album: entry.album.clone(),
+            album_id: None,
             duration_seconds: entry.duration_seconds,
             cover_art: CoverArt::from_tidal_option(entry.cover_art_id.clone()),
             service: entry.service,
+            available: true,
         }
     }
 }
@@ -107,9 +116,8 @@ impl HistoryDb {
     }
 
     fn get_db_path() -> Result<PathBuf> {
-        let data_dir = dirs::data_dir()
-            .context("Failed to get data directory")?
-            .join("drift");
+        let data_dir = crate::config::drift_data_dir()
+            .context("Failed to get data directory")?;
         std::fs::create_dir_all(&data_dir)
             .context("Failed to create data directory")?;
         Ok(data_dir.join("history.redb"))
@@ -237,9 +245,11 @@ mod tests {
             title: title.to_string(),
             artist: artist.to_string(),
             album: "Test Album".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art: CoverArt::tidal("cover-123".to_string()),
             service: ServiceType::Tidal,
+            available: true,
         }
     }
 
@@ -369,9 +379,11 @@ mod tests {
             title: "日本語タイトル".to_string(),
             artist: "アーティスト".to_string(),
             album: "Альбом".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art: CoverArt::None,
             service: ServiceType::Tidal,
+            available: true,
         };
 
         db.record_play(&track).unwrap();
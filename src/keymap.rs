@@ -0,0 +1,351 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{ActionKind, ViewMode, ACTIONS};
+
+/// Keys for one `[keybindings]` action: `"j"` or `["j", "down"]`. Each is
+/// a key (`"J"`, `"ctrl+f"`, `"shift+right"`, `"enter"`) or a prefix and a
+/// key (`"space q"`, `"g g"`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    pub fn specs(&self) -> &[String] {
+        match self {
+            KeyList::One(spec) => std::slice::from_ref(spec),
+            KeyList::Many(specs) => specs,
+        }
+    }
+}
+
+/// The key pressed before the one that picks the action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prefix {
+    None,
+    Space,
+    G,
+}
+
+/// Key → action for each prefix. Built from every action's default keys,
+/// with `[keybindings]` entries replacing the defaults of their action.
+pub struct Keymap {
+    /// Bindings per prefix: everywhere (None) and for each view's own
+    /// actions, which are checked first
+    tables: HashMap<(Option<ViewMode>, Prefix), HashMap<KeyEvent, ActionKind>>,
+    /// Keys still bound to each action, in the order they're configured
+    bindings: HashMap<ActionKind, Vec<(Prefix, KeyEvent)>>,
+    /// Label of each action's first key, for hints and the command palette
+    labels: HashMap<ActionKind, String>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&BTreeMap::new())
+    }
+}
+
+impl Keymap {
+    /// Build from `[keybindings]`; specs that don't parse are skipped (and
+    /// reported by [`validate`])
+    pub fn new(overrides: &BTreeMap<String, KeyList>) -> Self {
+        let mut keymap = Keymap {
+            tables: HashMap::new(),
+            bindings: HashMap::new(),
+            labels: HashMap::new(),
+        };
+
+        let specs_for = |id: &str, defaults: &'static [&'static str]| -> Vec<String> {
+            match overrides.get(id) {
+                Some(keys) => keys.specs().to_vec(),
+                None => defaults.iter().map(|s| s.to_string()).collect(),
+            }
+        };
+
+        // Defaults first so a rebound key takes over from whatever had it
+        for pass_overrides in [false, true] {
+            for entry in ACTIONS {
+                if overrides.contains_key(entry.id) != pass_overrides {
+                    continue;
+                }
+                for spec in specs_for(entry.id, entry.keys) {
+                    if let Ok((prefix, key)) = parse_binding(&spec) {
+                        keymap.tables.entry((entry.view, prefix)).or_default().insert(key, entry.action);
+                    }
+                }
+            }
+        }
+
+        for entry in ACTIONS {
+            let bound: Vec<(Prefix, KeyEvent)> = specs_for(entry.id, entry.keys)
+                .iter()
+                .filter_map(|spec| parse_binding(spec).ok())
+                .filter(|&(prefix, key)| {
+                    keymap.tables.get(&(entry.view, prefix)).and_then(|table| table.get(&key)) == Some(&entry.action)
+                })
+                .collect();
+            if let Some(&(prefix, key)) = bound.first() {
+                keymap.labels.insert(entry.action, binding_label(prefix, key));
+                keymap.bindings.insert(entry.action, bound);
+            }
+        }
+        keymap
+    }
+
+    /// The action `key` triggers after `prefix` in `view`
+    pub fn action(&self, view: ViewMode, prefix: Prefix, key: KeyEvent) -> Option<ActionKind> {
+        let key = normalize(key);
+        [Some(view), None]
+            .into_iter()
+            .find_map(|scope| self.tables.get(&(scope, prefix))?.get(&key).copied())
+    }
+
+    /// How to trigger `action`, e.g. "Space+p", or None when it's unbound
+    pub fn label(&self, action: ActionKind) -> Option<&str> {
+        self.labels.get(&action).map(String::as_str)
+    }
+
+    /// The key that triggers `action` after `prefix`, e.g. "p" for
+    /// Space+p, or None when none does
+    pub fn key_after(&self, prefix: Prefix, action: ActionKind) -> Option<String> {
+        self.bindings
+            .get(&action)?
+            .iter()
+            .find(|&&(bound_prefix, _)| bound_prefix == prefix)
+            .map(|&(_, key)| key_label(key))
+    }
+}
+
+/// Drop `[keybindings]` entries for unknown actions and keys that don't
+/// parse, returning a warning for each
+pub fn validate(overrides: &mut BTreeMap<String, KeyList>) -> Vec<String> {
+    let mut warnings = Vec::new();
+    overrides.retain(|id, keys| {
+        if !ACTIONS.iter().any(|entry| entry.id == id) {
+            warnings.push(format!("unknown action '{}' in keybindings, ignoring it", id));
+            return false;
+        }
+        let (valid, invalid): (Vec<String>, Vec<String>) =
+            keys.specs().iter().cloned().partition(|spec| parse_binding(spec).is_ok());
+        for spec in invalid {
+            let error = parse_binding(&spec).unwrap_err();
+            warnings.push(format!("keybindings.{}: {}, ignoring it", id, error));
+        }
+        *keys = KeyList::Many(valid);
+        true
+    });
+    warnings
+}
+
+/// Parse `"q"`, `"space q"` or `"g g"` into a prefix and key
+pub fn parse_binding(spec: &str) -> Result<(Prefix, KeyEvent), String> {
+    let tokens: Vec<&str> = spec.split_whitespace().collect();
+    let (prefix, key) = match tokens.as_slice() {
+        [key] => (Prefix::None, parse_key(key)?),
+        [prefix, key] => {
+            let prefix = match *prefix {
+                "space" => Prefix::Space,
+                "g" => Prefix::G,
+                other => return Err(format!("'{}' can't start a two-key binding, only space and g can", other)),
+            };
+            (prefix, parse_key(key)?)
+        }
+        _ => return Err(format!("'{}' is not a key or a prefix and a key", spec)),
+    };
+
+    // Space and g always wait for a second key
+    if prefix == Prefix::None && key.modifiers.is_empty() && matches!(key.code, KeyCode::Char(' ') | KeyCode::Char('g')) {
+        return Err(format!("'{}' is a prefix; bind a key after it, like \"{} q\"", spec, spec));
+    }
+    Ok((prefix, key))
+}
+
+/// Parse one key: a character or key name, after optional `ctrl+`,
+/// `alt+` and `shift+`
+fn parse_key(token: &str) -> Result<KeyEvent, String> {
+    // "+" and "ctrl++" end in the key itself
+    let (modifier_part, name) = match token.strip_suffix("++") {
+        Some(mods) => (mods, "+"),
+        None => match token.rsplit_once('+') {
+            Some((mods, name)) if !name.is_empty() => (mods, name),
+            _ => ("", token),
+        },
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    for modifier in modifier_part.split('+').filter(|m| !m.is_empty()) {
+        modifiers |= match modifier.to_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return Err(format!("unknown modifier '{}' in '{}'", modifier, token)),
+        };
+    }
+
+    let mut chars = name.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::Char(c.to_ascii_uppercase()),
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match name.to_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "insert" => KeyCode::Insert,
+            "delete" | "del" => KeyCode::Delete,
+            f => match f.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                _ => return Err(format!("unknown key '{}'", name)),
+            },
+        },
+    };
+    Ok(normalize(KeyEvent::new(code, modifiers)))
+}
+
+/// Reduce a key event to what bindings compare: the code and the
+/// Ctrl/Alt/Shift modifiers, without the Shift a character or Shift+Tab
+/// already carries
+pub fn normalize(key: KeyEvent) -> KeyEvent {
+    let mut modifiers = key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+    let code = match key.code {
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+        code => code,
+    };
+    if matches!(code, KeyCode::Char(_) | KeyCode::BackTab) {
+        modifiers.remove(KeyModifiers::SHIFT);
+    }
+    KeyEvent::new(code, modifiers)
+}
+
+/// "Space+q", "gg", "Ctrl+f", "Shift+Right"
+fn binding_label(prefix: Prefix, key: KeyEvent) -> String {
+    let key = key_label(key);
+    match prefix {
+        Prefix::None => key,
+        Prefix::Space => format!("Space+{}", key),
+        Prefix::G => format!("g{}", key),
+    }
+}
+
+fn key_label(key: KeyEvent) -> String {
+    let mut label = String::new();
+    for (modifier, name) in [
+        (KeyModifiers::CONTROL, "Ctrl+"),
+        (KeyModifiers::ALT, "Alt+"),
+        (KeyModifiers::SHIFT, "Shift+"),
+    ] {
+        if key.modifiers.contains(modifier) {
+            label.push_str(name);
+        }
+    }
+    match key.code {
+        KeyCode::Char(' ') => label.push_str("Space"),
+        KeyCode::Char(c) => label.push(c),
+        KeyCode::BackTab => label.push_str("Shift+Tab"),
+        KeyCode::PageUp => label.push_str("PgUp"),
+        KeyCode::PageDown => label.push_str("PgDn"),
+        KeyCode::F(n) => label.push_str(&format!("F{}", n)),
+        code => label.push_str(&format!("{:?}", code)),
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_binding() {
+        assert_eq!(parse_binding("J").unwrap(), (Prefix::None, key(KeyCode::Char('J'), KeyModifiers::NONE)));
+        assert_eq!(parse_binding("shift+j").unwrap().1, key(KeyCode::Char('J'), KeyModifiers::NONE));
+        assert_eq!(parse_binding("ctrl+f").unwrap().1, key(KeyCode::Char('f'), KeyModifiers::CONTROL));
+        assert_eq!(parse_binding("+").unwrap().1, key(KeyCode::Char('+'), KeyModifiers::NONE));
+        assert_eq!(parse_binding("shift+tab").unwrap().1, key(KeyCode::BackTab, KeyModifiers::NONE));
+        assert_eq!(parse_binding("space q").unwrap(), (Prefix::Space, key(KeyCode::Char('q'), KeyModifiers::NONE)));
+        assert_eq!(parse_binding("g g").unwrap(), (Prefix::G, key(KeyCode::Char('g'), KeyModifiers::NONE)));
+
+        assert!(parse_binding("g").is_err());
+        assert!(parse_binding("x q").is_err());
+        assert!(parse_binding("hyper+a").is_err());
+        assert!(parse_binding("nope").is_err());
+    }
+
+    #[test]
+    fn test_defaults_match_builtin_keys() {
+        let keymap = Keymap::default();
+        let plain = |c| key(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, plain('j')), Some(ActionKind::MoveDown));
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::Space, plain('q')), Some(ActionKind::Quit));
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::G, plain('e')), Some(ActionKind::JumpEnd));
+        // Terminals report Shift on capitals; it still matches
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, key(KeyCode::Char('Y'), KeyModifiers::SHIFT)), Some(ActionKind::AddAllToQueue));
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, key(KeyCode::Right, KeyModifiers::SHIFT)), Some(ActionKind::SeekForwardLong));
+        assert_eq!(keymap.label(ActionKind::TogglePlayback), Some("Space+p"));
+        assert_eq!(keymap.key_after(Prefix::Space, ActionKind::TogglePlayback).as_deref(), Some("p"));
+    }
+
+    #[test]
+    fn test_view_actions_win_in_their_view() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert("cycle_repeat".to_string(), KeyList::One("x".to_string()));
+        let keymap = Keymap::new(&overrides);
+        let plain = |c| key(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert_eq!(keymap.action(ViewMode::Library, Prefix::None, plain('r')), Some(ActionKind::RefreshFavorites));
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, plain('r')), None);
+        // Rebinding repeat doesn't drag the Library refresh along
+        assert_eq!(keymap.action(ViewMode::Library, Prefix::None, plain('x')), Some(ActionKind::CycleRepeat));
+        assert_eq!(keymap.action(ViewMode::Downloads, Prefix::None, plain('R')), Some(ActionKind::RetryDownload));
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, plain('R')), Some(ActionKind::ToggleRadio));
+    }
+
+    #[test]
+    fn test_overrides_replace_defaults() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert("move_down".to_string(), KeyList::One("h".to_string()));
+        overrides.insert("move_left".to_string(), KeyList::Many(vec!["d".to_string(), "left".to_string()]));
+        overrides.insert("quit".to_string(), KeyList::Many(vec![]));
+        let keymap = Keymap::new(&overrides);
+        let plain = |c| key(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, plain('h')), Some(ActionKind::MoveDown));
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, plain('j')), None);
+        // "d" moved from remove-from-queue to move_left
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, plain('d')), Some(ActionKind::MoveLeft));
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::None, key(KeyCode::Left, KeyModifiers::NONE)), Some(ActionKind::MoveLeft));
+        assert_eq!(keymap.label(ActionKind::RemoveSelected), None);
+        assert_eq!(keymap.action(ViewMode::Browse, Prefix::Space, plain('q')), None);
+    }
+
+    #[test]
+    fn test_validate_drops_bad_entries() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert("fly".to_string(), KeyList::One("f".to_string()));
+        overrides.insert("move_down".to_string(), KeyList::Many(vec!["t".to_string(), "x y".to_string()]));
+
+        let warnings = validate(&mut overrides);
+        assert_eq!(warnings.len(), 2);
+        assert!(!overrides.contains_key("fly"));
+        assert_eq!(overrides["move_down"], KeyList::Many(vec!["t".to_string()]));
+    }
+}
@@ -6,18 +6,25 @@
 pub mod album_art;
 pub mod app;
 pub mod cava;
+pub mod clipboard;
 pub mod config;
 pub mod download_db;
 pub mod downloads;
 pub mod handlers;
 pub mod history_db;
+pub mod keymap;
+pub mod metadata;
 pub mod mpd;
+pub mod nowplaying;
+pub mod playlist_view_state;
 pub mod queue_persistence;
 pub mod search;
+pub mod scrobble;
 pub mod search_cache;
 pub mod service;
 pub mod storage;
 pub mod sync;
+pub mod tasks;
 pub mod tidal_db;
 pub mod ui;
 pub mod video;
@@ -1,15 +1,22 @@
 mod mpd;
 mod cava;
+mod clipboard;
 mod album_art;
 mod queue_persistence;
+mod playlist_view_state;
 mod download_db;
 mod history_db;
+mod keymap;
 mod downloads;
 mod config;
 mod service;
 mod search;
 mod search_cache;
 mod storage;
+mod scrobble;
+mod metadata;
+mod nowplaying;
+mod tasks;
 mod tidal_db;
 mod app;
 mod ui;
@@ -18,7 +25,8 @@ mod video;
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, MouseEventKind},
+    cursor,
+    event::{self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, Event, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -26,16 +34,24 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Modifier, Style},
+    text::Line,
     widgets::{Block, BorderType, Borders, Paragraph, Wrap},
     Frame, Terminal,
 };
-use std::{io, time::Duration};
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use app::{App, ViewMode};
-use handlers::{handle_key_event, KeyAction};
+use handlers::{handle_key_event, handle_paste, KeyAction};
 use service::MusicService;
 use ui::{
-    render_now_playing, render_queue, render_browse_view,
+    render_now_playing, render_queue, render_browse_view, render_screensaver,
     render_search_view, render_search_preview, render_downloads_view, render_library_view, render_status_bar,
     render_artist_detail_view, render_album_detail_view, render_help_panel, HelpPanelState,
     render_dialog, DialogRenderState, SearchPreviewState,
@@ -43,31 +59,63 @@ use ui::{
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+
+    // --data-dir relocates config, credentials, databases and caches, e.g.
+    // for separate profiles; same as setting DRIFT_DATA_DIR
+    if let Some(idx) = args.iter().position(|a| a == "--data-dir") {
+        let Some(dir) = args.get(idx + 1) else {
+            anyhow::bail!("--data-dir requires a path");
+        };
+        std::env::set_var(config::DATA_DIR_ENV, config::expand_path(dir)?);
+    }
+
+    if args.iter().any(|a| a == "--init-config") {
+        let (path, written) = config::Config::write_template()?;
+        if written {
+            println!("Wrote default config to {}", path.display());
+        } else {
+            println!("Config already exists at {}, leaving it untouched", path.display());
+        }
+        return Ok(());
+    }
+
+    // A panic would otherwise leave the shell in raw mode until `reset`.
+    // Installed before touching the terminal so setup failures are covered
+    // too; the message prints after the screen is restored so it stays readable.
+    // Only a panic on the main thread ends the UI: background tasks run on
+    // tokio's workers, and their panics are caught by the runtime.
+    let main_thread = std::thread::current().id();
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if std::thread::current().id() == main_thread {
+            let _ = restore_terminal();
+        }
+        default_hook(info);
+    }));
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Bracketed paste delivers pastes as one event instead of a key per char
+    execute!(stdout, EnterAlternateScreen, EnableBracketedPaste)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = match App::new().await {
         Ok(app) => app,
         Err(e) => {
-            disable_raw_mode()?;
-            execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+            restore_terminal()?;
             eprintln!("Failed to initialize app: {}", e);
             return Err(e);
         }
     };
 
+    // Enabled once the config is loaded; off keeps native text selection
+    app::set_mouse_capture(app.config.ui.mouse);
+
     let res = run_app(&mut terminal, &mut app).await;
 
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    restore_terminal()?;
 
     if let Err(err) = res {
         eprintln!("{:?}", err);
@@ -84,6 +132,7 @@ where
     <B as ratatui::backend::Backend>::Error: Send + Sync + 'static,
 {
     let mut last_status_check = std::time::Instant::now();
+    let shutdown = spawn_shutdown_listener();
 
     // Restore saved queue on first tick
     if let Some(persisted) = app.pending_restore.take() {
@@ -91,17 +140,30 @@ where
     }
 
     loop {
+        // SIGTERM/SIGINT/SIGHUP: leave like Space+q so the session survives a kill.
+        // The config needs no saving: drift only ever reads config.toml.
+        if shutdown.load(Ordering::Relaxed) {
+            app.add_debug("Received shutdown signal".to_string());
+            app.save_queue_state().await;
+            app.remember_playlist_view();
+            return Ok(());
+        }
+
         // Check MPD status periodically
         if last_status_check.elapsed() > Duration::from_secs(1) {
             if let Err(e) = app.check_mpd_status().await {
-                app.add_debug(format!("MPD status check error: {}", e));
+                // Losing MPD is logged once by the controller, not every poll
+                if app.mpd_controller.is_connected() {
+                    app.add_debug(format!("MPD status check error: {}", e));
+                }
             }
 
-            if app.playback.queue_dirty {
+            if app.playback.queue_dirty || app.position_save_due() {
                 app.save_queue_state().await;
                 app.playback.queue_dirty = false;
             }
 
+            app.check_sleep_timer().await;
             app.process_downloads().await;
             app.process_blob_uploads().await;
             app.auto_sync_playlists().await;
@@ -120,23 +182,45 @@ where
         }
 
         app.handle_download_events();
+        app.show_rate_limit();
         app.clear_expired_status();
+        app.trim_debug_log();
 
         // Prefetch album art for search preview
         app.prefetch_search_preview_art().await;
 
+        app.process_discography_fetch().await;
+        app.process_bulk_enqueue().await;
+        app.poll_search().await;
+        app.poll_enrichment().await;
+        app.tasks.poll();
+        app.check_screensaver();
+
         terminal.draw(|f| render_ui(f, app))?;
 
         if event::poll(Duration::from_millis(100))? {
-            match event::read()? {
+            let event = event::read()?;
+            if matches!(event, Event::Key(_) | Event::Mouse(_) | Event::Paste(_)) && app.note_input() {
+                continue;
+            }
+            match event {
                 Event::Mouse(mouse) => {
                     if mouse.kind == MouseEventKind::Down(event::MouseButton::Left) {
                         app.handle_mouse_click(mouse.column, mouse.row).await;
                     }
                 }
+                Event::Paste(text) => {
+                    handle_paste(app, &text);
+                }
                 Event::Key(key) => {
                     match handle_key_event(app, key).await {
-                        KeyAction::Quit => return Ok(()),
+                        KeyAction::Quit => {
+                            // Always save so resume_on_startup picks up the
+                            // latest position, not just the last edit
+                            app.save_queue_state().await;
+                            app.remember_playlist_view();
+                            return Ok(());
+                        }
                         KeyAction::Continue => {}
                     }
                 }
@@ -146,21 +230,47 @@ where
     }
 }
 
+fn render_screensaver_ui(f: &mut Frame, app: &App, theme: &ui::Theme) {
+    let heights = app.visualizer.as_ref()
+        .map(|viz| viz.heights(app.playback.is_playing, &app.config.ui.visualizer_on_pause))
+        .unwrap_or_default();
+    let now_playing = match app.current_song {
+        Some(ref song) => format!(
+            "{} – {}   {}:{:02} / {}:{:02}",
+            song.artist,
+            song.title,
+            song.elapsed.as_secs() / 60,
+            song.elapsed.as_secs() % 60,
+            song.duration.as_secs() / 60,
+            song.duration.as_secs() % 60,
+        ),
+        None => String::new(),
+    };
+    render_screensaver(f, &heights, &now_playing, f.area(), theme);
+}
+
 fn render_ui(f: &mut Frame, app: &mut App) {
     // Clone theme early to avoid borrow conflicts with mutable app access
     let theme = app.config.theme.clone();
 
+    if app.screensaver {
+        render_screensaver_ui(f, app, &theme);
+        return;
+    }
+
     // Now Playing height: taller when visualizer is enabled
-    let now_playing_height = if app.show_visualizer && app.visualizer.is_some() {
+    let now_playing_height = if app.visualizer_visible() {
         14  // Extra space for visualizer
     } else {
         9
     };
 
-    let mut constraints = vec![
-        Constraint::Length(3),            // Header
-        Constraint::Length(now_playing_height),  // Now Playing (with optional visualizer)
-    ];
+    let show_header = app.config.ui.show_header;
+    let mut constraints = Vec::new();
+    if show_header {
+        constraints.push(Constraint::Length(3));  // Header
+    }
+    constraints.push(Constraint::Length(now_playing_height));  // Now Playing (with optional visualizer)
 
     if app.show_debug {
         constraints.push(Constraint::Percentage(50));  // Main content
@@ -195,18 +305,22 @@ fn render_ui(f: &mut Frame, app: &mut App) {
             ViewMode::AlbumDetail => "Album",
         }
     );
-    let header = Paragraph::new(header_text)
-        .style(Style::default().fg(theme.primary()).add_modifier(Modifier::BOLD))
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded),
-        );
-    f.render_widget(header, main_chunks[chunk_index]);
-    chunk_index += 1;
+    if show_header {
+        let header = Paragraph::new(header_text.clone())
+            .style(Style::default().fg(theme.primary()).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            );
+        f.render_widget(header, main_chunks[chunk_index]);
+        chunk_index += 1;
+    }
 
     // Now Playing
+    let show_visualizer = app.visualizer_visible();
+    let release_year = app.release_year();
     let now_playing_state = ui::now_playing::NowPlayingState {
         current_track: app.current_track.as_ref(),
         current_song: app.current_song.as_ref(),
@@ -214,12 +328,14 @@ fn render_ui(f: &mut Frame, app: &mut App) {
         volume: app.playback.volume,
         repeat_mode: app.playback.repeat_mode,
         random_mode: app.playback.random_mode,
-        single_mode: app.playback.single_mode,
         radio_seed: app.playback.radio_seed.clone(),
         local_queue_len: app.local_queue.len(),
         album_art_cache: &mut app.album_art_cache,
-        visualizer: if app.show_visualizer { app.visualizer.as_ref() } else { None },
+        visualizer: if show_visualizer { app.visualizer.as_ref() } else { None },
+        visualizer_on_pause: &app.config.ui.visualizer_on_pause,
         video_mode: app.playback.video_mode,
+        marquee_tick: app.config.ui.marquee.then(marquee_tick),
+        release_year,
     };
     let progress_bar_area = render_now_playing(f, &mut { now_playing_state }, main_chunks[chunk_index], &theme);
     app.clickable_areas.progress_bar = progress_bar_area;
@@ -229,24 +345,30 @@ fn render_ui(f: &mut Frame, app: &mut App) {
     let content_area = main_chunks[chunk_index];
 
     if app.playback.show_queue {
+        let direction = if app.config.ui.queue_layout == "vertical" {
+            Direction::Vertical
+        } else {
+            Direction::Horizontal
+        };
         let content_chunks = Layout::default()
-            .direction(Direction::Horizontal)
+            .direction(direction)
             .constraints([
-                Constraint::Percentage(60),
-                Constraint::Percentage(40),
+                Constraint::Percentage(100 - app.config.ui.queue_width_percent),
+                Constraint::Percentage(app.config.ui.queue_width_percent),
             ])
             .split(content_area);
 
         render_main_content(f, app, content_chunks[0], &theme);
 
-        let queue_area = render_queue(
-            f,
-            &app.local_queue,
-            app.playback.selected_queue_item,
-            app.current_track.as_ref().map(|t| t.id.as_str()),
-            content_chunks[1],
-            &theme,
-        );
+        let queue_state = ui::queue::QueueViewState {
+            local_queue: &app.local_queue,
+            selected_queue_item: app.playback.selected_queue_item,
+            current_track_id: app.current_track.as_ref().map(|t| t.id.as_str()),
+            playing: app.playback.queue_position.zip(app.current_song.as_ref().map(|s| s.elapsed.as_secs())),
+            scroll: list_scroll(&app.config.ui),
+            keymap: &app.keymap,
+        };
+        let queue_area = render_queue(f, &queue_state, content_chunks[1], &theme);
         app.clickable_areas.queue_list = Some(queue_area);
     } else {
         app.clickable_areas.queue_list = None;
@@ -256,25 +378,48 @@ fn render_ui(f: &mut Frame, app: &mut App) {
 
     // Debug panel (only shown when enabled)
     if app.show_debug {
-        let debug_text: String = app.debug_log
+        let debug_area = main_chunks[chunk_index];
+        let truncate = app.config.ui.debug_truncate;
+        // Inner width, minus the borders
+        let max_width = debug_area.width.saturating_sub(2) as usize;
+        let debug_text: Vec<Line> = app.debug_log
             .iter()
             .rev()
-            .take(10)
+            .skip(app.debug_panel.scroll_offset)
+            .take(app.config.ui.debug_lines)
             .rev()
-            .cloned()
-            .collect::<Vec<_>>()
-            .join("\n");
+            .map(|line| {
+                let text = if truncate { truncate_line(line, max_width) } else { line.clone() };
+                match debug_line_color(line, &theme) {
+                    Some(color) => Line::styled(text, Style::default().fg(color)),
+                    None => Line::raw(text),
+                }
+            })
+            .collect();
 
-        let debug_panel = Paragraph::new(debug_text)
+        let mut debug_title = String::from("Debug Log");
+        if app.debug_panel.scroll_offset > 0 {
+            debug_title.push_str(&format!(" (-{})", app.debug_panel.scroll_offset));
+        }
+        debug_title.push_str(" [Space+e: export | Space+c: clear | Space+d: hide | Space+D: focus]");
+        let debug_border = if app.debug_panel.focused {
+            theme.border_focused()
+        } else {
+            theme.border_normal()
+        };
+
+        let mut debug_panel = Paragraph::new(debug_text)
             .style(Style::default().fg(theme.text_muted()))
-            .wrap(Wrap { trim: false })
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Debug Log [Space+e: export | Space+c: clear | Space+d: hide]")
-                    .border_style(Style::default().fg(theme.border_normal())),
+                    .title(debug_title)
+                    .border_style(Style::default().fg(debug_border)),
             );
-        f.render_widget(debug_panel, main_chunks[chunk_index]);
+        if !truncate {
+            debug_panel = debug_panel.wrap(Wrap { trim: false });
+        }
+        f.render_widget(debug_panel, debug_area);
         chunk_index += 1;
     }
 
@@ -285,6 +430,14 @@ fn render_ui(f: &mut Frame, app: &mut App) {
         pending_key: app.key_state.pending_key,
         status_message: app.status_message.as_ref().map(|m| (m.message.clone(), m.is_error)),
         backend_name: Some(app.storage.backend_name().to_string()),
+        // Without the header row, its connection/mode line moves onto the status bar border
+        header: (!show_header).then_some(header_text),
+        tasks: app.tasks.active().map(|task| task.summary()).collect(),
+        sleep_timer: app.sleep_timer_remaining().map(|left| {
+            let secs = left.as_secs();
+            format!("{}:{:02}", secs / 60, secs % 60)
+        }),
+        keymap: &app.keymap,
     };
     render_status_bar(f, &status_state, main_chunks[chunk_index], &theme);
 
@@ -292,6 +445,7 @@ fn render_ui(f: &mut Frame, app: &mut App) {
     if app.show_help {
         let help_state = HelpPanelState {
             scroll_offset: app.help.scroll_offset,
+            keymap: &app.keymap,
         };
         render_help_panel(f, &help_state, f.area(), &theme);
     }
@@ -303,6 +457,7 @@ fn render_ui(f: &mut Frame, app: &mut App) {
             input_text: &app.dialog.input_text,
             selected_index: app.dialog.selected_index,
             playlists: &app.playlists,
+            keymap: &app.keymap,
         };
         render_dialog(f, &dialog_state, f.area(), &theme);
     }
@@ -310,6 +465,7 @@ fn render_ui(f: &mut Frame, app: &mut App) {
 
 fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect, theme: &ui::Theme) {
     let current_track_id = app.current_track.as_ref().map(|t| t.id.as_str());
+    let scroll = list_scroll(&app.config.ui);
 
     match app.view_mode {
         ViewMode::Browse => {
@@ -321,6 +477,12 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect
                 selected_tab: app.browse.selected_tab,
                 synced_playlist_ids: &app.downloads.synced_playlist_ids,
                 current_track_id,
+                sort: app.browse.sort,
+                filtered_indices: app.browse.is_filtered().then_some(app.browse.filtered_indices.as_slice()),
+                filter_query: &app.browse.filter_query,
+                filter_active: app.browse.filter_active,
+                scroll,
+                keymap: &app.keymap,
             };
             let (left, right) = render_browse_view(f, &browse_state, area, theme);
             app.clickable_areas.left_list = Some(left);
@@ -353,6 +515,7 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect
                 selected_search_album: app.search.selected_album,
                 selected_search_artist: app.search.selected_artist,
                 is_searching: app.search.is_active,
+                search_pending: app.search.pending.is_some(),
                 current_track_id,
                 filter_query: &app.search.filter_query,
                 filter_active: app.search.filter_active,
@@ -362,6 +525,9 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect
                 page: app.search.page,
                 has_more: app.search.has_more,
                 service_filter: app.search.service_filter,
+                tab_cycles_filter: app.tab_cycles_search_filter(),
+                trending: app.search.showing_trending,
+                scroll,
             };
             app.clickable_areas.left_list = None;
             let right = render_search_view(f, &search_state, search_area, theme);
@@ -397,6 +563,7 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect
                 pending_count: pending,
                 completed_count: completed,
                 failed_count: failed,
+                scroll,
             };
             app.clickable_areas.left_list = None;
             let right = render_downloads_view(f, &downloads_state, area, theme);
@@ -415,6 +582,8 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect
                 selected_history_entry: app.library.selected_history,
                 current_track_id,
                 service_filter: app.library.service_filter,
+                scroll,
+                keymap: &app.keymap,
             };
             app.clickable_areas.left_list = None;
             let right = render_library_view(f, &library_state, area, theme);
@@ -425,10 +594,13 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect
                 artist: app.artist_detail.artist.as_ref(),
                 top_tracks: &app.artist_detail.top_tracks,
                 albums: &app.artist_detail.albums,
+                album_filter: app.artist_detail.album_filter,
                 selected_track: app.artist_detail.selected_track,
                 selected_album: app.artist_detail.selected_album,
                 selected_panel: app.artist_detail.selected_panel,
                 current_track_id,
+                scroll,
+                keymap: &app.keymap,
             };
             let (left, right) = render_artist_detail_view(f, &artist_state, area, theme);
             app.clickable_areas.left_list = Some(left);
@@ -440,6 +612,8 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect
                 tracks: &app.album_detail.tracks,
                 selected_track: app.album_detail.selected_track,
                 current_track_id,
+                scroll,
+                keymap: &app.keymap,
             };
             app.clickable_areas.left_list = None;
             let right = render_album_detail_view(f, &album_state, area, theme);
@@ -447,3 +621,89 @@ fn render_main_content(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect
         }
     }
 }
+
+/// List scrolling from `ui.scroll_style` and `ui.scrolloff`
+fn list_scroll(ui_config: &config::UiConfig) -> ui::styles::ListScroll {
+    ui::styles::ListScroll {
+        centered: ui_config.scroll_style == "centered",
+        scrolloff: ui_config.scrolloff,
+    }
+}
+
+/// Clock-driven marquee step, so scrolling speed doesn't depend on how often
+/// the UI happens to redraw
+fn marquee_tick() -> usize {
+    const STEP_MS: u128 = 300;
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| (d.as_millis() / STEP_MS) as usize)
+}
+
+/// Leave raw mode and the alternate screen, and bring the cursor back
+fn restore_terminal() -> Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        cursor::Show
+    )?;
+    Ok(())
+}
+
+/// Flag set once SIGTERM, SIGINT or SIGHUP arrives, checked by the main loop
+fn spawn_shutdown_listener() -> Arc<AtomicBool> {
+    let flag = Arc::new(AtomicBool::new(false));
+    let task_flag = flag.clone();
+    tokio::spawn(async move {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{signal, SignalKind};
+            let (Ok(mut term), Ok(mut int), Ok(mut hup)) = (
+                signal(SignalKind::terminate()),
+                signal(SignalKind::interrupt()),
+                signal(SignalKind::hangup()),
+            ) else {
+                return;
+            };
+            tokio::select! {
+                _ = term.recv() => {}
+                _ = int.recv() => {}
+                // Terminal window closed
+                _ = hup.recv() => {}
+            }
+        }
+        #[cfg(not(unix))]
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        task_flag.store(true, Ordering::Relaxed);
+    });
+    flag
+}
+
+/// Color for a debug message from its leading marker: ✓ success, ✗ error,
+/// ⚠ warning. Unmarked lines keep the panel's muted color.
+fn debug_line_color(line: &str, theme: &ui::Theme) -> Option<ratatui::style::Color> {
+    let line = line.trim_start();
+    if line.starts_with('✓') {
+        Some(theme.success())
+    } else if line.starts_with('✗') {
+        Some(theme.error())
+    } else if line.starts_with('⚠') {
+        Some(theme.warning())
+    } else {
+        None
+    }
+}
+
+/// Cut a line to `max_width` characters, ending in '…' when shortened
+fn truncate_line(line: &str, max_width: usize) -> String {
+    if line.chars().count() <= max_width {
+        return line.to_string();
+    }
+    let mut out: String = line.chars().take(max_width.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
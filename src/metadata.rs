@@ -0,0 +1,225 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::{MusicBrainzConfig, NetworkConfig};
+use crate::service::rate_limit::{SendRateLimited, ServiceLimiter};
+use crate::service::Track;
+
+/// MusicBrainz rejects requests without an identifying User-Agent, so this
+/// is sent even when `network.user_agent` is set
+const MUSICBRAINZ_USER_AGENT: &str = concat!("drift/", env!("CARGO_PKG_VERSION"), " ( https://github.com/brittonr/drift )");
+
+/// MusicBrainz allows one request per second per client
+const MUSICBRAINZ_REQUESTS_PER_SECOND: f64 = 1.0;
+
+/// Lowest search score (0-100) taken as the same recording
+const MIN_MATCH_SCORE: u64 = 90;
+
+/// What MusicBrainz adds to a track. All None when nothing matched well
+/// enough, which is cached too so the lookup isn't repeated.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EnrichedTrack {
+    /// Recording MBID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mbid: Option<String>,
+    /// Year of the recording's first release
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<u16>,
+    /// The recording's most-voted tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre: Option<String>,
+}
+
+/// Looks tracks up in the MusicBrainz recording search, spacing requests
+/// to its rate limit
+pub struct MusicBrainz {
+    client: reqwest::Client,
+    api_url: String,
+    rate_limiter: ServiceLimiter,
+}
+
+/// Build the client when `musicbrainz.enabled` is set
+pub fn from_config(config: &MusicBrainzConfig, network: &NetworkConfig) -> Result<Option<MusicBrainz>> {
+    if !config.enabled {
+        return Ok(None);
+    }
+    MusicBrainz::new(&config.api_url, network).map(Some)
+}
+
+impl MusicBrainz {
+    pub fn new(api_url: &str, network: &NetworkConfig) -> Result<Self> {
+        let client = crate::service::http_client(network, "musicbrainz", Some(MUSICBRAINZ_USER_AGENT))?;
+        // Lookups run in the background, so a 429 is waited out
+        let mut rate_limiter = ServiceLimiter::new(MUSICBRAINZ_REQUESTS_PER_SECOND);
+        rate_limiter.set_background(true);
+        Ok(Self {
+            client,
+            api_url: api_url.trim_end_matches('/').to_string(),
+            rate_limiter,
+        })
+    }
+
+    /// Time left on a 429 backoff, see [`RateLimiter::backoff`]
+    pub fn rate_limit_backoff(&self) -> Option<std::time::Duration> {
+        self.rate_limiter.backoff()
+    }
+
+    /// Find `track`'s recording by title and artist
+    pub async fn enrich_track(&self, track: &Track) -> Result<EnrichedTrack> {
+        let response = self.client
+            .get(format!("{}/ws/2/recording", self.api_url))
+            .query(&[
+                ("query", recording_query(track).as_str()),
+                ("fmt", "json"),
+                ("limit", "5"),
+            ])
+            .header(reqwest::header::USER_AGENT, MUSICBRAINZ_USER_AGENT)
+            .send_limited(&self.rate_limiter)
+            .await
+            .context("MusicBrainz request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("MusicBrainz returned {}", status);
+        }
+        let body: Value = response.json().await.context("Invalid MusicBrainz response")?;
+        Ok(best_recording(&body))
+    }
+}
+
+/// Lucene query for the recording search: the title without the version
+/// notes services append, and the first credited artist
+fn recording_query(track: &Track) -> String {
+    let artist = track.artist.split(", ").next().unwrap_or(&track.artist);
+    format!(
+        "recording:\"{}\" AND artist:\"{}\"",
+        escape_phrase(&search_title(&track.title)),
+        escape_phrase(artist)
+    )
+}
+
+/// `title` without "(feat. X)", "[2011 Remaster]" or "- Remastered 2009",
+/// which MusicBrainz keeps out of recording titles
+fn search_title(title: &str) -> String {
+    let is_note = |text: &str| {
+        let text = text.to_lowercase();
+        ["feat.", "ft.", "remaster", "deluxe", "bonus track"].iter().any(|note| text.contains(note))
+    };
+
+    let mut title = title.to_string();
+    if let Some((head, tail)) = title.rsplit_once(" - ") {
+        if is_note(tail) {
+            title = head.to_string();
+        }
+    }
+    for (open, close) in [('(', ')'), ('[', ']')] {
+        while let Some(start) = title.rfind(open) {
+            let Some(len) = title[start..].find(close) else {
+                break;
+            };
+            if !is_note(&title[start..start + len]) {
+                break;
+            }
+            title.replace_range(start..start + len + 1, "");
+        }
+    }
+    title.trim().to_string()
+}
+
+/// Escape a value for inside a quoted Lucene phrase
+fn escape_phrase(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// The first recording scoring at least [`MIN_MATCH_SCORE`] (results come
+/// best first)
+fn best_recording(body: &Value) -> EnrichedTrack {
+    let Some(recording) = body["recordings"]
+        .as_array()
+        .and_then(|recordings| {
+            recordings.iter().find(|r| r["score"].as_u64().is_some_and(|s| s >= MIN_MATCH_SCORE))
+        })
+    else {
+        return EnrichedTrack::default();
+    };
+
+    let year = recording["first-release-date"]
+        .as_str()
+        .and_then(|date| date.get(..4))
+        .and_then(|year| year.parse().ok());
+    let genre = recording["tags"]
+        .as_array()
+        .and_then(|tags| tags.iter().max_by_key(|tag| tag["count"].as_i64().unwrap_or(0)))
+        .and_then(|tag| tag["name"].as_str())
+        .map(str::to_string);
+
+    EnrichedTrack {
+        mbid: recording["id"].as_str().map(str::to_string),
+        year,
+        genre,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_search_title_drops_version_notes() {
+        assert_eq!(search_title("Windowlicker"), "Windowlicker");
+        assert_eq!(search_title("Heroes - 2017 Remaster"), "Heroes");
+        assert_eq!(search_title("Get Lucky (feat. Pharrell Williams)"), "Get Lucky");
+        assert_eq!(search_title("Song [Remastered 2009] (Deluxe Edition)"), "Song");
+        // Parentheses that are part of the title stay
+        assert_eq!(search_title("(Don't Fear) The Reaper"), "(Don't Fear) The Reaper");
+        assert_eq!(search_title("Live - In Concert"), "Live - In Concert");
+    }
+
+    #[test]
+    fn test_recording_query_escapes_quotes() {
+        let track = Track {
+            id: "1".to_string(),
+            title: "Say \"Hi\"".to_string(),
+            artist: "A, B".to_string(),
+            album: String::new(),
+            album_id: None,
+            duration_seconds: 0,
+            cover_art: crate::service::CoverArt::None,
+            service: crate::service::ServiceType::Tidal,
+            available: true,
+        };
+        assert_eq!(recording_query(&track), r#"recording:"Say \"Hi\"" AND artist:"A""#);
+    }
+
+    #[test]
+    fn test_best_recording() {
+        let body = json!({
+            "recordings": [
+                {
+                    "id": "rec-1",
+                    "score": 100,
+                    "first-release-date": "1999-10-04",
+                    "tags": [
+                        { "count": 1, "name": "idm" },
+                        { "count": 4, "name": "electronic" }
+                    ]
+                },
+                { "id": "rec-2", "score": 95 }
+            ]
+        });
+        assert_eq!(
+            best_recording(&body),
+            EnrichedTrack {
+                mbid: Some("rec-1".to_string()),
+                year: Some(1999),
+                genre: Some("electronic".to_string()),
+            }
+        );
+
+        let weak = json!({ "recordings": [{ "id": "rec-3", "score": 60 }] });
+        assert_eq!(best_recording(&weak), EnrichedTrack::default());
+        assert_eq!(best_recording(&json!({})), EnrichedTrack::default());
+    }
+}
@@ -36,7 +36,12 @@ fn parse_duration(time_str: &str) -> Duration {
 }
 
 pub struct MpdController {
-    _is_connected: bool,
+    is_connected: bool,
+    /// Set when a status poll succeeds after MPD was unreachable
+    reconnected: bool,
+    /// Files Drift has put in MPD's queue, in queue order, so the song MPD
+    /// reports can be checked against the one expected at its position
+    queued_files: Vec<String>,
     host: String,
     port: u16,
 }
@@ -66,7 +71,9 @@ impl MpdController {
         }
 
         Ok(Self {
-            _is_connected: is_connected,
+            is_connected,
+            reconnected: false,
+            queued_files: Vec::new(),
             host: host.to_string(),
             port,
         })
@@ -91,6 +98,7 @@ impl MpdController {
 
         if output.status.success() {
             debug_log.push_back("✓ Track added to MPD queue".to_string());
+            self.queued_files.push(url.to_string());
             let stdout = String::from_utf8_lossy(&output.stdout);
             if !stdout.is_empty() {
                 debug_log.push_back(format!("  MPD response: {}", stdout.trim()));
@@ -111,6 +119,36 @@ impl MpdController {
         Ok(())
     }
 
+    /// Swap the file queued at `position` (0-indexed), which must be the
+    /// song after the current one, for `url`: `mpc insert` puts the new
+    /// entry right after the current song, then the old one is deleted
+    pub async fn replace_next(&mut self, position: usize, url: &str, debug_log: &mut VecDeque<String>) -> Result<()> {
+        debug_log.push_back(format!("Executing: mpc insert \"{}\"", &url[..100.min(url.len())]));
+        let output = self.mpc_cmd().arg("insert").arg(url).output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            debug_log.push_back(format!("✗ Failed to insert track: {}", stderr.trim()));
+            return Err(anyhow::anyhow!("Failed to insert track into MPD: {}", stderr));
+        }
+
+        // The old entry moved down one; mpc positions are 1-based
+        let old_position = position + 2;
+        debug_log.push_back(format!("Executing: mpc del {}", old_position));
+        let output = self.mpc_cmd().arg("del").arg(old_position.to_string()).output()?;
+        if !output.status.success() {
+            // Both copies are queued now, the new one first
+            self.queued_files.insert(position.min(self.queued_files.len()), url.to_string());
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            debug_log.push_back(format!("✗ Failed to remove replaced track: {}", stderr.trim()));
+            return Err(anyhow::anyhow!("Failed to remove replaced track from MPD: {}", stderr));
+        }
+        if let Some(file) = self.queued_files.get_mut(position) {
+            *file = url.to_string();
+        }
+        debug_log.push_back(format!("✓ Replaced track at position {}", position + 1));
+        Ok(())
+    }
+
     pub async fn play(&mut self, debug_log: &mut VecDeque<String>) -> Result<()> {
         debug_log.push_back("Executing: mpc play".to_string());
 
@@ -223,11 +261,39 @@ impl MpdController {
         Ok(())
     }
 
-    pub async fn get_status(&mut self, _debug_log: &mut VecDeque<String>) -> Result<PlayerStatus> {
+    /// Whether the last status poll reached MPD
+    pub fn is_connected(&self) -> bool {
+        self.is_connected
+    }
+
+    /// True once after a status poll reaches MPD again following a failed one
+    pub fn take_reconnected(&mut self) -> bool {
+        std::mem::take(&mut self.reconnected)
+    }
+
+    pub async fn get_status(&mut self, debug_log: &mut VecDeque<String>) -> Result<PlayerStatus> {
         let output = self.mpc_cmd().arg("status").output()?;
+
+        // mpc connects per command, so MPD coming back is noticed here
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            if self.is_connected {
+                debug_log.push_back(format!("✗ Lost connection to MPD at {}:{}", self.host, self.port));
+                debug_log.push_back(format!("  Error: {}", error));
+                self.is_connected = false;
+            }
+            return Err(anyhow::anyhow!("MPD unreachable: {}", error));
+        }
+        if !self.is_connected {
+            debug_log.push_back(format!("✓ Reconnected to MPD at {}:{}", self.host, self.port));
+            self.is_connected = true;
+            self.reconnected = true;
+        }
+
         let status_str = String::from_utf8_lossy(&output.stdout);
 
         let is_playing = status_str.contains("[playing]");
+        let is_stopped = !is_playing && !status_str.contains("[paused]");
         let current_track = if status_str.lines().count() > 1 {
             Some(status_str.lines().next().unwrap_or("Unknown").to_string())
         } else {
@@ -252,8 +318,14 @@ impl MpdController {
         let random = status_str.contains("random: on");
         let single = status_str.contains("single: on");
 
+        let error = status_str
+            .lines()
+            .find_map(|l| l.strip_prefix("ERROR:"))
+            .map(|e| e.trim().to_string());
+
         Ok(PlayerStatus {
             is_playing,
+            is_stopped,
             current_track,
             volume,
             elapsed: None,
@@ -261,10 +333,10 @@ impl MpdController {
             repeat,
             random,
             single,
+            error,
         })
     }
 
-    #[allow(dead_code)]
     pub async fn set_volume(&mut self, volume: u8, debug_log: &mut VecDeque<String>) -> Result<()> {
         debug_log.push_back(format!("Executing: mpc volume {}", volume));
 
@@ -282,72 +354,119 @@ impl MpdController {
         Ok(())
     }
 
-    pub async fn volume_up(&mut self, debug_log: &mut VecDeque<String>) -> Result<()> {
-        debug_log.push_back("Executing: mpc volume +5".to_string());
+    /// Set MPD's `replay_gain_mode`: "off", "track", "album" or "auto"
+    pub async fn set_replay_gain(&mut self, mode: &str, debug_log: &mut VecDeque<String>) -> Result<()> {
+        debug_log.push_back(format!("Executing: mpc replaygain {}", mode));
 
         let output = self.mpc_cmd()
-            .arg("volume")
-            .arg("+5")
+            .arg("replaygain")
+            .arg(mode)
             .output()?;
 
-        if output.status.success() {
-            debug_log.push_back("✓ Volume increased".to_string());
-        } else {
+        if !output.status.success() {
             let error = String::from_utf8_lossy(&output.stderr);
-            debug_log.push_back(format!("✗ Failed to increase volume: {}", error));
+            debug_log.push_back(format!("✗ Failed to set replay gain: {}", error));
+            return Err(anyhow::anyhow!("mpc replaygain failed: {}", error.trim()));
         }
+        debug_log.push_back(format!("✓ Replay gain mode set to {}", mode));
         Ok(())
     }
 
-    pub async fn volume_down(&mut self, debug_log: &mut VecDeque<String>) -> Result<()> {
-        debug_log.push_back("Executing: mpc volume -5".to_string());
+    /// Set MPD's crossfade between tracks (0 = off)
+    pub async fn set_crossfade(&mut self, seconds: u32, debug_log: &mut VecDeque<String>) -> Result<()> {
+        debug_log.push_back(format!("Executing: mpc crossfade {}", seconds));
 
         let output = self.mpc_cmd()
-            .arg("volume")
-            .arg("-5")
+            .arg("crossfade")
+            .arg(seconds.to_string())
             .output()?;
 
-        if output.status.success() {
-            debug_log.push_back("✓ Volume decreased".to_string());
-        } else {
+        if !output.status.success() {
             let error = String::from_utf8_lossy(&output.stderr);
-            debug_log.push_back(format!("✗ Failed to decrease volume: {}", error));
+            debug_log.push_back(format!("✗ Failed to set crossfade: {}", error));
+            return Err(anyhow::anyhow!("mpc crossfade failed: {}", error.trim()));
         }
+        debug_log.push_back(format!("✓ Crossfade set to {}s", seconds));
         Ok(())
     }
 
-    pub async fn seek_forward(&mut self, debug_log: &mut VecDeque<String>) -> Result<()> {
-        debug_log.push_back("Executing: mpc seek +10".to_string());
+    /// MPD's current crossfade in seconds
+    pub async fn crossfade(&mut self) -> Result<u32> {
+        let output = self.mpc_cmd().arg("crossfade").output()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        // "crossfade: 5"
+        text.trim()
+            .strip_prefix("crossfade:")
+            .and_then(|secs| secs.trim().parse().ok())
+            .ok_or_else(|| anyhow::anyhow!("unexpected mpc crossfade output: {}", text.trim()))
+    }
 
-        let output = self.mpc_cmd()
-            .arg("seek")
-            .arg("+10")
-            .output()?;
+    /// Whether any MPD audio output is enabled; with none, crossfade has
+    /// nothing to mix on
+    pub async fn has_enabled_output(&mut self) -> Result<bool> {
+        let output = self.mpc_cmd().arg("outputs").output()?;
+        // "Output 1 (PipeWire) is enabled"
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .any(|line| line.trim_end().ends_with("is enabled")))
+    }
 
-        if output.status.success() {
-            debug_log.push_back("✓ Seeked forward 10s".to_string());
-        } else {
-            let error = String::from_utf8_lossy(&output.stderr);
-            debug_log.push_back(format!("✗ Failed to seek: {}", error));
+    /// Set MixRamp, which overlaps tracks where their MixRamp tags reach
+    /// `db` (e.g. -17) rather than for a fixed time, taking `delay` seconds
+    /// off that overlap. MPD only uses it once a delay is set, and only for
+    /// files carrying the tags; others get plain crossfade.
+    pub async fn set_mixramp(&mut self, db: Option<f64>, delay: Option<f64>, debug_log: &mut VecDeque<String>) -> Result<()> {
+        for (command, value) in [("mixrampdb", db), ("mixrampdelay", delay)] {
+            let Some(value) = value else { continue };
+            debug_log.push_back(format!("Executing: mpc {} {}", command, value));
+
+            let output = self.mpc_cmd()
+                .arg(command)
+                .arg(value.to_string())
+                .output()?;
+
+            if !output.status.success() {
+                let error = String::from_utf8_lossy(&output.stderr);
+                debug_log.push_back(format!("✗ Failed to set {}: {}", command, error));
+                return Err(anyhow::anyhow!("mpc {} failed: {}", command, error.trim()));
+            }
+            debug_log.push_back(format!("✓ {} set to {}", command, value));
         }
         Ok(())
     }
 
-    pub async fn seek_backward(&mut self, debug_log: &mut VecDeque<String>) -> Result<()> {
-        debug_log.push_back("Executing: mpc seek -10".to_string());
+    /// Seek `seconds` forward (or back, when negative) in the current track,
+    /// clamped to its start. Seeking past the end skips to the next track.
+    /// A paused track stays paused.
+    ///
+    /// Returns the new elapsed time, or None when it moved to the next track.
+    pub async fn seek(&mut self, seconds: i64, debug_log: &mut VecDeque<String>) -> Result<Option<Duration>> {
+        let (elapsed, duration) = self.get_timing_info().await?;
+        let target = elapsed.as_secs() as i64 + seconds;
+
+        if !duration.is_zero() && target >= duration.as_secs() as i64 {
+            self.next(debug_log).await?;
+            return Ok(None);
+        }
 
-        let output = self.mpc_cmd()
-            .arg("seek")
-            .arg("-10")
-            .output()?;
+        let target = target.max(0) as u32;
+        self.seek_to(target, debug_log).await?;
+        Ok(Some(Duration::from_secs(target as u64)))
+    }
 
-        if output.status.success() {
-            debug_log.push_back("✓ Seeked backward 10s".to_string());
-        } else {
-            let error = String::from_utf8_lossy(&output.stderr);
-            debug_log.push_back(format!("✗ Failed to seek: {}", error));
+    /// Seek to `fraction` (0.0-1.0) of the way through the current track.
+    ///
+    /// Returns the new elapsed time, or None when MPD reports no track
+    /// duration (nothing playing, or a stream).
+    pub async fn seek_to_fraction(&mut self, fraction: f64, debug_log: &mut VecDeque<String>) -> Result<Option<Duration>> {
+        let (_, duration) = self.get_timing_info().await?;
+        if duration.is_zero() {
+            return Ok(None);
         }
-        Ok(())
+
+        let target = (duration.as_secs_f64() * fraction.clamp(0.0, 1.0)) as u32;
+        self.seek_to(target, debug_log).await?;
+        Ok(Some(Duration::from_secs(target as u64)))
     }
 
     pub async fn seek_to(&mut self, seconds: u32, debug_log: &mut VecDeque<String>) -> Result<()> {
@@ -367,65 +486,47 @@ impl MpdController {
         Ok(())
     }
 
-    pub async fn toggle_repeat(&mut self, debug_log: &mut VecDeque<String>) -> Result<()> {
-        debug_log.push_back("Executing: mpc repeat".to_string());
-
-        let output = self.mpc_cmd()
-            .arg("repeat")
-            .output()?;
-
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            if stdout.contains("On") {
-                debug_log.push_back("✓ Repeat enabled".to_string());
-            } else {
-                debug_log.push_back("✓ Repeat disabled".to_string());
-            }
-        } else {
-            let error = String::from_utf8_lossy(&output.stderr);
-            debug_log.push_back(format!("✗ Failed to toggle repeat: {}", error));
-        }
+    /// Set MPD's repeat and single flags together so they always describe
+    /// `mode`
+    pub async fn set_repeat_mode(&mut self, mode: RepeatMode, debug_log: &mut VecDeque<String>) -> Result<()> {
+        let (repeat, single) = mode.flags();
+        self.set_repeat(repeat, debug_log).await?;
+        self.set_single(single, debug_log).await?;
+        debug_log.push_back(format!("✓ Repeat mode: {:?}", mode));
         Ok(())
     }
 
-    pub async fn toggle_random(&mut self, debug_log: &mut VecDeque<String>) -> Result<()> {
-        debug_log.push_back("Executing: mpc random".to_string());
+    /// Loop the queue (or, with single on, the current track)
+    pub async fn set_repeat(&mut self, on: bool, debug_log: &mut VecDeque<String>) -> Result<()> {
+        self.set_flag("repeat", on, debug_log)
+    }
 
-        let output = self.mpc_cmd()
-            .arg("random")
-            .output()?;
+    /// Stop after the current track (or, with repeat on, loop it)
+    pub async fn set_single(&mut self, on: bool, debug_log: &mut VecDeque<String>) -> Result<()> {
+        self.set_flag("single", on, debug_log)
+    }
 
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            if stdout.contains("On") {
-                debug_log.push_back("✓ Random/shuffle enabled".to_string());
-            } else {
-                debug_log.push_back("✓ Random/shuffle disabled".to_string());
-            }
-        } else {
-            let error = String::from_utf8_lossy(&output.stderr);
-            debug_log.push_back(format!("✗ Failed to toggle random: {}", error));
-        }
+    /// Shuffle: play the queue in random order
+    pub async fn set_random(&mut self, on: bool, debug_log: &mut VecDeque<String>) -> Result<()> {
+        self.set_flag("random", on, debug_log)?;
+        debug_log.push_back(format!("✓ Random/shuffle {}", if on { "enabled" } else { "disabled" }));
         Ok(())
     }
 
-    pub async fn toggle_single(&mut self, debug_log: &mut VecDeque<String>) -> Result<()> {
-        debug_log.push_back("Executing: mpc single".to_string());
+    /// Turn one of MPD's on/off playback flags on or off
+    fn set_flag(&mut self, flag: &str, on: bool, debug_log: &mut VecDeque<String>) -> Result<()> {
+        let state = if on { "on" } else { "off" };
+        debug_log.push_back(format!("Executing: mpc {} {}", flag, state));
 
         let output = self.mpc_cmd()
-            .arg("single")
+            .arg(flag)
+            .arg(state)
             .output()?;
 
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            if stdout.contains("On") {
-                debug_log.push_back("✓ Single track repeat enabled".to_string());
-            } else {
-                debug_log.push_back("✓ Single track repeat disabled".to_string());
-            }
-        } else {
+        if !output.status.success() {
             let error = String::from_utf8_lossy(&output.stderr);
-            debug_log.push_back(format!("✗ Failed to toggle single: {}", error));
+            debug_log.push_back(format!("✗ Failed to set {}: {}", flag, error));
+            return Err(anyhow::anyhow!("Failed to set {}: {}", flag, error));
         }
         Ok(())
     }
@@ -568,8 +669,31 @@ impl MpdController {
         Ok(None)
     }
 
+    /// URI of the song MPD is on, None when stopped
+    pub async fn get_current_file(&mut self) -> Result<Option<String>> {
+        let output = self.mpc_cmd().args(["current", "-f", "%file%"]).output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        // MPD reports local files added by absolute path as file:// URIs
+        let file = stdout.trim().trim_start_matches("file://").to_string();
+        Ok(Some(file).filter(|f| output.status.success() && !f.is_empty()))
+    }
+
+    /// File Drift queued at `position` (0-indexed), if it queued that far
+    pub fn queued_file(&self, position: usize) -> Option<&str> {
+        self.queued_files.get(position).map(String::as_str)
+    }
+
+    /// Where Drift queued `file` (0-indexed), if it did
+    pub fn queued_position(&self, file: &str) -> Option<usize> {
+        self.queued_files.iter().position(|f| f == file)
+    }
+
+    /// Whether Drift has queued anything it can check MPD against
+    pub fn has_queued_files(&self) -> bool {
+        !self.queued_files.is_empty()
+    }
+
     // Get detailed current playing info
-    #[allow(dead_code)]
     pub async fn get_current_song(&mut self) -> Result<Option<CurrentSong>> {
         let output = self.mpc_cmd()
             .args(["current", "-f", "%artist%|||%title%|||%album%|||%time%"])
@@ -622,6 +746,7 @@ impl MpdController {
 
         if output.status.success() {
             debug_log.push_back("✓ Queue cleared".to_string());
+            self.queued_files.clear();
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
             debug_log.push_back(format!("✗ Failed to clear queue: {}", stderr));
@@ -673,6 +798,9 @@ impl MpdController {
 
         if output.status.success() {
             debug_log.push_back(format!("✓ Removed track at position {}", position));
+            if position < self.queued_files.len() {
+                self.queued_files.remove(position);
+            }
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
             debug_log.push_back(format!("✗ Failed to remove track: {}", stderr));
@@ -703,6 +831,10 @@ impl MpdController {
 
         if output.status.success() {
             debug_log.push_back(format!("✓ Moved track from position {} to {}", from_pos, to_pos));
+            if from < self.queued_files.len() && to < self.queued_files.len() {
+                let file = self.queued_files.remove(from);
+                self.queued_files.insert(to, file);
+            }
         } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
             debug_log.push_back(format!("✗ Failed to move track: {}", stderr));
@@ -713,11 +845,69 @@ impl MpdController {
     }
 }
 
+/// What happens at the end of a track, as one setting. MPD stores it as two
+/// independent flags whose combination isn't obvious: `single` without
+/// `repeat` stops after the track, `single` with `repeat` loops it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RepeatMode {
+    /// Play through the queue once
+    #[default]
+    Off,
+    /// Loop the whole queue
+    All,
+    /// Loop the current track
+    One,
+    /// Stop once the current track ends
+    StopAfterTrack,
+}
+
+impl RepeatMode {
+    pub fn from_flags(repeat: bool, single: bool) -> Self {
+        match (repeat, single) {
+            (false, false) => Self::Off,
+            (true, false) => Self::All,
+            (true, true) => Self::One,
+            (false, true) => Self::StopAfterTrack,
+        }
+    }
+
+    /// MPD's (repeat, single) flags for this mode
+    pub fn flags(self) -> (bool, bool) {
+        match self {
+            Self::Off => (false, false),
+            Self::All => (true, false),
+            Self::One => (true, true),
+            Self::StopAfterTrack => (false, true),
+        }
+    }
+
+    /// Next mode for the repeat key: off → all → one → off
+    pub fn cycle(self) -> Self {
+        match self {
+            Self::Off | Self::StopAfterTrack => Self::All,
+            Self::All => Self::One,
+            Self::One => Self::Off,
+        }
+    }
+
+    /// Short name for the Now Playing mode list, None when off
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            Self::Off => None,
+            Self::All => Some("repeat"),
+            Self::One => Some("repeat one"),
+            Self::StopAfterTrack => Some("stop after track"),
+        }
+    }
+}
+
 // PlayerStatus fields are populated by get_status but some are unused in current UI
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct PlayerStatus {
     pub is_playing: bool,
+    /// Neither playing nor paused, e.g. after the end of the queue
+    pub is_stopped: bool,
     pub current_track: Option<String>,
     pub volume: Option<u8>,
     pub elapsed: Option<Duration>,
@@ -725,4 +915,12 @@ pub struct PlayerStatus {
     pub repeat: bool,
     pub random: bool,
     pub single: bool,
-}
\ No newline at end of file
+    /// Player error reported by MPD (e.g. a stream that failed to open)
+    pub error: Option<String>,
+}
+
+impl PlayerStatus {
+    pub fn repeat_mode(&self) -> RepeatMode {
+        RepeatMode::from_flags(self.repeat, self.single)
+    }
+}
@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::service::Track;
+
+/// Placeholders `integrations.nowplaying_format` can use: the fields
+/// downloads are filed by (`artist/album/title.flac`)
+pub const PLACEHOLDERS: &[&str] = &["artist", "album", "title"];
+
+/// Fill `{artist}`, `{title}` and `{album}` in an
+/// `integrations.nowplaying_format` string
+pub fn format_track(format: &str, track: &Track) -> String {
+    format
+        .replace("{artist}", &track.artist)
+        .replace("{title}", &track.title)
+        .replace("{album}", &track.album)
+}
+
+/// `{name}`s in `format` that aren't one of [`PLACEHOLDERS`], so a typo
+/// like `{Artist}` is reported instead of written out literally
+pub fn unknown_placeholders(format: &str) -> Vec<&str> {
+    let mut unknown = Vec::new();
+    let mut rest = format;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let name = &rest[start + 1..start + len];
+        if !PLACEHOLDERS.contains(&name) {
+            unknown.push(name);
+        }
+        rest = &rest[start + len + 1..];
+    }
+    unknown
+}
+
+/// Replace the file at `path` with `text` in one rename, so an overlay
+/// polling it never reads it half-written
+pub fn write_atomic(path: &Path, text: &str) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .with_context(|| format!("{} is not a file path", path.display()))?;
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).context("Failed to create now playing directory")?;
+    }
+
+    let tmp = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+    fs::write(&tmp, text).context("Failed to write now playing file")?;
+    fs::rename(&tmp, path).context("Failed to replace now playing file")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::{CoverArt, ServiceType};
+
+    #[test]
+    fn test_format_track() {
+        let track = Track {
+            id: "1".to_string(),
+            title: "Windowlicker".to_string(),
+            artist: "Aphex Twin".to_string(),
+            album: "Windowlicker".to_string(),
+            album_id: None,
+            duration_seconds: 367,
+            cover_art: CoverArt::None,
+            service: ServiceType::Tidal,
+            available: true,
+        };
+        assert_eq!(format_track("{artist} – {title}", &track), "Aphex Twin – Windowlicker");
+        assert_eq!(format_track("♪ {title} ({album})", &track), "♪ Windowlicker (Windowlicker)");
+        assert_eq!(
+            format_track("{artist} — {title} [{album}]", &track),
+            "Aphex Twin — Windowlicker [Windowlicker]"
+        );
+    }
+
+    #[test]
+    fn test_unknown_placeholders() {
+        assert!(unknown_placeholders("{artist} — {title} [{album}]").is_empty());
+        assert!(unknown_placeholders("no placeholders {").is_empty());
+        assert_eq!(unknown_placeholders("{Artist} - {title} ({year})"), vec!["Artist", "year"]);
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_contents() {
+        let dir = std::env::temp_dir().join(format!("drift-nowplaying-{}", std::process::id()));
+        let path = dir.join("nowplaying.txt");
+
+        write_atomic(&path, "Artist – First").unwrap();
+        write_atomic(&path, "Artist – Second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Artist – Second");
+        assert!(!dir.join(".nowplaying.txt.tmp").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::service::Track;
+
+const VIEW_STATE_FILE_NAME: &str = "playlist_views.toml";
+
+/// Sort order for a playlist's track list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackSort {
+    /// The playlist's own order
+    #[default]
+    Playlist,
+    Title,
+    Artist,
+    Album,
+    Duration,
+}
+
+impl TrackSort {
+    pub fn next(self) -> Self {
+        match self {
+            TrackSort::Playlist => TrackSort::Title,
+            TrackSort::Title => TrackSort::Artist,
+            TrackSort::Artist => TrackSort::Album,
+            TrackSort::Album => TrackSort::Duration,
+            TrackSort::Duration => TrackSort::Playlist,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TrackSort::Playlist => "playlist order",
+            TrackSort::Title => "title",
+            TrackSort::Artist => "artist",
+            TrackSort::Album => "album",
+            TrackSort::Duration => "duration",
+        }
+    }
+
+    /// Sort tracks in place. `positions` holds each track's index in the
+    /// playlist and is reordered alongside, so the original order can always
+    /// be recovered. Ties keep playlist order.
+    pub fn apply(self, tracks: &mut Vec<Track>, positions: &mut Vec<usize>) {
+        let mut paired: Vec<(usize, Track)> = positions.drain(..).zip(tracks.drain(..)).collect();
+
+        paired.sort_by(|(pa, a), (pb, b)| {
+            let by_field = match self {
+                TrackSort::Playlist => std::cmp::Ordering::Equal,
+                TrackSort::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+                TrackSort::Artist => a.artist.to_lowercase().cmp(&b.artist.to_lowercase())
+                    .then_with(|| a.album.to_lowercase().cmp(&b.album.to_lowercase())),
+                TrackSort::Album => a.album.to_lowercase().cmp(&b.album.to_lowercase()),
+                TrackSort::Duration => a.duration_seconds.cmp(&b.duration_seconds),
+            };
+            by_field.then(pa.cmp(pb))
+        });
+
+        for (position, track) in paired {
+            positions.push(position);
+            tracks.push(track);
+        }
+    }
+}
+
+/// Remembered view settings for one playlist
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlaylistViewState {
+    pub sort: TrackSort,
+    pub selected_track: usize,
+}
+
+/// Per-playlist view state, keyed by playlist id and persisted locally
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlaylistViewStore {
+    playlists: HashMap<String, PlaylistViewState>,
+}
+
+impl PlaylistViewStore {
+    /// Load the store, starting empty if the file is missing or unreadable
+    pub fn load() -> Self {
+        get_store_path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = get_store_path()?;
+        let contents = toml::to_string_pretty(self)
+            .context("Failed to serialize playlist view state")?;
+        fs::write(&path, contents)
+            .context("Failed to write playlist view state")?;
+        Ok(())
+    }
+
+    pub fn get(&self, playlist_id: &str) -> PlaylistViewState {
+        self.playlists.get(playlist_id).cloned().unwrap_or_default()
+    }
+
+    /// Store state for a playlist, returning whether anything changed.
+    /// Default state is dropped rather than stored.
+    pub fn set(&mut self, playlist_id: &str, state: PlaylistViewState) -> bool {
+        if state == PlaylistViewState::default() {
+            return self.playlists.remove(playlist_id).is_some();
+        }
+        self.playlists.insert(playlist_id.to_string(), state.clone()) != Some(state)
+    }
+}
+
+fn get_store_path() -> Result<PathBuf> {
+    let config_dir = crate::config::drift_config_dir()
+        .context("Failed to get config directory")?;
+
+    fs::create_dir_all(&config_dir)
+        .context("Failed to create config directory")?;
+
+    Ok(config_dir.join(VIEW_STATE_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::{CoverArt, ServiceType};
+
+    fn track(title: &str, artist: &str, duration: u32) -> Track {
+        Track {
+            id: title.to_string(),
+            title: title.to_string(),
+            artist: artist.to_string(),
+            album: String::new(),
+            album_id: None,
+            duration_seconds: duration,
+            cover_art: CoverArt::None,
+            service: ServiceType::Tidal,
+            available: true,
+        }
+    }
+
+    #[test]
+    fn test_sort_round_trips_to_playlist_order() {
+        let mut tracks = vec![track("b", "Zed", 200), track("A", "Amy", 100), track("c", "Amy", 300)];
+        let mut positions: Vec<usize> = (0..tracks.len()).collect();
+
+        TrackSort::Title.apply(&mut tracks, &mut positions);
+        let titles: Vec<_> = tracks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["A", "b", "c"]);
+        assert_eq!(positions, vec![1, 0, 2]);
+
+        TrackSort::Artist.apply(&mut tracks, &mut positions);
+        assert_eq!(positions, vec![1, 2, 0]);
+
+        TrackSort::Playlist.apply(&mut tracks, &mut positions);
+        let titles: Vec<_> = tracks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["b", "A", "c"]);
+        assert_eq!(positions, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_store_set_and_serialize() {
+        let mut store = PlaylistViewStore::default();
+        let state = PlaylistViewState { sort: TrackSort::Duration, selected_track: 4 };
+
+        assert!(store.set("p1", state.clone()));
+        assert!(!store.set("p1", state.clone()));
+        assert_eq!(store.get("p1"), state);
+        assert_eq!(store.get("missing"), PlaylistViewState::default());
+
+        let toml = toml::to_string_pretty(&store).unwrap();
+        assert!(toml.contains("sort = \"duration\""));
+        let parsed: PlaylistViewStore = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed.get("p1"), state);
+
+        // Resetting to default forgets the playlist
+        assert!(store.set("p1", PlaylistViewState::default()));
+        assert!(store.playlists.is_empty());
+    }
+}
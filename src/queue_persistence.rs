@@ -99,17 +99,18 @@ impl From<&PersistedTrack> for Track {
             title: pt.title.clone(),
             artist: pt.artist.clone(),
             album: pt.album.clone(),
+            album_id: None,
             duration_seconds: pt.duration_seconds,
             cover_art: CoverArt::from_tidal_option(pt.cover_art_id.clone()),
             service,
+            available: true,
         }
     }
 }
 
 fn get_queue_path() -> Result<PathBuf> {
-    let config_dir = dirs::config_dir()
-        .context("Failed to get config directory")?
-        .join("drift");
+    let config_dir = crate::config::drift_config_dir()
+        .context("Failed to get config directory")?;
 
     fs::create_dir_all(&config_dir)
         .context("Failed to create config directory")?;
@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use crate::config::{ListenBrainzConfig, NetworkConfig};
+use crate::history_db::HistoryEntry;
+use crate::service::{ServiceType, Track};
+
+/// Somewhere plays are reported to. The app tells it when a track starts
+/// and, once enough of it has played, submits the listen.
+#[async_trait]
+pub trait Scrobbler: Send + Sync {
+    /// Name shown in debug messages
+    fn name(&self) -> &'static str;
+
+    /// Mark `track` as playing right now
+    async fn update_now_playing(&self, track: &Track) -> Result<()>;
+
+    /// Record a finished listen, timestamped with when it started
+    async fn submit_listen(&self, entry: &HistoryEntry) -> Result<()>;
+}
+
+/// Build the configured scrobbler, if any
+pub fn from_config(config: &ListenBrainzConfig, network: &NetworkConfig) -> Result<Option<Arc<dyn Scrobbler>>> {
+    match config.token.as_deref().map(str::trim) {
+        Some(token) if !token.is_empty() => {
+            let scrobbler = ListenBrainzScrobbler::new(token, &config.api_url, network)?;
+            Ok(Some(Arc::new(scrobbler)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// A listen counts once it has played for half its length or four minutes,
+/// whichever comes first (the Last.fm rule ListenBrainz also follows).
+/// Tracks of unknown length count after four minutes.
+pub fn listen_threshold_secs(duration_seconds: u32) -> u32 {
+    const MAX_SECS: u32 = 240;
+    match duration_seconds {
+        0 => MAX_SECS,
+        d => (d / 2).min(MAX_SECS),
+    }
+}
+
+/// Submits to the ListenBrainz API with a user token
+/// (listenbrainz.org/settings)
+pub struct ListenBrainzScrobbler {
+    client: reqwest::Client,
+    token: String,
+    api_url: String,
+}
+
+impl ListenBrainzScrobbler {
+    pub fn new(token: &str, api_url: &str, network: &NetworkConfig) -> Result<Self> {
+        let client = crate::service::http_client(
+            network,
+            "listenbrainz",
+            Some(concat!("drift/", env!("CARGO_PKG_VERSION"))),
+        )?;
+        Ok(Self {
+            client,
+            token: token.to_string(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    async fn submit(&self, payload: Value) -> Result<()> {
+        let response = self.client
+            .post(format!("{}/1/submit-listens", self.api_url))
+            .header("Authorization", format!("Token {}", self.token))
+            .json(&payload)
+            .send()
+            .await
+            .context("ListenBrainz request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!("ListenBrainz returned {}: {}", status, body.trim());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Scrobbler for ListenBrainzScrobbler {
+    fn name(&self) -> &'static str {
+        "ListenBrainz"
+    }
+
+    async fn update_now_playing(&self, track: &Track) -> Result<()> {
+        let metadata = track_metadata(&track.title, &track.artist, &track.album, track.duration_seconds, track.service);
+        self.submit(listen_payload("playing_now", None, metadata)).await
+    }
+
+    async fn submit_listen(&self, entry: &HistoryEntry) -> Result<()> {
+        let metadata = track_metadata(&entry.title, &entry.artist, &entry.album, entry.duration_seconds, entry.service);
+        self.submit(listen_payload("single", Some(entry.played_at.timestamp()), metadata)).await
+    }
+}
+
+/// `submit-listens` body for one listen. "playing_now" must not carry a
+/// `listened_at`; "single" must, in Unix seconds.
+fn listen_payload(listen_type: &str, listened_at: Option<i64>, track_metadata: Value) -> Value {
+    let mut listen = json!({ "track_metadata": track_metadata });
+    if let Some(listened_at) = listened_at {
+        listen["listened_at"] = json!(listened_at);
+    }
+    json!({
+        "listen_type": listen_type,
+        "payload": [listen],
+    })
+}
+
+/// Track fields plus `additional_info`, where the service the track
+/// streamed from is recorded alongside the client details
+fn track_metadata(title: &str, artist: &str, album: &str, duration_seconds: u32, service: ServiceType) -> Value {
+    let mut additional_info = json!({
+        "media_player": "drift",
+        "submission_client": "drift",
+        "submission_client_version": env!("CARGO_PKG_VERSION"),
+        "music_service": music_service_domain(service),
+        "music_service_name": service.to_string(),
+    });
+    if duration_seconds > 0 {
+        additional_info["duration_ms"] = json!(u64::from(duration_seconds) * 1000);
+    }
+
+    let mut metadata = json!({
+        "artist_name": artist,
+        "track_name": title,
+        "additional_info": additional_info,
+    });
+    if !album.is_empty() {
+        metadata["release_name"] = json!(album);
+    }
+    metadata
+}
+
+/// Canonical domain ListenBrainz expects in `music_service`
+fn music_service_domain(service: ServiceType) -> &'static str {
+    match service {
+        ServiceType::Tidal => "tidal.com",
+        ServiceType::YouTube => "youtube.com",
+        ServiceType::Bandcamp => "bandcamp.com",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_playing_now_has_no_timestamp() {
+        let metadata = track_metadata("Song", "Artist", "Album", 200, ServiceType::Tidal);
+        let payload = listen_payload("playing_now", None, metadata);
+
+        assert_eq!(payload["listen_type"], "playing_now");
+        let listen = &payload["payload"][0];
+        assert!(listen.get("listened_at").is_none());
+        assert_eq!(listen["track_metadata"]["release_name"], "Album");
+        let info = &listen["track_metadata"]["additional_info"];
+        assert_eq!(info["music_service"], "tidal.com");
+        assert_eq!(info["music_service_name"], "tidal");
+        assert_eq!(info["duration_ms"], 200_000);
+    }
+
+    #[test]
+    fn test_single_listen_is_timestamped() {
+        let metadata = track_metadata("Song", "Artist", "", 0, ServiceType::Bandcamp);
+        let payload = listen_payload("single", Some(1_700_000_000), metadata);
+
+        let listen = &payload["payload"][0];
+        assert_eq!(listen["listened_at"], 1_700_000_000);
+        assert!(listen["track_metadata"].get("release_name").is_none());
+        assert!(listen["track_metadata"]["additional_info"].get("duration_ms").is_none());
+    }
+
+    #[test]
+    fn test_listen_threshold() {
+        assert_eq!(listen_threshold_secs(200), 100);
+        assert_eq!(listen_threshold_secs(1200), 240);
+        assert_eq!(listen_threshold_secs(0), 240);
+    }
+}
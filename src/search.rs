@@ -35,9 +35,9 @@ impl SearchHistory {
     const FILE_NAME: &'static str = "search_history.toml";
 
     fn storage_path() -> Result<PathBuf> {
-        let config_dir = dirs::config_dir()
+        let config_dir = crate::config::drift_config_dir()
             .ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
-        Ok(config_dir.join("drift").join(Self::FILE_NAME))
+        Ok(config_dir.join(Self::FILE_NAME))
     }
 
     /// Load search history from disk
@@ -506,6 +506,72 @@ impl ResultScorer {
         Self::sort_albums(&mut results.albums, query);
         Self::sort_artists(&mut results.artists, query);
     }
+
+    /// Score a track against an in-list filter: its "title artist album"
+    /// must contain the query's characters in order, and title/artist
+    /// matches rank like [`ResultScorer::score_track`]. None if it doesn't match.
+    pub fn filter_score(track: &Track, query: &str) -> Option<u32> {
+        let query_lower = query.to_lowercase();
+        let title_lower = track.title.to_lowercase();
+        let artist_lower = track.artist.to_lowercase();
+        let haystack = format!("{} {} {}", title_lower, artist_lower, track.album.to_lowercase());
+        let mut score = subsequence_score(&haystack, &query_lower)?;
+
+        if title_lower.starts_with(&query_lower) {
+            score += 500;
+        } else if title_lower.contains(&query_lower) {
+            score += 200;
+        }
+        if artist_lower.starts_with(&query_lower) {
+            score += 400;
+        } else if artist_lower.contains(&query_lower) {
+            score += 150;
+        }
+        Some(score)
+    }
+
+    /// Indices of `tracks` matching `query`, best match first (ties keep
+    /// list order)
+    pub fn filter_tracks(tracks: &[Track], query: &str) -> Vec<usize> {
+        let mut matches: Vec<(usize, u32)> = tracks
+            .iter()
+            .enumerate()
+            .filter_map(|(i, track)| Self::filter_score(track, query).map(|score| (i, score)))
+            .collect();
+        matches.sort_by(|a, b| b.1.cmp(&a.1));
+        matches.into_iter().map(|(i, _)| i).collect()
+    }
+}
+
+/// Score `needle` as a subsequence of `haystack` (both lowercase), or None
+/// if its characters don't all appear in order. Runs of adjacent
+/// characters and matches at word starts score higher.
+pub fn subsequence_score(haystack: &str, needle: &str) -> Option<u32> {
+    let mut score = 0u32;
+    let mut chars = haystack.chars().enumerate().peekable();
+    let mut prev_char = ' ';
+    let mut last_match: Option<usize> = None;
+
+    for wanted in needle.chars() {
+        loop {
+            let (i, c) = chars.next()?;
+            let at_word_start = !prev_char.is_alphanumeric();
+            prev_char = c;
+            if c != wanted {
+                continue;
+            }
+            score += 10;
+            if last_match.is_some_and(|last| last + 1 == i) {
+                score += 15;
+            }
+            if at_word_start {
+                score += 10;
+            }
+            last_match = Some(i);
+            break;
+        }
+    }
+    Some(score)
 }
 
 #[cfg(test)]
@@ -555,9 +621,11 @@ mod tests {
             title: "Anti-Hero".to_string(),
             artist: "Taylor Swift".to_string(),
             album: "Midnights".to_string(),
+            album_id: None,
             duration_seconds: 200,
             cover_art: crate::service::CoverArt::None,
             service: ServiceType::Tidal,
+            available: true,
         };
 
         let score1 = ResultScorer::score_track(&track, "Anti-Hero");
@@ -576,22 +644,60 @@ mod tests {
                 title: "Random Song".to_string(),
                 artist: "Unknown".to_string(),
                 album: "Album".to_string(),
+                album_id: None,
                 duration_seconds: 200,
                 cover_art: crate::service::CoverArt::None,
                 service: ServiceType::YouTube,
+                available: true,
             },
             Track {
                 id: "2".to_string(),
                 title: "Anti-Hero".to_string(),
                 artist: "Taylor Swift".to_string(),
                 album: "Midnights".to_string(),
+                album_id: None,
                 duration_seconds: 200,
                 cover_art: crate::service::CoverArt::None,
                 service: ServiceType::Tidal,
+                available: true,
             },
         ];
 
         ResultScorer::sort_tracks(&mut tracks, "Anti-Hero");
         assert_eq!(tracks[0].title, "Anti-Hero");
     }
+
+    #[test]
+    fn test_subsequence_score() {
+        assert!(subsequence_score("anti-hero taylor swift", "ahr").is_some());
+        assert!(subsequence_score("anti-hero taylor swift", "swa").is_none());
+        // Contiguous, word-start matches beat scattered ones
+        let contiguous = subsequence_score("anti-hero", "her").unwrap();
+        let scattered = subsequence_score("anti-hero", "atr").unwrap();
+        assert!(contiguous > scattered);
+    }
+
+    #[test]
+    fn test_filter_tracks_ranks_title_matches_first() {
+        let track = |id: &str, title: &str, artist: &str| Track {
+            id: id.to_string(),
+            title: title.to_string(),
+            artist: artist.to_string(),
+            album: "Album".to_string(),
+            album_id: None,
+            duration_seconds: 200,
+            cover_art: crate::service::CoverArt::None,
+            service: ServiceType::Tidal,
+            available: true,
+        };
+        let tracks = vec![
+            track("1", "Lavender Haze", "Taylor Swift"),
+            track("2", "Mastermind", "Taylor Swift"),
+            track("3", "Maroon", "Taylor Swift"),
+        ];
+
+        assert_eq!(ResultScorer::filter_tracks(&tracks, "mar"), vec![2, 1]);
+        assert_eq!(ResultScorer::filter_tracks(&tracks, "taylor").len(), 3);
+        assert!(ResultScorer::filter_tracks(&tracks, "xyz").is_empty());
+    }
 }
@@ -23,9 +23,8 @@ pub struct SearchCache {
 
 impl SearchCache {
     pub fn new(ttl_seconds: u64) -> Result<Self> {
-        let cache_dir = dirs::cache_dir()
+        let cache_dir = crate::config::drift_cache_dir()
             .context("Failed to get cache directory")?
-            .join("drift")
             .join("search");
 
         std::fs::create_dir_all(&cache_dir)
@@ -10,23 +10,32 @@ use tokio::process::Command;
 use super::bandcamp_storage::{
     BandcampStorage, SavedPlaylist, StoredAlbum, StoredArtist, StoredTrack,
 };
-use super::{Album, Artist, CoverArt, MusicService, Playlist, SearchResults, ServiceType, Track};
-use crate::config::BandcampConfig;
+use super::{Album, AlbumType, Artist, CoverArt, MusicService, Playlist, SearchResults, ServiceType, Track};
+use super::rate_limit::{SendRateLimited, ServiceLimiter};
+use crate::config::{BandcampConfig, NetworkConfig};
+
+/// Browser User-Agent; Bandcamp serves bot-looking clients differently
+const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64; rv:120.0) Gecko/20100101 Firefox/120.0";
 
 /// Bandcamp client using yt-dlp for stream extraction and HTML scraping for search
+#[derive(Clone)]
 pub struct BandcampClient {
     ytdlp_path: PathBuf,
     config: BandcampConfig,
     http_client: reqwest::Client,
+    /// Spaces out page fetches so searches don't get throttled
+    rate_limiter: ServiceLimiter,
     audio_quality: String,
     authenticated: bool,
+    /// `[network]` User-Agent/headers passed to every yt-dlp call
+    network_args: Vec<String>,
 }
 
 impl BandcampClient {
     /// Create a new Bandcamp client
     ///
     /// Errors if yt-dlp is not found in PATH.
-    pub async fn new(config: BandcampConfig) -> Result<Self> {
+    pub async fn new(config: BandcampConfig, network: &NetworkConfig) -> Result<Self> {
         let ytdlp_path = "yt-dlp";
 
         // Verify yt-dlp exists
@@ -52,9 +61,7 @@ impl BandcampClient {
             }
         }
 
-        let http_client = reqwest::Client::builder()
-            .user_agent("Mozilla/5.0 (X11; Linux x86_64; rv:120.0) Gecko/20100101 Firefox/120.0")
-            .build()?;
+        let http_client = super::http_client(network, ServiceType::Bandcamp, Some(DEFAULT_USER_AGENT))?;
 
         // Test authentication if credentials provided
         let authenticated = config.cookie_file.is_some() || config.cookies_from_browser.is_some();
@@ -63,16 +70,18 @@ impl BandcampClient {
             ytdlp_path: PathBuf::from(ytdlp_path),
             config,
             http_client,
+            rate_limiter: ServiceLimiter::new(network.requests_per_second),
             audio_quality: "mp3-128".to_string(),
             authenticated,
+            network_args: super::ytdlp_network_args(network, ServiceType::Bandcamp),
         })
     }
 
     // === yt-dlp Helper Methods ===
 
-    /// Build cookie arguments for yt-dlp
+    /// Build cookie and network arguments for yt-dlp
     fn cookie_args(&self) -> Vec<String> {
-        let mut args = Vec::new();
+        let mut args = self.network_args.clone();
         if let Some(ref cookie_file) = self.config.cookie_file {
             args.push("--cookies".to_string());
             args.push(cookie_file.clone());
@@ -121,6 +130,35 @@ impl BandcampClient {
             .ok_or_else(|| anyhow!("No track info returned"))
     }
 
+    /// Fetch a Bandcamp page as HTML
+    async fn fetch_page(&self, url: &str) -> Result<String> {
+        let resp = self.http_client.get(url).send_limited(&self.rate_limiter).await?.error_for_status()?;
+        Ok(resp.text().await?)
+    }
+
+    /// Pull the `mp3-128` stream URL from a track page's `data-tralbum` JSON.
+    ///
+    /// Returns `None` when the page has no track info at all, and
+    /// `Some(None)` when it does but the track has no public stream.
+    fn extract_stream_url(html: &str) -> Option<Option<String>> {
+        let document = Html::parse_document(html);
+        let selector = Selector::parse("script[data-tralbum]").ok()?;
+        let tralbum: Value = document
+            .select(&selector)
+            .next()
+            .and_then(|el| el.value().attr("data-tralbum"))
+            .and_then(|data| serde_json::from_str(data).ok())?;
+        let track = tralbum["trackinfo"].as_array()?.first()?;
+
+        Some(track["file"]["mp3-128"].as_str().map(|url| {
+            if url.starts_with("//") {
+                format!("https:{}", url)
+            } else {
+                url.to_string()
+            }
+        }))
+    }
+
     /// Parse a JSON value into a Track
     fn parse_track(json: &Value) -> Option<Track> {
         // Bandcamp URLs serve as unique IDs
@@ -145,6 +183,11 @@ impl BandcampClient {
             .unwrap_or("")
             .to_string();
 
+        let album_url = json
+            .get("playlist_url")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
         let duration = json
             .get("duration")
             .and_then(|v| v.as_f64())
@@ -158,9 +201,11 @@ impl BandcampClient {
             title,
             artist,
             album,
+            album_id: album_url,
             duration_seconds: duration,
             cover_art: thumbnail.map(CoverArt::Url).unwrap_or(CoverArt::None),
             service: ServiceType::Bandcamp,
+            available: true,
         })
     }
 
@@ -192,6 +237,7 @@ impl BandcampClient {
             num_tracks,
             cover_art: thumbnail.map(CoverArt::Url).unwrap_or(CoverArt::None),
             service: ServiceType::Bandcamp,
+            album_type: AlbumType::Unknown,
         })
     }
 
@@ -226,6 +272,7 @@ impl BandcampClient {
             title: stored.title.clone(),
             artist: stored.artist.clone(),
             album: stored.album.clone(),
+            album_id: stored.album_url.clone(),
             duration_seconds: stored.duration_seconds,
             cover_art: stored
                 .thumbnail_url
@@ -233,6 +280,7 @@ impl BandcampClient {
                 .map(CoverArt::Url)
                 .unwrap_or(CoverArt::None),
             service: ServiceType::Bandcamp,
+            available: true,
         }
     }
 
@@ -249,6 +297,7 @@ impl BandcampClient {
                 .map(CoverArt::Url)
                 .unwrap_or(CoverArt::None),
             service: ServiceType::Bandcamp,
+            album_type: AlbumType::Unknown,
         }
     }
 
@@ -274,22 +323,49 @@ impl BandcampClient {
 
     // === HTML Search Scraping ===
 
+    /// Fetch search result pages for one item type (`t`, `a` or `b`) until
+    /// `limit` results are collected. Bandcamp returns ~18 results per page.
+    async fn search_pages<T: Send>(
+        &self,
+        query: &str,
+        item_type: &str,
+        limit: usize,
+        parse: fn(&str) -> Vec<T>,
+    ) -> Vec<T> {
+        const MAX_PAGES: usize = 5;
+
+        let mut results = Vec::new();
+        for page in 1..=MAX_PAGES {
+            let url = format!(
+                "https://bandcamp.com/search?q={}&item_type={}&page={}",
+                urlencoding::encode(query),
+                item_type,
+                page
+            );
+            let Ok(html) = self.fetch_page(&url).await else {
+                break;
+            };
+            let found = parse(&html);
+            if found.is_empty() {
+                break;
+            }
+            results.extend(found);
+            if results.len() >= limit {
+                break;
+            }
+        }
+        results.truncate(limit);
+        results
+    }
+
     /// Search Bandcamp for tracks
     async fn search_tracks(&self, query: &str, limit: usize) -> Vec<Track> {
-        let url = format!(
-            "https://bandcamp.com/search?q={}&item_type=t",
-            urlencoding::encode(query)
-        );
-
-        let html = match self.http_client.get(&url).send().await {
-            Ok(resp) => match resp.text().await {
-                Ok(text) => text,
-                Err(_) => return vec![],
-            },
-            Err(_) => return vec![],
-        };
+        self.search_pages(query, "t", limit, Self::parse_track_results).await
+    }
 
-        let document = Html::parse_document(&html);
+    /// Parse track results from one search results page
+    fn parse_track_results(html: &str) -> Vec<Track> {
+        let document = Html::parse_document(html);
         let result_selector = Selector::parse(".searchresult.track").unwrap_or_else(|_| {
             Selector::parse(".result-info").unwrap()
         });
@@ -305,7 +381,7 @@ impl BandcampClient {
 
         let mut tracks = Vec::new();
 
-        for result in document.select(&result_selector).take(limit) {
+        for result in document.select(&result_selector) {
             let title = result
                 .select(&title_selector)
                 .next()
@@ -344,9 +420,11 @@ impl BandcampClient {
                     title,
                     artist,
                     album: String::new(),
+                    album_id: None,
                     duration_seconds: 0, // Search results don't include duration
                     cover_art: thumbnail.map(CoverArt::Url).unwrap_or(CoverArt::None),
                     service: ServiceType::Bandcamp,
+                    available: true,
                 });
             }
         }
@@ -356,20 +434,12 @@ impl BandcampClient {
 
     /// Search Bandcamp for albums
     async fn search_albums(&self, query: &str, limit: usize) -> Vec<Album> {
-        let url = format!(
-            "https://bandcamp.com/search?q={}&item_type=a",
-            urlencoding::encode(query)
-        );
-
-        let html = match self.http_client.get(&url).send().await {
-            Ok(resp) => match resp.text().await {
-                Ok(text) => text,
-                Err(_) => return vec![],
-            },
-            Err(_) => return vec![],
-        };
+        self.search_pages(query, "a", limit, Self::parse_album_results).await
+    }
 
-        let document = Html::parse_document(&html);
+    /// Parse album results from one search results page
+    fn parse_album_results(html: &str) -> Vec<Album> {
+        let document = Html::parse_document(html);
         let result_selector = Selector::parse(".searchresult.album").unwrap_or_else(|_| {
             Selector::parse(".result-info").unwrap()
         });
@@ -385,7 +455,7 @@ impl BandcampClient {
 
         let mut albums = Vec::new();
 
-        for result in document.select(&result_selector).take(limit) {
+        for result in document.select(&result_selector) {
             let title = result
                 .select(&title_selector)
                 .next()
@@ -425,6 +495,7 @@ impl BandcampClient {
                     num_tracks: 0, // Search results don't include track count
                     cover_art: thumbnail.map(CoverArt::Url).unwrap_or(CoverArt::None),
                     service: ServiceType::Bandcamp,
+                    album_type: AlbumType::Unknown,
                 });
             }
         }
@@ -434,20 +505,12 @@ impl BandcampClient {
 
     /// Search Bandcamp for artists (bands)
     async fn search_artists(&self, query: &str, limit: usize) -> Vec<Artist> {
-        let url = format!(
-            "https://bandcamp.com/search?q={}&item_type=b",
-            urlencoding::encode(query)
-        );
-
-        let html = match self.http_client.get(&url).send().await {
-            Ok(resp) => match resp.text().await {
-                Ok(text) => text,
-                Err(_) => return vec![],
-            },
-            Err(_) => return vec![],
-        };
+        self.search_pages(query, "b", limit, Self::parse_artist_results).await
+    }
 
-        let document = Html::parse_document(&html);
+    /// Parse artist results from one search results page
+    fn parse_artist_results(html: &str) -> Vec<Artist> {
+        let document = Html::parse_document(html);
         let result_selector = Selector::parse(".searchresult.band").unwrap_or_else(|_| {
             Selector::parse(".result-info").unwrap()
         });
@@ -457,7 +520,7 @@ impl BandcampClient {
 
         let mut artists = Vec::new();
 
-        for result in document.select(&result_selector).take(limit) {
+        for result in document.select(&result_selector) {
             let name = result
                 .select(&name_selector)
                 .next()
@@ -507,6 +570,10 @@ impl MusicService for BandcampClient {
         ServiceType::Bandcamp
     }
 
+    fn clone_box(&self) -> Box<dyn MusicService> {
+        Box::new(self.clone())
+    }
+
     fn is_authenticated(&self) -> bool {
         self.authenticated
     }
@@ -520,8 +587,32 @@ impl MusicService for BandcampClient {
         };
     }
 
+    fn rate_limit_backoff(&self) -> Option<std::time::Duration> {
+        self.rate_limiter.backoff()
+    }
+
+    fn set_background(&mut self, background: bool) {
+        self.rate_limiter.set_background(background);
+    }
+
     async fn get_stream_url(&mut self, track_id: &str) -> Result<String> {
-        // track_id is the full URL for Bandcamp
+        // track_id is the full URL for Bandcamp. The public 128k stream is
+        // embedded in the page, which is much faster than spawning yt-dlp;
+        // other formats (purchased downloads) still need yt-dlp and cookies.
+        if self.audio_quality == "mp3-128" {
+            if let Ok(html) = self.fetch_page(track_id).await {
+                match Self::extract_stream_url(&html) {
+                    Some(Some(url)) => return Ok(url),
+                    Some(None) if !self.authenticated => {
+                        return Err(anyhow!(
+                            "No streamable source for this Bandcamp track (purchase-only or unreleased)"
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
         let mut cmd = Command::new(&self.ytdlp_path);
         cmd.args([
             "-f",
@@ -697,8 +788,101 @@ impl MusicService for BandcampClient {
         Ok(())
     }
 
+    async fn add_favorite_album(&mut self, album_id: &str) -> Result<()> {
+        // album_id is the full album URL
+        let info = self
+            .run_ytdlp_json(&["--flat-playlist", "-J", album_id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No album info returned"))?;
+
+        let stored = StoredAlbum {
+            url: album_id.to_string(),
+            album_id: info
+                .get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            title: info
+                .get("title")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown")
+                .to_string(),
+            artist: info
+                .get("artist")
+                .or_else(|| info.get("uploader"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown")
+                .to_string(),
+            artist_subdomain: Self::extract_subdomain(album_id).unwrap_or_default(),
+            num_tracks: info
+                .get("playlist_count")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as u32,
+            thumbnail_url: Self::extract_thumbnail(&info),
+            added_at: Utc::now(),
+        };
+
+        let mut storage = BandcampStorage::load().unwrap_or_default();
+        storage.add_favorite_album(stored);
+        storage.save()?;
+
+        Ok(())
+    }
+
+    async fn remove_favorite_album(&mut self, album_id: &str) -> Result<()> {
+        let mut storage = BandcampStorage::load().unwrap_or_default();
+        storage.remove_favorite_album(album_id);
+        storage.save()?;
+        Ok(())
+    }
+
+    async fn add_favorite_artist(&mut self, artist_id: &str) -> Result<()> {
+        // artist_id is the subdomain
+        let url = format!("https://{}.bandcamp.com", artist_id);
+        let info = self
+            .run_ytdlp_json(&["--flat-playlist", "-J", "--playlist-end", "1", &url])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No artist info returned"))?;
+
+        let stored = StoredArtist {
+            subdomain: artist_id.to_string(),
+            name: info
+                .get("uploader")
+                .or_else(|| info.get("title"))
+                .and_then(|v| v.as_str())
+                .unwrap_or(artist_id)
+                .to_string(),
+            url,
+            added_at: Utc::now(),
+        };
+
+        let mut storage = BandcampStorage::load().unwrap_or_default();
+        storage.add_favorite_artist(stored);
+        storage.save()?;
+
+        Ok(())
+    }
+
+    async fn remove_favorite_artist(&mut self, artist_id: &str) -> Result<()> {
+        // artist_id is the subdomain
+        let mut storage = BandcampStorage::load().unwrap_or_default();
+        storage.remove_favorite_artist(artist_id);
+        storage.save()?;
+        Ok(())
+    }
+
     // === Search ===
 
+    async fn get_track(&mut self, track_id: &str) -> Result<Track> {
+        // track_id is the full URL
+        let info = self.get_track_info(track_id).await?;
+        Self::parse_track(&info).ok_or_else(|| anyhow!("No track info for {}", track_id))
+    }
+
     async fn search(&mut self, query: &str, limit: usize) -> Result<SearchResults> {
         // Run all three searches in parallel
         let (tracks, albums, artists) = tokio::join!(
@@ -741,7 +925,7 @@ impl MusicService for BandcampClient {
         // Scrape the artist's /music page for albums
         let url = format!("https://{}.bandcamp.com/music", artist_id);
 
-        let html = match self.http_client.get(&url).send().await {
+        let html = match self.http_client.get(&url).send_limited(&self.rate_limiter).await {
             Ok(resp) => match resp.text().await {
                 Ok(text) => text,
                 Err(e) => return Err(anyhow!("Failed to read response: {}", e)),
@@ -799,6 +983,7 @@ impl MusicService for BandcampClient {
                     num_tracks: 0,
                     cover_art: thumbnail.map(CoverArt::Url).unwrap_or(CoverArt::None),
                     service: ServiceType::Bandcamp,
+                    album_type: AlbumType::Unknown,
                 });
             }
         }
@@ -1009,6 +1194,38 @@ mod tests {
         assert!(matches!(track.service, ServiceType::Bandcamp));
     }
 
+    #[test]
+    fn test_extract_stream_url() {
+        let page = r#"<html><script data-tralbum="{&quot;trackinfo&quot;:[{&quot;file&quot;:{&quot;mp3-128&quot;:&quot;https://t4.bcbits.com/stream/abc&quot;}}]}"></script></html>"#;
+        assert_eq!(
+            BandcampClient::extract_stream_url(page),
+            Some(Some("https://t4.bcbits.com/stream/abc".to_string()))
+        );
+
+        let no_stream = r#"<script data-tralbum="{&quot;trackinfo&quot;:[{&quot;file&quot;:null}]}"></script>"#;
+        assert_eq!(BandcampClient::extract_stream_url(no_stream), Some(None));
+
+        assert_eq!(BandcampClient::extract_stream_url("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_parse_track_results() {
+        let page = r#"<ul>
+            <li class="searchresult track">
+                <div class="art"><img src="https://f4.bcbits.com/img/a1_7.jpg"></div>
+                <div class="heading"><a href="https://artist.bandcamp.com/track/song">Song</a></div>
+                <div class="subhead">from Record by Artist</div>
+            </li>
+            <li class="searchresult track"><div class="heading"><a href="">Broken</a></div></li>
+        </ul>"#;
+
+        let tracks = BandcampClient::parse_track_results(page);
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].id, "https://artist.bandcamp.com/track/song");
+        assert_eq!(tracks[0].title, "Song");
+        assert!(matches!(tracks[0].cover_art, CoverArt::Url(ref url) if url.ends_with("a1_7.jpg")));
+    }
+
     #[test]
     fn test_parse_album() {
         let json: Value = serde_json::json!({
@@ -100,9 +100,9 @@ impl BandcampStorage {
 
     /// Get the storage file path
     fn storage_path() -> Result<PathBuf> {
-        let config_dir = dirs::config_dir()
+        let config_dir = crate::config::drift_config_dir()
             .ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
-        Ok(config_dir.join("drift").join("bandcamp_data.toml"))
+        Ok(config_dir.join("bandcamp_data.toml"))
     }
 
     /// Load storage from disk, returning default if not found
@@ -173,7 +173,6 @@ impl BandcampStorage {
     // === Favorite Albums ===
 
     /// Add an album to favorites
-    #[allow(dead_code)]
     pub fn add_favorite_album(&mut self, album: StoredAlbum) {
         if !self.favorite_albums.iter().any(|a| a.url == album.url) {
             self.favorite_albums.push(album);
@@ -181,7 +180,6 @@ impl BandcampStorage {
     }
 
     /// Remove an album from favorites by URL
-    #[allow(dead_code)]
     pub fn remove_favorite_album(&mut self, album_url: &str) -> bool {
         let len_before = self.favorite_albums.len();
         self.favorite_albums.retain(|a| a.url != album_url);
@@ -191,7 +189,6 @@ impl BandcampStorage {
     // === Favorite Artists ===
 
     /// Add an artist to favorites
-    #[allow(dead_code)]
     pub fn add_favorite_artist(&mut self, artist: StoredArtist) {
         if !self.favorite_artists.iter().any(|a| a.subdomain == artist.subdomain) {
             self.favorite_artists.push(artist);
@@ -199,7 +196,6 @@ impl BandcampStorage {
     }
 
     /// Remove an artist from favorites by subdomain
-    #[allow(dead_code)]
     pub fn remove_favorite_artist(&mut self, subdomain: &str) -> bool {
         let len_before = self.favorite_artists.len();
         self.favorite_artists.retain(|a| a.subdomain != subdomain);
@@ -54,9 +54,9 @@ impl MixedPlaylistStorage {
 
     /// Get the storage file path
     fn storage_path() -> Result<PathBuf> {
-        let config_dir = dirs::config_dir()
+        let config_dir = crate::config::drift_config_dir()
             .ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
-        Ok(config_dir.join("drift").join(Self::FILE_NAME))
+        Ok(config_dir.join(Self::FILE_NAME))
     }
 
     /// Load storage from disk, returning default if not found
@@ -225,6 +225,7 @@ impl MixedPlaylistStorage {
                         title: tr.title.clone(),
                         artist: tr.artist.clone(),
                         album: tr.album.clone(),
+                        album_id: None,
                         duration_seconds: tr.duration_seconds,
                         cover_art: tr
                             .cover_art_url
@@ -232,6 +233,7 @@ impl MixedPlaylistStorage {
                             .map(CoverArt::Url)
                             .unwrap_or(CoverArt::None),
                         service: tr.service,
+                        available: true,
                     })
                     .collect()
             })
@@ -259,9 +261,11 @@ mod tests {
             title: format!("Test Track from {}", service),
             artist: "Test Artist".to_string(),
             album: "Test Album".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art: CoverArt::None,
             service,
+            available: true,
         }
     }
 
@@ -20,6 +20,16 @@ pub struct MultiServiceManager {
     init_errors: HashMap<ServiceType, String>,
 }
 
+impl Clone for MultiServiceManager {
+    fn clone(&self) -> Self {
+        Self {
+            services: self.services.iter().map(|(k, s)| (*k, s.clone_box())).collect(),
+            primary: self.primary,
+            init_errors: self.init_errors.clone(),
+        }
+    }
+}
+
 impl MultiServiceManager {
     /// Initialize all available services based on config
     pub async fn new(config: &Config) -> Result<Self> {
@@ -27,9 +37,11 @@ impl MultiServiceManager {
         let mut init_errors: HashMap<ServiceType, String> = HashMap::new();
 
         // Always try Tidal
-        match TidalClient::new().await {
+        match TidalClient::new(&config.network).await {
             Ok(mut client) => {
                 client.set_audio_quality(&config.playback.audio_quality);
+                client.set_artist_albums_limit(config.tidal.artist_albums_limit);
+                client.set_artist_top_tracks_limit(config.tidal.artist_top_tracks_limit);
                 services.insert(ServiceType::Tidal, Box::new(client));
             }
             Err(e) => {
@@ -41,7 +53,7 @@ impl MultiServiceManager {
         if config.service.auto_detect && Self::check_ytdlp_available().await {
             // YouTube
             if Self::should_enable_service(&config.service.enabled, "youtube") {
-                match YouTubeClient::new(None).await {
+                match YouTubeClient::new(None, &config.network).await {
                     Ok(mut client) => {
                         client.set_audio_quality(&config.playback.audio_quality);
                         services.insert(ServiceType::YouTube, Box::new(client));
@@ -54,7 +66,7 @@ impl MultiServiceManager {
 
             // Bandcamp
             if Self::should_enable_service(&config.service.enabled, "bandcamp") {
-                match BandcampClient::new(config.bandcamp.clone()).await {
+                match BandcampClient::new(config.bandcamp.clone(), &config.network).await {
                     Ok(mut client) => {
                         client.set_audio_quality(&config.playback.audio_quality);
                         services.insert(ServiceType::Bandcamp, Box::new(client));
@@ -148,6 +160,15 @@ impl MultiServiceManager {
             return ServiceType::YouTube;
         }
 
+        // YouTube: playlist (album) and channel (artist) IDs
+        if track_id.starts_with('@')
+            || track_id.starts_with("OLAK5uy_")
+            || (track_id.starts_with("PL") && track_id.len() > 11)
+            || (track_id.starts_with("UC") && track_id.len() == 24)
+        {
+            return ServiceType::YouTube;
+        }
+
         // Tidal: numeric IDs
         ServiceType::Tidal
     }
@@ -165,6 +186,27 @@ impl MultiServiceManager {
         service.get_stream_url(&track.id).await
     }
 
+    /// Look up a track on a known service, e.g. one named by a pasted link,
+    /// rather than guessing it from the id
+    pub async fn get_track_from(&mut self, service: ServiceType, track_id: &str) -> Result<Track> {
+        self.get_service_mut(service)?.get_track(track_id).await
+    }
+
+    /// Get an album's tracks from the service the album came from
+    pub async fn get_album_tracks_for(&mut self, album: &Album) -> Result<Vec<Track>> {
+        self.get_service_mut(album.service)?.get_album_tracks(&album.id).await
+    }
+
+    /// Get an artist's top tracks from the service the artist came from
+    pub async fn get_artist_top_tracks_for(&mut self, artist: &Artist) -> Result<Vec<Track>> {
+        self.get_service_mut(artist.service)?.get_artist_top_tracks(&artist.id).await
+    }
+
+    /// Get an artist's albums from the service the artist came from
+    pub async fn get_artist_albums_for(&mut self, artist: &Artist) -> Result<Vec<Album>> {
+        self.get_service_mut(artist.service)?.get_artist_albums(&artist.id).await
+    }
+
     /// Get stream URL by ID, detecting service from ID format
     pub async fn get_stream_url_by_id(&mut self, track_id: &str) -> Result<String> {
         let service_type = Self::detect_service_from_id(track_id);
@@ -179,6 +221,10 @@ impl MusicService for MultiServiceManager {
         self.primary
     }
 
+    fn clone_box(&self) -> Box<dyn MusicService> {
+        Box::new(self.clone())
+    }
+
     fn is_authenticated(&self) -> bool {
         // Return true if primary service is authenticated
         self.services
@@ -193,6 +239,16 @@ impl MusicService for MultiServiceManager {
         }
     }
 
+    fn rate_limit_backoff(&self) -> Option<std::time::Duration> {
+        self.services.values().filter_map(|s| s.rate_limit_backoff()).max()
+    }
+
+    fn set_background(&mut self, background: bool) {
+        for service in self.services.values_mut() {
+            service.set_background(background);
+        }
+    }
+
     async fn get_stream_url(&mut self, track_id: &str) -> Result<String> {
         // Route based on track ID format detection
         self.get_stream_url_by_id(track_id).await
@@ -303,6 +359,30 @@ impl MusicService for MultiServiceManager {
         service.remove_favorite_track(track_id).await
     }
 
+    async fn add_favorite_album(&mut self, album_id: &str) -> Result<()> {
+        let service_type = Self::detect_service_from_id(album_id);
+        let service = self.get_service_mut(service_type)?;
+        service.add_favorite_album(album_id).await
+    }
+
+    async fn remove_favorite_album(&mut self, album_id: &str) -> Result<()> {
+        let service_type = Self::detect_service_from_id(album_id);
+        let service = self.get_service_mut(service_type)?;
+        service.remove_favorite_album(album_id).await
+    }
+
+    async fn add_favorite_artist(&mut self, artist_id: &str) -> Result<()> {
+        let service_type = Self::detect_service_from_id(artist_id);
+        let service = self.get_service_mut(service_type)?;
+        service.add_favorite_artist(artist_id).await
+    }
+
+    async fn remove_favorite_artist(&mut self, artist_id: &str) -> Result<()> {
+        let service_type = Self::detect_service_from_id(artist_id);
+        let service = self.get_service_mut(service_type)?;
+        service.remove_favorite_artist(artist_id).await
+    }
+
     // === Search ===
 
     async fn search(&mut self, query: &str, limit: usize) -> Result<SearchResults> {
@@ -338,6 +418,12 @@ impl MusicService for MultiServiceManager {
 
     // === Album/Artist Details ===
 
+    async fn get_track(&mut self, track_id: &str) -> Result<Track> {
+        let service_type = Self::detect_service_from_id(track_id);
+        let service = self.get_service_mut(service_type)?;
+        service.get_track(track_id).await
+    }
+
     async fn get_album_tracks(&mut self, album_id: &str) -> Result<Vec<Track>> {
         let service_type = Self::detect_service_from_id(album_id);
         let service = self.get_service_mut(service_type)?;
@@ -388,6 +474,27 @@ impl MusicService for MultiServiceManager {
         Err(anyhow!("Could not get playlist radio"))
     }
 
+    async fn get_trending(&mut self, limit: usize) -> Result<Vec<Track>> {
+        // Primary service first, then any other service with a trending feed
+        if let Some(service) = self.services.get_mut(&self.primary) {
+            if let Ok(tracks) = service.get_trending(limit).await {
+                if !tracks.is_empty() {
+                    return Ok(tracks);
+                }
+            }
+        }
+
+        for service in self.services.values_mut() {
+            if let Ok(tracks) = service.get_trending(limit).await {
+                if !tracks.is_empty() {
+                    return Ok(tracks);
+                }
+            }
+        }
+
+        Ok(vec![])
+    }
+
     // === Playlist Management ===
 
     async fn create_playlist(&mut self, name: &str, description: Option<&str>) -> Result<Playlist> {
@@ -558,6 +665,20 @@ mod tests {
             ServiceType::YouTube
         );
 
+        // YouTube playlist and channel IDs
+        assert_eq!(
+            MultiServiceManager::detect_service_from_id("OLAK5uy_kx7Vd1o9FiGMLvJ5Uq0OYp1tTyv4eMkE0"),
+            ServiceType::YouTube
+        );
+        assert_eq!(
+            MultiServiceManager::detect_service_from_id("UCuAXFkgsw1L7xaCfnd5JJOw"),
+            ServiceType::YouTube
+        );
+        assert_eq!(
+            MultiServiceManager::detect_service_from_id("@artist"),
+            ServiceType::YouTube
+        );
+
         // Tidal numeric IDs
         assert_eq!(
             MultiServiceManager::detect_service_from_id("123456789"),
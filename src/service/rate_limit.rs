@@ -0,0 +1,244 @@
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+/// Token bucket that spaces out a service's HTTP requests.
+///
+/// Holds up to one second's worth of requests as burst, then lets one
+/// request through every `1 / requests_per_second`. A rate of 0 disables it.
+/// With [`RateLimiter::acquire_many`] the tokens can be bytes instead, to cap
+/// bandwidth.
+pub struct RateLimiter {
+    requests_per_second: f64,
+    capacity: f64,
+    bucket: Mutex<Bucket>,
+    /// When the latest 429 backoff through [`SendRateLimited`] ends
+    backoff_until: std::sync::Mutex<Option<Instant>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: f64) -> Self {
+        let capacity = requests_per_second.ceil().max(1.0);
+        Self {
+            requests_per_second,
+            capacity,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                refilled_at: Instant::now(),
+            }),
+            backoff_until: std::sync::Mutex::new(None),
+        }
+    }
+
+    /// Time left on a 429 backoff in progress, for showing in the UI
+    pub fn backoff(&self) -> Option<Duration> {
+        let until = (*self.backoff_until.lock().unwrap())?;
+        until.checked_duration_since(Instant::now()).filter(|left| !left.is_zero())
+    }
+
+    fn note_backoff(&self, wait: Duration) {
+        let until = Instant::now() + wait;
+        let mut backoff_until = self.backoff_until.lock().unwrap();
+        if !backoff_until.is_some_and(|current| current >= until) {
+            *backoff_until = Some(until);
+        }
+    }
+
+    /// Wait until a request may be sent
+    pub async fn acquire(&self) {
+        self.acquire_many(1.0).await;
+    }
+
+    /// Wait until `amount` tokens are free and take them. An amount over the
+    /// burst capacity waits out the shortfall, so large chunks still pass.
+    pub async fn acquire_many(&self, amount: f64) {
+        if self.requests_per_second <= 0.0 {
+            return;
+        }
+
+        // Waiters queue on the lock, so sleeping while holding it hands out
+        // tokens in order
+        let mut bucket = self.bucket.lock().await;
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.refilled_at).as_secs_f64() * self.requests_per_second;
+        bucket.tokens = (bucket.tokens + refill).min(self.capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens < amount {
+            let wait = (amount - bucket.tokens) / self.requests_per_second;
+            tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+            bucket.tokens = amount;
+            bucket.refilled_at = Instant::now();
+        }
+        bucket.tokens -= amount;
+    }
+}
+
+/// A client's hold on its service's [`RateLimiter`]. Clones share the bucket
+/// and backoff, but each decides what a 429 does: a request someone is
+/// waiting on fails at once, while background jobs wait it out and retry.
+#[derive(Clone)]
+pub struct ServiceLimiter {
+    limiter: Arc<RateLimiter>,
+    background: bool,
+}
+
+impl ServiceLimiter {
+    pub fn new(requests_per_second: f64) -> Self {
+        Self {
+            limiter: Arc::new(RateLimiter::new(requests_per_second)),
+            background: false,
+        }
+    }
+
+    /// Wait out 429 backoffs instead of failing, for jobs nobody's watching
+    pub fn set_background(&mut self, background: bool) {
+        self.background = background;
+    }
+}
+
+impl Deref for ServiceLimiter {
+    type Target = RateLimiter;
+
+    fn deref(&self) -> &RateLimiter {
+        &self.limiter
+    }
+}
+
+/// Retries after a 429 before handing the response back to the caller
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Longest a single 429 backoff may wait, whatever `Retry-After` says
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(30);
+
+/// Most one request spends in 429 backoffs altogether
+const MAX_RATE_LIMIT_TOTAL_WAIT: Duration = Duration::from_secs(45);
+
+/// `send()` that first waits on a [`ServiceLimiter`]
+pub(crate) trait SendRateLimited {
+    async fn send_limited(self, limiter: &ServiceLimiter) -> anyhow::Result<reqwest::Response>;
+}
+
+impl SendRateLimited for reqwest::RequestBuilder {
+    /// On 429 Too Many Requests, a foreground limiter fails with how long to
+    /// wait. A background one waits for `Retry-After` (or 1s, 2s, 4s when
+    /// absent), capped at [`MAX_RATE_LIMIT_WAIT`], and retries; the last 429
+    /// is returned as-is once retries run out or another wait would pass
+    /// [`MAX_RATE_LIMIT_TOTAL_WAIT`]. Waits show in [`RateLimiter::backoff`].
+    async fn send_limited(self, limiter: &ServiceLimiter) -> anyhow::Result<reqwest::Response> {
+        let mut request = self;
+        let mut waited = Duration::ZERO;
+        for attempt in 0..MAX_RATE_LIMIT_RETRIES {
+            // Streaming bodies can't be replayed; those get a single attempt
+            let retry = request.try_clone();
+            limiter.acquire().await;
+            let response = request.send().await?;
+            if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+
+            let wait = retry_after(&response)
+                .unwrap_or(Duration::from_secs(1 << attempt))
+                .min(MAX_RATE_LIMIT_WAIT);
+            if !limiter.background {
+                limiter.note_backoff(wait);
+                anyhow::bail!("rate limited, retry in {}s", wait.as_secs().max(1));
+            }
+            let Some(next) = retry else {
+                return Ok(response);
+            };
+            if waited + wait > MAX_RATE_LIMIT_TOTAL_WAIT {
+                return Ok(response);
+            }
+            tracing::warn!(
+                "rate limited by {}, retrying in {}s",
+                response.url().host_str().unwrap_or("service"),
+                wait.as_secs()
+            );
+            limiter.note_backoff(wait);
+            tokio::time::sleep(wait).await;
+            waited += wait;
+            request = next;
+        }
+        limiter.acquire().await;
+        Ok(request.send().await?)
+    }
+}
+
+/// `Retry-After` given in seconds
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_bursts_then_spaces_requests() {
+        let limiter = RateLimiter::new(20.0);
+        let start = Instant::now();
+        // A full bucket goes through immediately
+        for _ in 0..20 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(40));
+
+        // The next two wait ~50ms each
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_many_caps_bytes() {
+        // 100 KB/s: the first second's worth is burst, the next 10 KB waits ~100ms
+        let limiter = RateLimiter::new(100_000.0);
+        let start = Instant::now();
+        limiter.acquire_many(100_000.0).await;
+        assert!(start.elapsed() < Duration::from_millis(40));
+
+        limiter.acquire_many(10_000.0).await;
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[test]
+    fn test_backoff_reports_the_latest_end() {
+        let limiter = RateLimiter::new(1.0);
+        assert_eq!(limiter.backoff(), None);
+
+        limiter.note_backoff(Duration::from_secs(10));
+        // A shorter backoff elsewhere doesn't cut the longer one short
+        limiter.note_backoff(Duration::from_secs(2));
+        let left = limiter.backoff().unwrap();
+        assert!(left > Duration::from_secs(9) && left <= Duration::from_secs(10));
+
+        *limiter.backoff_until.lock().unwrap() = Some(Instant::now());
+        assert_eq!(limiter.backoff(), None);
+    }
+
+    #[tokio::test]
+    async fn test_zero_rate_is_unlimited() {
+        let limiter = RateLimiter::new(0.0);
+        let start = Instant::now();
+        for _ in 0..1000 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+}
@@ -8,10 +8,20 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
 
-use super::{Album, Artist, CoverArt, MusicService, Playlist, SearchResults, ServiceType, Track};
+use super::{Album, AlbumType, Artist, CoverArt, MusicService, Playlist, SearchResults, ServiceType, Track};
+use super::rate_limit::{SendRateLimited, ServiceLimiter};
+use crate::config::NetworkConfig;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Albums requested per page when listing an artist's discography (Tidal's
+/// maximum page size)
+const ARTIST_ALBUMS_PAGE_SIZE: usize = 100;
+
+/// Most top tracks Tidal returns for an artist in one request
+const ARTIST_TOP_TRACKS_MAX: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TidalConfig {
     pub access_token: String,
     pub refresh_token: String,
@@ -60,6 +70,10 @@ struct TrackData {
     artists: Option<Vec<ArtistResponse>>,
     album: Option<AlbumResponse>,
     duration: Option<u32>,
+    #[serde(rename = "streamReady")]
+    stream_ready: Option<bool>,
+    #[serde(rename = "allowStreaming")]
+    allow_streaming: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -69,18 +83,26 @@ struct ArtistResponse {
 
 #[derive(Debug, Deserialize)]
 struct AlbumResponse {
+    id: Option<u64>,
     title: String,
     cover: Option<String>,
 }
 
+#[derive(Clone)]
 pub struct TidalClient {
-    pub config: Option<TidalConfig>,
+    /// Shared with every clone so a token refreshed by one is used by all
+    credentials: Arc<RwLock<Option<TidalConfig>>>,
     http_client: HttpClient,
+    /// Spaces out API calls so bulk operations don't trip Tidal's limits
+    rate_limiter: ServiceLimiter,
     audio_quality: String,
+    /// Most albums fetched per artist (0 = all)
+    artist_albums_limit: usize,
+    artist_top_tracks_limit: usize,
 }
 
 impl TidalClient {
-    pub async fn new() -> Result<Self> {
+    pub async fn new(network: &NetworkConfig) -> Result<Self> {
         let upmpdcli_path = Self::upmpdcli_path()?;
         let tidal_tui_path = Self::config_path()?;
 
@@ -100,18 +122,31 @@ impl TidalClient {
             None
         };
 
-        let http_client = HttpClient::new();
+        let http_client = super::http_client(network, ServiceType::Tidal, None)?;
 
         Ok(Self {
-            config,
+            credentials: Arc::new(RwLock::new(config)),
             http_client,
+            rate_limiter: ServiceLimiter::new(network.requests_per_second),
             audio_quality: "HIGH".to_string(),
+            artist_albums_limit: 0,
+            artist_top_tracks_limit: 20,
         })
     }
 
+    /// Cap on albums fetched per artist (`tidal.artist_albums_limit`, 0 = all)
+    pub fn set_artist_albums_limit(&mut self, limit: usize) {
+        self.artist_albums_limit = limit;
+    }
+
+    /// Top tracks fetched per artist (`tidal.artist_top_tracks_limit`),
+    /// kept within what the API returns in one request
+    pub fn set_artist_top_tracks_limit(&mut self, limit: usize) {
+        self.artist_top_tracks_limit = limit.clamp(1, ARTIST_TOP_TRACKS_MAX);
+    }
+
     fn config_path() -> Result<PathBuf> {
-        let mut path = config_dir().ok_or_else(|| anyhow!("Could not find config directory"))?;
-        path.push("drift");
+        let mut path = crate::config::drift_config_dir().ok_or_else(|| anyhow!("Could not find config directory"))?;
         fs::create_dir_all(&path)?;
         path.push("credentials.json");
         Ok(path)
@@ -131,8 +166,13 @@ impl TidalClient {
         Ok(config)
     }
 
+    /// Snapshot of the current credentials, None in demo mode
+    fn credentials(&self) -> Option<TidalConfig> {
+        self.credentials.read().unwrap().clone()
+    }
+
     pub async fn save_config(&self) -> Result<()> {
-        if let Some(ref config) = self.config {
+        if let Some(ref config) = self.credentials() {
             let path = Self::config_path()?;
             let contents = serde_json::to_string_pretty(config)?;
             fs::write(&path, contents)?;
@@ -173,34 +213,40 @@ impl TidalClient {
                 title: "Bohemian Rhapsody".to_string(),
                 artist: "Queen".to_string(),
                 album: "A Night at the Opera".to_string(),
+                album_id: None,
                 duration_seconds: 354,
                 cover_art: CoverArt::None,
                 service: ServiceType::Tidal,
+                available: true,
             },
             Track {
                 id: "2".to_string(),
                 title: "Stairway to Heaven".to_string(),
                 artist: "Led Zeppelin".to_string(),
                 album: "Led Zeppelin IV".to_string(),
+                album_id: None,
                 duration_seconds: 482,
                 cover_art: CoverArt::None,
                 service: ServiceType::Tidal,
+                available: true,
             },
             Track {
                 id: "3".to_string(),
                 title: "Hotel California".to_string(),
                 artist: "Eagles".to_string(),
                 album: "Hotel California".to_string(),
+                album_id: None,
                 duration_seconds: 391,
                 cover_art: CoverArt::None,
                 service: ServiceType::Tidal,
+                available: true,
             },
         ]
     }
 
     #[allow(dead_code)]
     fn is_token_expired(&self) -> bool {
-        if let Some(ref config) = self.config {
+        if let Some(ref config) = self.credentials() {
             if let Some(expires_at) = config.expires_at {
                 return expires_at - Duration::minutes(5) < Utc::now();
             }
@@ -220,8 +266,8 @@ impl TidalClient {
     }
 
     async fn refresh_token(&mut self) -> Result<()> {
-        if let Some(ref mut config) = self.config {
-            let refresh_token = config.refresh_token.clone();
+        if let Some(config) = self.credentials() {
+            let refresh_token = config.refresh_token;
             let url = "https://auth.tidal.com/v1/oauth2/token";
 
             let params = [
@@ -230,21 +276,23 @@ impl TidalClient {
                 ("client_id", "dN2N95wCyEBTllu4"),
             ];
 
-            let response = self.http_client.post(url).form(&params).send().await?;
+            let response = self.http_client.post(url).form(&params).send_limited(&self.rate_limiter).await?;
 
             if response.status().is_success() {
                 let json: Value = response.json().await?;
 
-                if let Some(access_token) = json.get("access_token").and_then(|v| v.as_str()) {
-                    config.access_token = access_token.to_string();
-                }
+                if let Some(config) = self.credentials.write().unwrap().as_mut() {
+                    if let Some(access_token) = json.get("access_token").and_then(|v| v.as_str()) {
+                        config.access_token = access_token.to_string();
+                    }
 
-                if let Some(refresh_token) = json.get("refresh_token").and_then(|v| v.as_str()) {
-                    config.refresh_token = refresh_token.to_string();
-                }
+                    if let Some(refresh_token) = json.get("refresh_token").and_then(|v| v.as_str()) {
+                        config.refresh_token = refresh_token.to_string();
+                    }
 
-                if let Some(expires_in) = json.get("expires_in").and_then(|v| v.as_i64()) {
-                    config.expires_at = Some(Utc::now() + Duration::seconds(expires_in));
+                    if let Some(expires_in) = json.get("expires_in").and_then(|v| v.as_i64()) {
+                        config.expires_at = Some(Utc::now() + Duration::seconds(expires_in));
+                    }
                 }
 
                 self.save_config().await?;
@@ -297,6 +345,12 @@ impl TidalClient {
             .and_then(|c| c.as_str())
             .map(|s| s.to_string());
 
+        let album_id = item
+            .get("album")
+            .and_then(|a| a.get("id"))
+            .and_then(|id| id.as_u64())
+            .map(|id| id.to_string());
+
         let duration = item.get("duration")?.as_u64()? as u32;
 
         Some(Track {
@@ -304,12 +358,21 @@ impl TidalClient {
             title,
             artist,
             album,
+            album_id,
             duration_seconds: duration,
             cover_art: CoverArt::from_tidal_option(album_cover_id),
             service: ServiceType::Tidal,
+            available: Self::is_streamable(item),
         })
     }
 
+    /// Tidal marks region-locked or withdrawn tracks with `streamReady` /
+    /// `allowStreaming` = false; missing flags are treated as streamable
+    fn is_streamable(item: &Value) -> bool {
+        let flag = |key: &str| item.get(key).and_then(|v| v.as_bool()).unwrap_or(true);
+        flag("streamReady") && flag("allowStreaming")
+    }
+
     // Helper to parse track from nested item structure
     fn parse_track_from_nested(item: &Value) -> Option<Track> {
         let track_data = item.get("item")?;
@@ -334,6 +397,58 @@ impl TidalClient {
         )
     }
 
+    /// Add (`POST favorites/{kind}`) or remove (`DELETE favorites/{kind}/{id}`)
+    /// a favorite, where `kind` is "albums" or "artists"
+    async fn update_favorite(&mut self, kind: &str, id: &str, add: bool) -> Result<()> {
+        for attempt in 0..2 {
+            if let Some(ref config) = self.credentials() {
+                let url = format!(
+                    "https://api.tidal.com/v1/users/{}/favorites/{}",
+                    config.user_id, kind
+                );
+
+                let request = if add {
+                    // albumIds / artistIds
+                    let field = format!("{}Ids", kind.trim_end_matches('s'));
+                    self.http_client.post(&url).form(&[(field, id)])
+                } else {
+                    self.http_client.delete(format!("{}/{}", url, id))
+                };
+                let response = request
+                    .header(
+                        header::AUTHORIZATION,
+                        format!("Bearer {}", config.access_token),
+                    )
+                    .query(&[("countryCode", "US")])
+                    .send_limited(&self.rate_limiter)
+                    .await;
+
+                let action = if add { "add" } else { "remove" };
+                match response {
+                    Ok(resp) if resp.status().is_success() => {
+                        return Ok(());
+                    }
+                    Ok(resp) if resp.status().as_u16() == 401 && attempt == 0 => {
+                        if self.refresh_token().await.is_ok() {
+                            continue;
+                        }
+                    }
+                    Ok(resp) => {
+                        let status = resp.status();
+                        let body = resp.text().await.unwrap_or_default();
+                        return Err(anyhow!("Failed to {} favorite {}: {} - {}", action, kind, status, body));
+                    }
+                    Err(e) => {
+                        return Err(anyhow!("Network error trying to {} favorite {}: {}", action, kind, e));
+                    }
+                }
+            }
+            break;
+        }
+
+        Err(anyhow!("No configuration available"))
+    }
+
     #[allow(dead_code)]
     pub fn format_playlist_display(playlist: &Playlist) -> String {
         format!("{} ({} tracks)", playlist.title, playlist.num_tracks)
@@ -365,6 +480,106 @@ impl TidalClient {
         self.get_artist_top_tracks(&artist_id.to_string()).await
     }
 
+    /// One page of an artist's albums, with the total the artist has
+    async fn get_artist_albums_page(&mut self, artist_id: &str, offset: usize, limit: usize) -> Result<(Vec<Album>, usize)> {
+        for attempt in 0..2 {
+            if let Some(ref config) = self.credentials() {
+                let url = format!("https://api.tidal.com/v1/artists/{}/albums", artist_id);
+
+                let response = self
+                    .http_client
+                    .get(&url)
+                    .header(
+                        header::AUTHORIZATION,
+                        format!("Bearer {}", config.access_token),
+                    )
+                    .query(&[
+                        ("countryCode", "US"),
+                        ("offset", &offset.to_string()),
+                        ("limit", &limit.to_string()),
+                    ])
+                    .send_limited(&self.rate_limiter)
+                    .await;
+
+                match response {
+                    Ok(resp) if resp.status().is_success() => {
+                        let json: Value = resp.json().await?;
+
+                        let albums: Vec<Album> =
+                            if let Some(items) = json.get("items").and_then(|i| i.as_array()) {
+                                items
+                                    .iter()
+                                    .filter_map(|item| {
+                                        let id = item.get("id")?.as_u64()?.to_string();
+                                        let title = item.get("title")?.as_str()?.to_string();
+
+                                        let artist = item
+                                            .get("artist")
+                                            .and_then(|a| a.get("name"))
+                                            .and_then(|n| n.as_str())
+                                            .or_else(|| {
+                                                item.get("artists")
+                                                    .and_then(|a| a.as_array())
+                                                    .and_then(|arr| arr.first())
+                                                    .and_then(|a| a.get("name"))
+                                                    .and_then(|n| n.as_str())
+                                            })
+                                            .unwrap_or("Unknown Artist")
+                                            .to_string();
+
+                                        let num_tracks = item
+                                            .get("numberOfTracks")
+                                            .and_then(|n| n.as_u64())
+                                            .unwrap_or(0)
+                                            as u32;
+
+                                        let cover_id = item
+                                            .get("cover")
+                                            .and_then(|c| c.as_str())
+                                            .map(|s| s.to_string());
+
+                                        Some(Album {
+                                            id,
+                                            title,
+                                            artist,
+                                            num_tracks,
+                                            cover_art: CoverArt::from_tidal_option(cover_id),
+                                            service: ServiceType::Tidal,
+                                            album_type: AlbumType::from_tidal(
+                                                item.get("type").and_then(|t| t.as_str()),
+                                            ),
+                                        })
+                                    })
+                                    .collect()
+                            } else {
+                                vec![]
+                            };
+
+                        let total = json
+                            .get("totalNumberOfItems")
+                            .and_then(|t| t.as_u64())
+                            .map_or(offset + albums.len(), |t| t as usize);
+                        return Ok((albums, total));
+                    }
+                    Ok(resp) if resp.status().as_u16() == 401 && attempt == 0 => {
+                        if self.refresh_token().await.is_ok() {
+                            continue;
+                        }
+                    }
+                    Ok(resp) => {
+                        eprintln!("Artist albums request failed: {}", resp.status());
+                    }
+                    Err(e) => {
+                        eprintln!("Network error fetching artist albums: {}", e);
+                    }
+                }
+            }
+            break;
+        }
+
+        Ok((vec![], 0))
+    }
+
     #[allow(dead_code)]
     pub async fn get_artist_albums_by_id(&mut self, artist_id: u64) -> Result<Vec<Album>> {
         self.get_artist_albums(&artist_id.to_string()).await
@@ -383,8 +598,12 @@ impl MusicService for TidalClient {
         ServiceType::Tidal
     }
 
+    fn clone_box(&self) -> Box<dyn MusicService> {
+        Box::new(self.clone())
+    }
+
     fn is_authenticated(&self) -> bool {
-        self.config.is_some()
+        self.credentials.read().unwrap().is_some()
     }
 
     fn set_audio_quality(&mut self, quality: &str) {
@@ -398,10 +617,18 @@ impl MusicService for TidalClient {
         .to_string();
     }
 
+    fn rate_limit_backoff(&self) -> Option<std::time::Duration> {
+        self.rate_limiter.backoff()
+    }
+
+    fn set_background(&mut self, background: bool) {
+        self.rate_limiter.set_background(background);
+    }
+
     async fn get_stream_url(&mut self, track_id: &str) -> Result<String> {
         if !track_id.starts_with("demo") {
             for attempt in 0..2 {
-                let (token, _user_id) = if let Some(ref config) = self.config {
+                let (token, _user_id) = if let Some(ref config) = self.credentials() {
                     (config.access_token.clone(), config.user_id)
                 } else {
                     break;
@@ -422,7 +649,7 @@ impl MusicService for TidalClient {
                         ("audioquality", self.audio_quality.as_str()),
                         ("playbackmode", "STREAM"),
                     ])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -487,7 +714,7 @@ impl MusicService for TidalClient {
                                     ("soundQuality", self.audio_quality.as_str()),
                                     ("assetpresentation", "FULL"),
                                 ])
-                                .send()
+                                .send_limited(&self.rate_limiter)
                                 .await;
 
                             if let Ok(stream_resp) = stream_response {
@@ -514,7 +741,7 @@ impl MusicService for TidalClient {
 
     async fn get_playlists(&mut self) -> Result<Vec<Playlist>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!(
                     "https://api.tidal.com/v1/users/{}/playlists",
                     config.user_id
@@ -528,7 +755,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US"), ("limit", "50")])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -572,7 +799,7 @@ impl MusicService for TidalClient {
     async fn get_playlist_tracks(&mut self, playlist_id: &str) -> Result<Vec<Track>> {
         if !playlist_id.starts_with("demo-") {
             for attempt in 0..2 {
-                if let Some(ref config) = self.config {
+                if let Some(ref config) = self.credentials() {
                     let url = format!(
                         "https://api.tidal.com/v1/playlists/{}/items",
                         playlist_id
@@ -586,7 +813,7 @@ impl MusicService for TidalClient {
                             format!("Bearer {}", config.access_token),
                         )
                         .query(&[("countryCode", "US"), ("limit", "100")])
-                        .send()
+                        .send_limited(&self.rate_limiter)
                         .await;
 
                     match response {
@@ -606,11 +833,11 @@ impl MusicService for TidalClient {
                                                 .map(|a| a.name)
                                                 .unwrap_or_else(|| "Unknown Artist".to_string());
 
-                                            let (album_title, album_cover_id) = track
+                                            let (album_id, album_title, album_cover_id) = track
                                                 .album
-                                                .map(|a| (a.title, a.cover))
+                                                .map(|a| (a.id.map(|id| id.to_string()), a.title, a.cover))
                                                 .unwrap_or_else(|| {
-                                                    ("Unknown Album".to_string(), None)
+                                                    (None, "Unknown Album".to_string(), None)
                                                 });
 
                                             Track {
@@ -618,11 +845,14 @@ impl MusicService for TidalClient {
                                                 title: track.title,
                                                 artist: artist_name,
                                                 album: album_title,
+                                                album_id,
                                                 duration_seconds: track.duration.unwrap_or(0),
                                                 cover_art: CoverArt::from_tidal_option(
                                                     album_cover_id,
                                                 ),
                                                 service: ServiceType::Tidal,
+                                                available: track.stream_ready.unwrap_or(true)
+                                                    && track.allow_streaming.unwrap_or(true),
                                             }
                                         })
                                     })
@@ -653,7 +883,7 @@ impl MusicService for TidalClient {
 
     async fn get_favorite_tracks(&mut self) -> Result<Vec<Track>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!(
                     "https://api.tidal.com/v1/users/{}/favorites/tracks",
                     config.user_id
@@ -667,7 +897,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US"), ("limit", "100")])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -707,7 +937,7 @@ impl MusicService for TidalClient {
 
     async fn get_favorite_albums(&mut self) -> Result<Vec<Album>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!(
                     "https://api.tidal.com/v1/users/{}/favorites/albums",
                     config.user_id
@@ -721,7 +951,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US"), ("limit", "100")])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -762,6 +992,9 @@ impl MusicService for TidalClient {
                                             num_tracks,
                                             cover_art: CoverArt::from_tidal_option(cover_id),
                                             service: ServiceType::Tidal,
+                                            album_type: AlbumType::from_tidal(
+                                                album_data.get("type").and_then(|t| t.as_str()),
+                                            ),
                                         })
                                     })
                                     .collect()
@@ -792,7 +1025,7 @@ impl MusicService for TidalClient {
 
     async fn get_favorite_artists(&mut self) -> Result<Vec<Artist>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!(
                     "https://api.tidal.com/v1/users/{}/favorites/artists",
                     config.user_id
@@ -806,7 +1039,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US"), ("limit", "100")])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -858,7 +1091,7 @@ impl MusicService for TidalClient {
 
     async fn add_favorite_track(&mut self, track_id: &str) -> Result<()> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!(
                     "https://api.tidal.com/v1/users/{}/favorites/tracks",
                     config.user_id
@@ -873,7 +1106,7 @@ impl MusicService for TidalClient {
                     )
                     .query(&[("countryCode", "US")])
                     .form(&[("trackIds", track_id)])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -905,9 +1138,25 @@ impl MusicService for TidalClient {
         Err(anyhow!("No configuration available"))
     }
 
+    async fn add_favorite_album(&mut self, album_id: &str) -> Result<()> {
+        self.update_favorite("albums", album_id, true).await
+    }
+
+    async fn remove_favorite_album(&mut self, album_id: &str) -> Result<()> {
+        self.update_favorite("albums", album_id, false).await
+    }
+
+    async fn add_favorite_artist(&mut self, artist_id: &str) -> Result<()> {
+        self.update_favorite("artists", artist_id, true).await
+    }
+
+    async fn remove_favorite_artist(&mut self, artist_id: &str) -> Result<()> {
+        self.update_favorite("artists", artist_id, false).await
+    }
+
     async fn remove_favorite_track(&mut self, track_id: &str) -> Result<()> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!(
                     "https://api.tidal.com/v1/users/{}/favorites/tracks/{}",
                     config.user_id, track_id
@@ -921,7 +1170,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US")])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -953,9 +1202,49 @@ impl MusicService for TidalClient {
         Err(anyhow!("No configuration available"))
     }
 
+    async fn get_track(&mut self, track_id: &str) -> Result<Track> {
+        if track_id.starts_with("demo") || self.credentials().is_none() {
+            return self
+                .get_demo_tracks()
+                .into_iter()
+                .find(|t| t.id == track_id)
+                .ok_or_else(|| anyhow!("Track {} not found", track_id));
+        }
+
+        for attempt in 0..2 {
+            if let Some(ref config) = self.credentials() {
+                let url = format!("https://api.tidal.com/v1/tracks/{}", track_id);
+
+                let response = self
+                    .http_client
+                    .get(&url)
+                    .header(
+                        header::AUTHORIZATION,
+                        format!("Bearer {}", config.access_token),
+                    )
+                    .query(&[("countryCode", "US")])
+                    .send_limited(&self.rate_limiter)
+                    .await?;
+
+                if response.status().as_u16() == 401 && attempt == 0 && self.refresh_token().await.is_ok() {
+                    continue;
+                }
+                if !response.status().is_success() {
+                    return Err(anyhow!("Track request failed: {}", response.status()));
+                }
+                let json: Value = response.json().await?;
+                return Self::parse_track_from_json(&json)
+                    .ok_or_else(|| anyhow!("Invalid track response for {}", track_id));
+            }
+            break;
+        }
+
+        Err(anyhow!("No configuration available"))
+    }
+
     async fn search(&mut self, query: &str, limit: usize) -> Result<SearchResults> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = "https://api.tidal.com/v1/search";
 
                 let response = self
@@ -971,7 +1260,7 @@ impl MusicService for TidalClient {
                         ("countryCode", "US"),
                         ("types", "TRACKS,ALBUMS,ARTISTS"),
                     ])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1026,6 +1315,12 @@ impl MusicService for TidalClient {
                                         .and_then(|c| c.as_str())
                                         .map(|s| s.to_string());
 
+                                    let album_id = item
+                                        .get("album")
+                                        .and_then(|a| a.get("id"))
+                                        .and_then(|id| id.as_u64())
+                                        .map(|id| id.to_string());
+
                                     let duration = item.get("duration")?.as_u64()? as u32;
 
                                     Some(Track {
@@ -1033,9 +1328,11 @@ impl MusicService for TidalClient {
                                         title,
                                         artist,
                                         album,
+                                        album_id,
                                         duration_seconds: duration,
                                         cover_art: CoverArt::from_tidal_option(album_cover_id),
                                         service: ServiceType::Tidal,
+                                        available: Self::is_streamable(item),
                                     })
                                 })
                                 .collect()
@@ -1077,6 +1374,9 @@ impl MusicService for TidalClient {
                                         num_tracks,
                                         cover_art: CoverArt::from_tidal_option(cover_id),
                                         service: ServiceType::Tidal,
+                                        album_type: AlbumType::from_tidal(
+                                            item.get("type").and_then(|t| t.as_str()),
+                                        ),
                                     })
                                 })
                                 .collect()
@@ -1135,7 +1435,7 @@ impl MusicService for TidalClient {
 
     async fn get_album_tracks(&mut self, album_id: &str) -> Result<Vec<Track>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!("https://api.tidal.com/v1/albums/{}/items", album_id);
 
                 let response = self
@@ -1146,7 +1446,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US"), ("limit", "100")])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1186,7 +1486,7 @@ impl MusicService for TidalClient {
 
     async fn get_artist_top_tracks(&mut self, artist_id: &str) -> Result<Vec<Track>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!(
                     "https://api.tidal.com/v1/artists/{}/toptracks",
                     artist_id
@@ -1199,8 +1499,8 @@ impl MusicService for TidalClient {
                         header::AUTHORIZATION,
                         format!("Bearer {}", config.access_token),
                     )
-                    .query(&[("countryCode", "US"), ("limit", "20")])
-                    .send()
+                    .query(&[("countryCode", "US"), ("limit", &self.artist_top_tracks_limit.to_string())])
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1239,9 +1539,27 @@ impl MusicService for TidalClient {
     }
 
     async fn get_artist_albums(&mut self, artist_id: &str) -> Result<Vec<Album>> {
+        let limit = self.artist_albums_limit;
+        let mut albums = Vec::new();
+        loop {
+            let want = match limit {
+                0 => ARTIST_ALBUMS_PAGE_SIZE,
+                limit => (limit - albums.len()).min(ARTIST_ALBUMS_PAGE_SIZE),
+            };
+            let (page, total) = self.get_artist_albums_page(artist_id, albums.len(), want).await?;
+            let fetched = page.len();
+            albums.extend(page);
+            if fetched < want || albums.len() >= total || (limit != 0 && albums.len() >= limit) {
+                break;
+            }
+        }
+        Ok(albums)
+    }
+
+    async fn get_track_radio(&mut self, track_id: &str, limit: usize) -> Result<Vec<Track>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
-                let url = format!("https://api.tidal.com/v1/artists/{}/albums", artist_id);
+            if let Some(ref config) = self.credentials() {
+                let url = format!("https://api.tidal.com/v1/tracks/{}/radio", track_id);
 
                 let response = self
                     .http_client
@@ -1250,62 +1568,28 @@ impl MusicService for TidalClient {
                         header::AUTHORIZATION,
                         format!("Bearer {}", config.access_token),
                     )
-                    .query(&[("countryCode", "US"), ("limit", "50")])
-                    .send()
+                    .query(&[("countryCode", "US"), ("limit", &limit.to_string())])
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
                     Ok(resp) if resp.status().is_success() => {
                         let json: Value = resp.json().await?;
 
-                        let albums =
+                        let tracks =
                             if let Some(items) = json.get("items").and_then(|i| i.as_array()) {
                                 items
                                     .iter()
-                                    .filter_map(|item| {
-                                        let id = item.get("id")?.as_u64()?.to_string();
-                                        let title = item.get("title")?.as_str()?.to_string();
-
-                                        let artist = item
-                                            .get("artist")
-                                            .and_then(|a| a.get("name"))
-                                            .and_then(|n| n.as_str())
-                                            .or_else(|| {
-                                                item.get("artists")
-                                                    .and_then(|a| a.as_array())
-                                                    .and_then(|arr| arr.first())
-                                                    .and_then(|a| a.get("name"))
-                                                    .and_then(|n| n.as_str())
-                                            })
-                                            .unwrap_or("Unknown Artist")
-                                            .to_string();
-
-                                        let num_tracks = item
-                                            .get("numberOfTracks")
-                                            .and_then(|n| n.as_u64())
-                                            .unwrap_or(0)
-                                            as u32;
-
-                                        let cover_id = item
-                                            .get("cover")
-                                            .and_then(|c| c.as_str())
-                                            .map(|s| s.to_string());
-
-                                        Some(Album {
-                                            id,
-                                            title,
-                                            artist,
-                                            num_tracks,
-                                            cover_art: CoverArt::from_tidal_option(cover_id),
-                                            service: ServiceType::Tidal,
-                                        })
-                                    })
+                                    .filter_map(Self::parse_track_from_json)
                                     .collect()
                             } else {
                                 vec![]
                             };
 
-                        return Ok(albums);
+                        return Ok(tracks);
+                    }
+                    Ok(resp) if resp.status().as_u16() == 404 => {
+                        return Ok(vec![]);
                     }
                     Ok(resp) if resp.status().as_u16() == 401 && attempt == 0 => {
                         if self.refresh_token().await.is_ok() {
@@ -1313,10 +1597,10 @@ impl MusicService for TidalClient {
                         }
                     }
                     Ok(resp) => {
-                        eprintln!("Artist albums request failed: {}", resp.status());
+                        eprintln!("Track radio request failed: {}", resp.status());
                     }
                     Err(e) => {
-                        eprintln!("Network error fetching artist albums: {}", e);
+                        eprintln!("Network error fetching track radio: {}", e);
                     }
                 }
             }
@@ -1326,10 +1610,10 @@ impl MusicService for TidalClient {
         Ok(vec![])
     }
 
-    async fn get_track_radio(&mut self, track_id: &str, limit: usize) -> Result<Vec<Track>> {
+    async fn get_artist_radio(&mut self, artist_id: &str, limit: usize) -> Result<Vec<Track>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
-                let url = format!("https://api.tidal.com/v1/tracks/{}/radio", track_id);
+            if let Some(ref config) = self.credentials() {
+                let url = format!("https://api.tidal.com/v1/artists/{}/radio", artist_id);
 
                 let response = self
                     .http_client
@@ -1339,7 +1623,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US"), ("limit", &limit.to_string())])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1367,10 +1651,10 @@ impl MusicService for TidalClient {
                         }
                     }
                     Ok(resp) => {
-                        eprintln!("Track radio request failed: {}", resp.status());
+                        eprintln!("Artist radio request failed: {}", resp.status());
                     }
                     Err(e) => {
-                        eprintln!("Network error fetching track radio: {}", e);
+                        eprintln!("Network error fetching artist radio: {}", e);
                     }
                 }
             }
@@ -1380,10 +1664,13 @@ impl MusicService for TidalClient {
         Ok(vec![])
     }
 
-    async fn get_artist_radio(&mut self, artist_id: &str, limit: usize) -> Result<Vec<Track>> {
+    async fn get_playlist_radio(&mut self, playlist_id: &str, limit: usize) -> Result<Vec<Track>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
-                let url = format!("https://api.tidal.com/v1/artists/{}/radio", artist_id);
+            if let Some(ref config) = self.credentials() {
+                let url = format!(
+                    "https://api.tidal.com/v1/playlists/{}/radio",
+                    playlist_id
+                );
 
                 let response = self
                     .http_client
@@ -1393,7 +1680,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US"), ("limit", &limit.to_string())])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1421,10 +1708,10 @@ impl MusicService for TidalClient {
                         }
                     }
                     Ok(resp) => {
-                        eprintln!("Artist radio request failed: {}", resp.status());
+                        eprintln!("Playlist radio request failed: {}", resp.status());
                     }
                     Err(e) => {
-                        eprintln!("Network error fetching artist radio: {}", e);
+                        eprintln!("Network error fetching playlist radio: {}", e);
                     }
                 }
             }
@@ -1434,23 +1721,18 @@ impl MusicService for TidalClient {
         Ok(vec![])
     }
 
-    async fn get_playlist_radio(&mut self, playlist_id: &str, limit: usize) -> Result<Vec<Track>> {
+    async fn get_trending(&mut self, limit: usize) -> Result<Vec<Track>> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
-                let url = format!(
-                    "https://api.tidal.com/v1/playlists/{}/radio",
-                    playlist_id
-                );
-
+            if let Some(ref config) = self.credentials() {
                 let response = self
                     .http_client
-                    .get(&url)
+                    .get("https://api.tidal.com/v1/featured/top/tracks")
                     .header(
                         header::AUTHORIZATION,
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US"), ("limit", &limit.to_string())])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1469,19 +1751,16 @@ impl MusicService for TidalClient {
 
                         return Ok(tracks);
                     }
-                    Ok(resp) if resp.status().as_u16() == 404 => {
-                        return Ok(vec![]);
-                    }
                     Ok(resp) if resp.status().as_u16() == 401 && attempt == 0 => {
                         if self.refresh_token().await.is_ok() {
                             continue;
                         }
                     }
                     Ok(resp) => {
-                        eprintln!("Playlist radio request failed: {}", resp.status());
+                        eprintln!("Trending tracks request failed: {}", resp.status());
                     }
                     Err(e) => {
-                        eprintln!("Network error fetching playlist radio: {}", e);
+                        eprintln!("Network error fetching trending tracks: {}", e);
                     }
                 }
             }
@@ -1497,7 +1776,7 @@ impl MusicService for TidalClient {
         description: Option<&str>,
     ) -> Result<Playlist> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url =
                     "https://api.tidal.com/v2/my-collection/playlists/folders/create-playlist";
 
@@ -1517,7 +1796,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&query_params)
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1593,7 +1872,7 @@ impl MusicService for TidalClient {
         }
 
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!("https://api.tidal.com/v1/playlists/{}", playlist_id);
 
                 let mut form_params: Vec<(&str, &str)> = Vec::new();
@@ -1612,7 +1891,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .form(&form_params)
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1648,7 +1927,7 @@ impl MusicService for TidalClient {
 
     async fn delete_playlist(&mut self, playlist_id: &str) -> Result<()> {
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!("https://api.tidal.com/v1/playlists/{}", playlist_id);
 
                 let response = self
@@ -1658,7 +1937,7 @@ impl MusicService for TidalClient {
                         header::AUTHORIZATION,
                         format!("Bearer {}", config.access_token),
                     )
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1704,7 +1983,7 @@ impl MusicService for TidalClient {
         }
 
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let url = format!(
                     "https://api.tidal.com/v1/playlists/{}/items",
                     playlist_id
@@ -1725,7 +2004,7 @@ impl MusicService for TidalClient {
                         ("onArtifactNotFound", "SKIP"),
                         ("onDupes", "ADD"),
                     ])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -1771,7 +2050,7 @@ impl MusicService for TidalClient {
         }
 
         for attempt in 0..2 {
-            if let Some(ref config) = self.config {
+            if let Some(ref config) = self.credentials() {
                 let indices_str = indices
                     .iter()
                     .map(|i| i.to_string())
@@ -1791,7 +2070,7 @@ impl MusicService for TidalClient {
                         format!("Bearer {}", config.access_token),
                     )
                     .query(&[("countryCode", "US")])
-                    .send()
+                    .send_limited(&self.rate_limiter)
                     .await;
 
                 match response {
@@ -7,20 +7,24 @@ use std::path::PathBuf;
 use std::process::Stdio;
 use tokio::process::Command;
 
-use super::youtube_storage::{SavedPlaylist, StoredTrack, YouTubeStorage};
-use super::{Album, Artist, CoverArt, MusicService, Playlist, SearchResults, ServiceType, Track};
+use super::youtube_storage::{SavedPlaylist, StoredChannel, StoredTrack, YouTubeStorage};
+use super::{Album, AlbumType, Artist, CoverArt, MusicService, Playlist, SearchResults, ServiceType, Track};
+use crate::config::NetworkConfig;
 
 /// YouTube Music client using yt-dlp for search and stream extraction
+#[derive(Clone)]
 pub struct YouTubeClient {
     ytdlp_path: PathBuf,
     audio_quality: String,
+    /// `[network]` User-Agent/headers passed to every yt-dlp call
+    network_args: Vec<String>,
 }
 
 impl YouTubeClient {
     /// Create a new YouTube client
     ///
     /// This will error if yt-dlp is not found in PATH or at the configured path.
-    pub async fn new(ytdlp_path: Option<&str>) -> Result<Self> {
+    pub async fn new(ytdlp_path: Option<&str>, network: &NetworkConfig) -> Result<Self> {
         let path = ytdlp_path.unwrap_or("yt-dlp");
 
         // Verify yt-dlp exists and is executable
@@ -35,6 +39,7 @@ impl YouTubeClient {
             Ok(status) if status.success() => Ok(Self {
                 ytdlp_path: PathBuf::from(path),
                 audio_quality: "bestaudio".to_string(),
+                network_args: super::ytdlp_network_args(network, ServiceType::YouTube),
             }),
             Ok(_) => Err(anyhow!(
                 "yt-dlp found but returned error. Please ensure yt-dlp is properly installed."
@@ -52,6 +57,7 @@ impl YouTubeClient {
     /// Execute yt-dlp and return JSON output as parsed values
     async fn run_ytdlp_json(&self, args: &[&str]) -> Result<Vec<Value>> {
         let output = Command::new(&self.ytdlp_path)
+            .args(&self.network_args)
             .args(args)
             .args(["--no-warnings"])
             .output()
@@ -109,9 +115,11 @@ impl YouTubeClient {
             title,
             artist,
             album: String::new(), // YouTube videos don't have albums
+            album_id: None,
             duration_seconds: duration,
             cover_art: thumbnail.map(CoverArt::Url).unwrap_or(CoverArt::None),
             service: ServiceType::YouTube,
+            available: true,
         })
     }
 
@@ -145,6 +153,7 @@ impl YouTubeClient {
             title: stored.title.clone(),
             artist: stored.channel_name.clone(),
             album: String::new(),
+            album_id: None,
             duration_seconds: stored.duration_seconds,
             cover_art: stored
                 .thumbnail_url
@@ -152,6 +161,7 @@ impl YouTubeClient {
                 .map(CoverArt::Url)
                 .unwrap_or(CoverArt::None),
             service: ServiceType::YouTube,
+            available: true,
         }
     }
 
@@ -173,6 +183,10 @@ impl MusicService for YouTubeClient {
         ServiceType::YouTube
     }
 
+    fn clone_box(&self) -> Box<dyn MusicService> {
+        Box::new(self.clone())
+    }
+
     fn is_authenticated(&self) -> bool {
         // Unauthenticated mode - always false
         false
@@ -191,6 +205,7 @@ impl MusicService for YouTubeClient {
         let url = format!("https://www.youtube.com/watch?v={}", track_id);
 
         let output = Command::new(&self.ytdlp_path)
+            .args(&self.network_args)
             .args([
                 "-f",
                 &self.audio_quality,
@@ -332,8 +347,53 @@ impl MusicService for YouTubeClient {
         Ok(())
     }
 
+    async fn add_favorite_artist(&mut self, artist_id: &str) -> Result<()> {
+        // Artists are channels, by ID or @handle
+        let channel_url = if artist_id.starts_with('@') {
+            format!("https://www.youtube.com/{}", artist_id)
+        } else {
+            format!("https://www.youtube.com/channel/{}", artist_id)
+        };
+        let info = self
+            .run_ytdlp_json(&["--flat-playlist", "-J", "--playlist-end", "1", &channel_url])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No channel info returned"))?;
+
+        let stored = StoredChannel {
+            id: artist_id.to_string(),
+            name: info
+                .get("channel")
+                .or_else(|| info.get("uploader"))
+                .and_then(|v| v.as_str())
+                .unwrap_or(artist_id)
+                .to_string(),
+            added_at: Utc::now(),
+        };
+
+        let mut storage = YouTubeStorage::load().unwrap_or_default();
+        storage.add_favorite_channel(stored);
+        storage.save()?;
+
+        Ok(())
+    }
+
+    async fn remove_favorite_artist(&mut self, artist_id: &str) -> Result<()> {
+        // Artists are channels
+        let mut storage = YouTubeStorage::load().unwrap_or_default();
+        storage.remove_favorite_channel(artist_id);
+        storage.save()?;
+        Ok(())
+    }
+
     // === Search ===
 
+    async fn get_track(&mut self, track_id: &str) -> Result<Track> {
+        let info = self.get_video_info(track_id).await?;
+        Self::parse_track(&info).ok_or_else(|| anyhow!("No track info for {}", track_id))
+    }
+
     async fn search(&mut self, query: &str, limit: usize) -> Result<SearchResults> {
         // Track search
         let track_query = format!("ytsearch{}:{}", limit, query);
@@ -417,6 +477,7 @@ impl MusicService for YouTubeClient {
                     num_tracks: count,
                     cover_art: thumbnail.map(CoverArt::Url).unwrap_or(CoverArt::None),
                     service: ServiceType::YouTube,
+                    album_type: AlbumType::Unknown,
                 })
             })
             .collect();
@@ -451,6 +512,7 @@ impl MusicService for YouTubeClient {
         };
 
         let output = Command::new(&self.ytdlp_path)
+            .args(&self.network_args)
             .args([
                 "--flat-playlist",
                 "-j",
@@ -488,6 +550,7 @@ impl MusicService for YouTubeClient {
         };
 
         let output = Command::new(&self.ytdlp_path)
+            .args(&self.network_args)
             .args([
                 "--flat-playlist",
                 "-j",
@@ -523,6 +586,7 @@ impl MusicService for YouTubeClient {
                         .map(CoverArt::Url)
                         .unwrap_or(CoverArt::None),
                     service: ServiceType::YouTube,
+                    album_type: AlbumType::Unknown,
                 })
             })
             .collect();
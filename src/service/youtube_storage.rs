@@ -80,9 +80,9 @@ impl YouTubeStorage {
 
     /// Get the storage file path
     fn storage_path() -> Result<PathBuf> {
-        let config_dir = dirs::config_dir()
+        let config_dir = crate::config::drift_config_dir()
             .ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
-        Ok(config_dir.join("drift").join("youtube_data.toml"))
+        Ok(config_dir.join("youtube_data.toml"))
     }
 
     /// Load storage from disk, returning default if not found
@@ -148,7 +148,6 @@ impl YouTubeStorage {
     // === Favorite Channels/Artists ===
 
     /// Add a channel to favorites
-    #[allow(dead_code)]
     pub fn add_favorite_channel(&mut self, channel: StoredChannel) {
         if !self.favorite_channels.iter().any(|c| c.id == channel.id) {
             self.favorite_channels.push(channel);
@@ -156,7 +155,6 @@ impl YouTubeStorage {
     }
 
     /// Remove a channel from favorites by ID
-    #[allow(dead_code)]
     pub fn remove_favorite_channel(&mut self, channel_id: &str) -> bool {
         let len_before = self.favorite_channels.len();
         self.favorite_channels.retain(|c| c.id != channel_id);
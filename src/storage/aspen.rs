@@ -854,9 +854,11 @@ mod tests {
             title: title.to_string(),
             artist: artist.to_string(),
             album: "Test Album".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art,
             service,
+            available: true,
         }
     }
 
@@ -314,9 +314,11 @@ mod tests {
             title: format!("Track {}", id),
             artist: "Artist".to_string(),
             album: "Album".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art: CoverArt::None,
             service: ServiceType::Tidal,
+            available: true,
         }
     }
 
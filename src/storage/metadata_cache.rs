@@ -6,9 +6,11 @@ use std::fs;
 use std::path::PathBuf;
 use std::time::Duration;
 
-use crate::service::{Album, Artist, Playlist, Track};
+use crate::metadata::EnrichedTrack;
+use crate::service::{Album, Artist, Playlist, ServiceType, Track};
 
-/// Key: "playlists", "playlist_tracks:{id}", "favorites", "album_tracks:{id}", "artist_data:{id}"
+/// Key: "playlists", "playlist_tracks:{id}", "favorites", "album_tracks:{id}", "artist_data:{id}",
+/// "enrichment:{service}:{track_id}"
 /// Value: JSON bytes of CacheEntry<T>
 const METADATA_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("metadata_cache");
 
@@ -68,8 +70,8 @@ impl MetadataCache {
     }
 
     fn default_path() -> Result<PathBuf> {
-        let data_dir = dirs::data_dir().context("Failed to determine system data directory")?;
-        Ok(data_dir.join("drift").join("metadata.redb"))
+        let data_dir = crate::config::drift_data_dir().context("Failed to determine system data directory")?;
+        Ok(data_dir.join("metadata.redb"))
     }
 
     /// Get a cached entry and check staleness.
@@ -220,6 +222,27 @@ impl MetadataCache {
         self.set_entry(&key, &(tracks, albums))
     }
 
+    // MusicBrainz enrichment (keyed by service and track_id)
+
+    pub fn get_enrichment(
+        &self,
+        service: ServiceType,
+        track_id: &str,
+    ) -> Result<Option<CacheHit<EnrichedTrack>>> {
+        let key = format!("enrichment:{}:{}", service, track_id);
+        self.get_entry(&key)
+    }
+
+    pub fn set_enrichment(
+        &self,
+        service: ServiceType,
+        track_id: &str,
+        enriched: &EnrichedTrack,
+    ) -> Result<()> {
+        let key = format!("enrichment:{}:{}", service, track_id);
+        self.set_entry(&key, enriched)
+    }
+
     /// Invalidate a specific cache entry.
     pub fn invalidate(&self, table_key: &str) -> Result<()> {
         let txn = self
@@ -275,7 +298,7 @@ impl MetadataCache {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::service::{CoverArt, ServiceType};
+    use crate::service::{AlbumType, CoverArt, ServiceType};
     use std::thread::sleep;
 
     fn make_test_track(id: &str) -> Track {
@@ -284,9 +307,11 @@ mod tests {
             title: format!("Track {}", id),
             artist: "Artist".to_string(),
             album: "Album".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art: CoverArt::None,
             service: ServiceType::Tidal,
+            available: true,
         }
     }
 
@@ -308,6 +333,7 @@ mod tests {
             num_tracks: 12,
             cover_art: CoverArt::None,
             service: ServiceType::Tidal,
+            album_type: AlbumType::Unknown,
         }
     }
 
@@ -414,6 +440,22 @@ mod tests {
         assert_eq!(hit.status, CacheStatus::Fresh);
     }
 
+    #[test]
+    fn test_set_get_enrichment() {
+        let cache = MetadataCache::new_in_memory(Duration::from_secs(3600)).unwrap();
+        let enriched = EnrichedTrack {
+            mbid: Some("rec-1".to_string()),
+            year: Some(1999),
+            genre: None,
+        };
+
+        cache.set_enrichment(ServiceType::Tidal, "t1", &enriched).unwrap();
+
+        assert_eq!(cache.get_enrichment(ServiceType::Tidal, "t1").unwrap().unwrap().data, enriched);
+        // Track ids are only unique within a service
+        assert!(cache.get_enrichment(ServiceType::YouTube, "t1").unwrap().is_none());
+    }
+
     #[test]
     fn test_cache_miss_returns_none() {
         let cache = MetadataCache::new_in_memory(Duration::from_secs(3600)).unwrap();
@@ -87,9 +87,8 @@ impl WalManager {
     }
 
     fn get_db_path() -> Result<PathBuf> {
-        let data_dir = dirs::data_dir()
-            .context("Failed to get data directory")?
-            .join("drift");
+        let data_dir = crate::config::drift_data_dir()
+            .context("Failed to get data directory")?;
         std::fs::create_dir_all(&data_dir)
             .context("Failed to create data directory")?;
         Ok(data_dir.join("wal.redb"))
@@ -250,9 +249,11 @@ mod tests {
             title: title.to_string(),
             artist: artist.to_string(),
             album: "Test Album".to_string(),
+            album_id: None,
             duration_seconds: 180,
             cover_art: CoverArt::None,
             service: ServiceType::Tidal,
+            available: true,
         }
     }
 
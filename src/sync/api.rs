@@ -125,7 +125,9 @@ impl SyncApiClient {
             dirs::config_dir().context("Could not determine config directory")?;
 
         // Try drift path first
-        let drift_path = config_dir.join("drift").join("credentials.json");
+        let drift_path = crate::config::drift_config_dir()
+            .context("Could not determine config directory")?
+            .join("credentials.json");
         if drift_path.exists() {
             let config = Self::load_creds_from(&drift_path)?;
             return Ok((config, drift_path));
@@ -497,9 +497,11 @@ impl SyncEngine {
                         title: track.title.clone(),
                         artist: track.artist.clone(),
                         album: track.album.clone(),
+                        album_id: None,
                         duration_seconds: 0,
                         cover_art: crate::service::CoverArt::None,
                         service: crate::service::ServiceType::Tidal,
+                        available: true,
                     };
                     if let Err(e) = storage.record_play(&drift_track).await {
                         eprintln!("    ⚠ Aspen sync: {}", e);
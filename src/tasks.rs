@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+
+use tokio::sync::mpsc;
+
+pub type TaskId = u64;
+
+/// Update from a background task: its latest progress, or that it finished
+#[derive(Debug, Clone)]
+pub struct TaskEvent {
+    pub id: TaskId,
+    pub label: String,
+    /// (done, total) in whatever unit the task counts: tracks, bytes, ...
+    pub progress: Option<(u64, u64)>,
+    pub done: bool,
+}
+
+impl TaskEvent {
+    /// Label with a percentage once the task has reported progress
+    pub fn summary(&self) -> String {
+        match self.progress {
+            Some((done, total)) if total > 0 => {
+                format!("{} {}%", self.label, (done.min(total) * 100 / total))
+            }
+            _ => self.label.clone(),
+        }
+    }
+}
+
+/// Given to a spawned job so it can report progress back to the main loop
+#[derive(Clone)]
+pub struct TaskHandle {
+    id: TaskId,
+    label: String,
+    tx: mpsc::UnboundedSender<TaskEvent>,
+}
+
+impl TaskHandle {
+    pub fn progress(&self, done: u64, total: u64) {
+        self.send(Some((done, total)), false);
+    }
+
+    fn send(&self, progress: Option<(u64, u64)>, done: bool) {
+        let _ = self.tx.send(TaskEvent {
+            id: self.id,
+            label: self.label.clone(),
+            progress,
+            done,
+        });
+    }
+}
+
+/// Spawns async jobs and tracks the ones still running, so the status bar
+/// can show them. The main loop calls [`TaskManager::poll`] every tick.
+pub struct TaskManager {
+    next_id: TaskId,
+    tx: mpsc::UnboundedSender<TaskEvent>,
+    rx: mpsc::UnboundedReceiver<TaskEvent>,
+    active: BTreeMap<TaskId, TaskEvent>,
+}
+
+impl TaskManager {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self {
+            next_id: 0,
+            tx,
+            rx,
+            active: BTreeMap::new(),
+        }
+    }
+
+    /// Run `job` on its own tokio task. It shows as active until it returns
+    /// or panics.
+    pub fn spawn<F, Fut>(&mut self, label: impl Into<String>, job: F) -> TaskId
+    where
+        F: FnOnce(TaskHandle) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let handle = TaskHandle {
+            id,
+            label: label.into(),
+            tx: self.tx.clone(),
+        };
+        self.active.insert(id, TaskEvent {
+            id,
+            label: handle.label.clone(),
+            progress: None,
+            done: false,
+        });
+
+        let job = tokio::spawn(job(handle.clone()));
+        tokio::spawn(async move {
+            if let Err(e) = job.await {
+                tracing::error!("task '{}' failed: {}", handle.label, e);
+            }
+            handle.send(None, true);
+        });
+        id
+    }
+
+    /// Apply the events sent since the last call, dropping finished tasks
+    pub fn poll(&mut self) {
+        while let Ok(event) = self.rx.try_recv() {
+            self.apply(event);
+        }
+    }
+
+    fn apply(&mut self, event: TaskEvent) {
+        if event.done {
+            self.active.remove(&event.id);
+        } else if let Some(task) = self.active.get_mut(&event.id) {
+            *task = event;
+        }
+    }
+
+    /// Wait for the next event from a task and apply it
+    #[cfg(test)]
+    async fn poll_next(&mut self) {
+        if let Some(event) = self.rx.recv().await {
+            self.apply(event);
+        }
+    }
+
+    /// Running tasks, oldest first
+    pub fn active(&self) -> impl Iterator<Item = &TaskEvent> {
+        self.active.values()
+    }
+}
+
+impl Default for TaskManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tracks_progress_until_done() {
+        let mut tasks = TaskManager::new();
+        let (go_tx, go_rx) = tokio::sync::oneshot::channel::<()>();
+        let id = tasks.spawn("Queuing 4 tracks", |task| async move {
+            task.progress(1, 4);
+            let _ = go_rx.await;
+        });
+        assert_eq!(tasks.active().count(), 1);
+
+        tasks.poll_next().await;
+        let task = tasks.active().next().unwrap();
+        assert_eq!(task.id, id);
+        assert_eq!(task.summary(), "Queuing 4 tracks 25%");
+
+        go_tx.send(()).unwrap();
+        tasks.poll_next().await;
+        assert_eq!(tasks.active().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_panicking_task_finishes() {
+        let mut tasks = TaskManager::new();
+        tasks.spawn("Doomed", |_| async move { panic!("job failed") });
+
+        tasks.poll_next().await;
+        assert_eq!(tasks.active().count(), 0);
+    }
+
+    #[test]
+    fn test_summary_without_progress() {
+        let event = TaskEvent { id: 0, label: "Syncing".to_string(), progress: Some((0, 0)), done: false };
+        assert_eq!(event.summary(), "Syncing");
+    }
+}
@@ -1,12 +1,15 @@
 use ratatui::{
     layout::Rect,
     style::Style,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem},
     Frame,
 };
 
 use crate::service::{Album, Track};
-use super::styles::{format_track_with_indicator, is_track_playing};
+use super::keybindings::hints;
+use crate::app::ActionKind;
+use crate::keymap::Keymap;
+use super::styles::{availability_badge, format_track_with_indicator, is_track_playing, list_state, ListScroll};
 use super::theme::Theme;
 
 pub struct AlbumDetailViewState<'a> {
@@ -14,6 +17,9 @@ pub struct AlbumDetailViewState<'a> {
     pub tracks: &'a [Track],
     pub selected_track: usize,
     pub current_track_id: Option<&'a str>,
+    /// `ui.scroll_style` / `ui.scrolloff`
+    pub scroll: ListScroll,
+    pub keymap: &'a Keymap,
 }
 
 pub fn render_album_detail_view(f: &mut Frame, state: &AlbumDetailViewState, area: Rect, theme: &Theme) -> Rect {
@@ -33,11 +39,12 @@ pub fn render_album_detail_view(f: &mut Frame, state: &AlbumDetailViewState, are
 
             // Show track number
             let display = format!(
-                "{}. {} ({}:{:02})",
+                "{}. {} ({}:{:02}){}",
                 i + 1,
                 track.title,
                 track.duration_seconds / 60,
-                track.duration_seconds % 60
+                track.duration_seconds % 60,
+                availability_badge(track)
             );
             let display = format_track_with_indicator(display, is_playing);
             ListItem::new(display).style(style)
@@ -45,9 +52,15 @@ pub fn render_album_detail_view(f: &mut Frame, state: &AlbumDetailViewState, are
         .collect();
 
     let title = format!(
-        "{} ({} tracks) [p: play | y: queue | Y: queue all | Esc: back]",
+        "{} ({} tracks) {}",
         album_info,
-        state.tracks.len()
+        state.tracks.len(),
+        hints(state.keymap, &[
+            (ActionKind::PlaySelected, "play"),
+            (ActionKind::AddToQueue, "queue"),
+            (ActionKind::AddAllToQueue, "queue all"),
+            (ActionKind::Back, "back"),
+        ])
     );
     let list = List::new(track_items)
         .block(
@@ -61,7 +74,7 @@ pub fn render_album_detail_view(f: &mut Frame, state: &AlbumDetailViewState, are
     f.render_stateful_widget(
         list,
         area,
-        &mut ListState::default().with_selected(Some(state.selected_track)),
+        &mut list_state(Some(state.selected_track), state.tracks.len(), area, state.scroll),
     );
 
     area
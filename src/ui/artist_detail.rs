@@ -1,22 +1,29 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::Style,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem},
     Frame,
 };
 
-use crate::service::{Album, Artist, Track};
-use super::styles::{format_track_with_indicator, is_track_playing};
+use crate::service::{Album, AlbumType, Artist, Track};
+use super::keybindings::hints;
+use crate::app::ActionKind;
+use crate::keymap::Keymap;
+use super::styles::{availability_badge, format_track_with_indicator, is_track_playing, list_state, ListScroll};
 use super::theme::Theme;
 
 pub struct ArtistDetailViewState<'a> {
     pub artist: Option<&'a Artist>,
     pub top_tracks: &'a [Track],
     pub albums: &'a [Album],
+    pub album_filter: Option<AlbumType>,
     pub selected_track: usize,
     pub selected_album: usize,
     pub selected_panel: usize, // 0 = top tracks, 1 = albums
     pub current_track_id: Option<&'a str>,
+    /// `ui.scroll_style` / `ui.scrolloff`
+    pub scroll: ListScroll,
+    pub keymap: &'a Keymap,
 }
 
 pub fn render_artist_detail_view(
@@ -49,11 +56,12 @@ pub fn render_artist_detail_view(
             let style = theme.track_style(is_selected, is_playing);
 
             let display = format!(
-                "{} - {} ({}:{:02})",
+                "{} - {} ({}:{:02}){}",
                 track.artist,
                 track.title,
                 track.duration_seconds / 60,
-                track.duration_seconds % 60
+                track.duration_seconds % 60,
+                availability_badge(track)
             );
             let display = format_track_with_indicator(display, is_playing);
             ListItem::new(display).style(style)
@@ -61,9 +69,10 @@ pub fn render_artist_detail_view(
         .collect();
 
     let tracks_title = format!(
-        "{} - Top Tracks ({}) [p: play | y: queue]",
+        "{} - Top Tracks ({}) {}",
         artist_name,
-        state.top_tracks.len()
+        state.top_tracks.len(),
+        hints(state.keymap, &[(ActionKind::PlaySelected, "play"), (ActionKind::AddToQueue, "queue")])
     );
     let tracks_widget = List::new(track_items)
         .block(
@@ -71,7 +80,7 @@ pub fn render_artist_detail_view(
                 .borders(Borders::ALL)
                 .title(tracks_title)
                 .border_style(if state.selected_panel == 0 {
-                    Style::default().fg(theme.warning())
+                    Style::default().fg(theme.selection())
                 } else {
                     Style::default().fg(theme.border_normal())
                 }),
@@ -87,7 +96,7 @@ pub fn render_artist_detail_view(
     f.render_stateful_widget(
         tracks_widget,
         left_area,
-        &mut ListState::default().with_selected(selected_track),
+        &mut list_state(selected_track, state.top_tracks.len(), left_area, state.scroll),
     );
 
     // Right panel - Albums/Discography
@@ -100,17 +109,28 @@ pub fn render_artist_detail_view(
         })
         .collect();
 
-    let albums_title = format!(
-        "Discography ({}) [v: view | y: queue]",
-        state.albums.len()
-    );
+    let album_hints = hints(state.keymap, &[
+        (ActionKind::ViewDetail, "view"),
+        (ActionKind::AddToQueue, "queue"),
+        (ActionKind::AddAllToQueue, "queue all"),
+        (ActionKind::Sort, "type"),
+    ]);
+    let albums_title = match state.album_filter {
+        Some(filter) => format!(
+            "Discography: {} ({}) {}",
+            filter.label(),
+            state.albums.len(),
+            album_hints
+        ),
+        None => format!("Discography ({}) {}", state.albums.len(), album_hints),
+    };
     let albums_widget = List::new(album_items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(albums_title)
                 .border_style(if state.selected_panel == 1 {
-                    Style::default().fg(theme.warning())
+                    Style::default().fg(theme.selection())
                 } else {
                     Style::default().fg(theme.border_normal())
                 }),
@@ -126,7 +146,7 @@ pub fn render_artist_detail_view(
     f.render_stateful_widget(
         albums_widget,
         right_area,
-        &mut ListState::default().with_selected(selected_album),
+        &mut list_state(selected_album, state.albums.len(), right_area, state.scroll),
     );
 
     (left_area, right_area)
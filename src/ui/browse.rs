@@ -3,12 +3,16 @@ use std::collections::HashSet;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::Style,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem},
     Frame,
 };
 
+use crate::playlist_view_state::TrackSort;
 use crate::service::{Playlist, Track};
-use super::styles::{format_track_with_indicator, is_track_playing, service_badge};
+use super::keybindings::hints;
+use crate::app::ActionKind;
+use crate::keymap::Keymap;
+use super::styles::{availability_badge, format_track_with_indicator, is_track_playing, list_state, ListScroll, service_badge};
 use super::theme::Theme;
 
 pub struct BrowseViewState<'a> {
@@ -19,6 +23,16 @@ pub struct BrowseViewState<'a> {
     pub selected_tab: usize,
     pub synced_playlist_ids: &'a HashSet<String>,
     pub current_track_id: Option<&'a str>,
+    pub sort: TrackSort,
+    /// Track indices to draw, best match first, while a filter is set
+    /// (None = every track in order)
+    pub filtered_indices: Option<&'a [usize]>,
+    pub filter_query: &'a str,
+    /// Filter input has focus
+    pub filter_active: bool,
+    /// `ui.scroll_style` / `ui.scrolloff`
+    pub scroll: ListScroll,
+    pub keymap: &'a Keymap,
 }
 
 pub fn render_browse_view(
@@ -54,7 +68,7 @@ pub fn render_browse_view(
                 .borders(Borders::ALL)
                 .title("Playlists [h/l: switch | Enter: load | S: sync]")
                 .border_style(if state.selected_tab == 0 {
-                    Style::default().fg(theme.warning())
+                    Style::default().fg(theme.selection())
                 } else {
                     Style::default().fg(theme.border_normal())
                 }),
@@ -70,39 +84,52 @@ pub fn render_browse_view(
     f.render_stateful_widget(
         playlists_widget,
         left_area,
-        &mut ListState::default().with_selected(selected_playlist),
+        &mut list_state(selected_playlist, state.playlists.len(), left_area, state.scroll),
     );
 
-    // Right panel - Tracks
-    let tracks: Vec<ListItem> = state
-        .tracks
+    // Right panel - Tracks, narrowed to the filter's matches if one is set
+    let rows: Vec<usize> = match state.filtered_indices {
+        Some(indices) => indices.to_vec(),
+        None => (0..state.tracks.len()).collect(),
+    };
+    let tracks: Vec<ListItem> = rows
         .iter()
-        .enumerate()
+        .filter_map(|&i| state.tracks.get(i).map(|track| (i, track)))
         .map(|(i, track)| {
             let is_selected = state.selected_tab == 1 && i == state.selected_track;
             let is_playing = is_track_playing(&track.id, state.current_track_id);
             let style = theme.track_style(is_selected, is_playing);
 
             let display = format!(
-                "{} {} - {} ({}:{:02})",
+                "{} {} - {} ({}:{:02}){}",
                 service_badge(track.service),
                 track.artist,
                 track.title,
                 track.duration_seconds / 60,
-                track.duration_seconds % 60
+                track.duration_seconds % 60,
+                availability_badge(track)
             );
             let display = format_track_with_indicator(display, is_playing);
             ListItem::new(display).style(style)
         })
         .collect();
 
+    let track_hints = hints(state.keymap, &[(ActionKind::PlaySelected, "play"), (ActionKind::AddToQueue, "add to queue"), (ActionKind::Sort, "sort")]);
     let tracks_widget = List::new(tracks)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Tracks [p/Enter: play | y: add to queue]")
+                .title(if state.filter_active {
+                    format!("Filter: {}_ ({}/{}) [Enter: keep | Esc: clear]", state.filter_query, rows.len(), state.tracks.len())
+                } else if state.filtered_indices.is_some() {
+                    format!("Tracks matching '{}' ({}/{}) [Esc: clear]", state.filter_query, rows.len(), state.tracks.len())
+                } else if state.sort == TrackSort::Playlist {
+                    format!("Tracks {}", track_hints)
+                } else {
+                    format!("Tracks by {} {}", state.sort.label(), track_hints)
+                })
                 .border_style(if state.selected_tab == 1 {
-                    Style::default().fg(theme.warning())
+                    Style::default().fg(theme.selection())
                 } else {
                     Style::default().fg(theme.border_normal())
                 }),
@@ -110,15 +137,15 @@ pub fn render_browse_view(
         .highlight_style(theme.highlight_style())
         .highlight_symbol("> ");
 
-    let selected_track = if state.selected_tab == 1 {
-        Some(state.selected_track)
+    let selected_row = if state.selected_tab == 1 {
+        rows.iter().position(|&i| i == state.selected_track)
     } else {
         None
     };
     f.render_stateful_widget(
         tracks_widget,
         right_area,
-        &mut ListState::default().with_selected(selected_track),
+        &mut list_state(selected_row, rows.len(), right_area, state.scroll),
     );
 
     (left_area, right_area)
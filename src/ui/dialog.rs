@@ -2,11 +2,14 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Paragraph},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
+use crate::app::matching_actions;
 use crate::app::state::DialogMode;
+use crate::downloads::format_bytes;
+use crate::keymap::Keymap;
 use crate::service::Playlist;
 use super::theme::Theme;
 
@@ -15,6 +18,7 @@ pub struct DialogRenderState<'a> {
     pub input_text: &'a str,
     pub selected_index: usize,
     pub playlists: &'a [Playlist],
+    pub keymap: &'a Keymap,
 }
 
 pub fn render_dialog(f: &mut Frame, state: &DialogRenderState, area: Rect, theme: &Theme) {
@@ -30,6 +34,19 @@ pub fn render_dialog(f: &mut Frame, state: &DialogRenderState, area: Rect, theme
                 theme,
             );
         }
+        DialogMode::SleepTimer => {
+            render_text_input_dialog(
+                f,
+                "Sleep Timer",
+                "Pause playback in how many minutes? (0 cancels)",
+                state.input_text,
+                area,
+                theme,
+            );
+        }
+        DialogMode::CommandPalette => {
+            render_command_palette(f, state.input_text, state.selected_index, state.keymap, area, theme);
+        }
         DialogMode::AddToPlaylist { track_title, .. } => {
             render_playlist_selector_dialog(
                 f,
@@ -50,11 +67,55 @@ pub fn render_dialog(f: &mut Frame, state: &DialogRenderState, area: Rect, theme
                 theme,
             );
         }
-        DialogMode::ConfirmDeletePlaylist { playlist_title, .. } => {
+        DialogMode::ConfirmDeletePlaylist { playlist_title, track_count, synced, .. } => {
+            let mut message = format!(
+                "Delete '{}' and its {} track{}?",
+                truncate_str(playlist_title, 20),
+                track_count,
+                if *track_count == 1 { "" } else { "s" }
+            );
+            if *synced {
+                message.push_str("\nSynced: auto-sync stops, downloads are kept");
+            }
+            render_confirm_dialog(f, "Delete Playlist", &message, area, theme);
+        }
+        DialogMode::ConfirmSyncPlaylist { playlist_title, track_count, estimated_bytes } => {
+            render_confirm_dialog(
+                f,
+                &format!("Sync: {}", truncate_str(playlist_title, 30)),
+                &format!(
+                    "Download {} new tracks (~{})?",
+                    track_count,
+                    format_bytes(*estimated_bytes)
+                ),
+                area,
+                theme,
+            );
+        }
+        DialogMode::ConfirmDownloadAll { title, track_count, estimated_bytes } => {
             render_confirm_dialog(
                 f,
-                "Delete Playlist",
-                &format!("Are you sure you want to delete '{}'?", playlist_title),
+                &format!("Download: {}", truncate_str(title, 30)),
+                &format!(
+                    "Download {} tracks (~{})?",
+                    track_count,
+                    format_bytes(*estimated_bytes)
+                ),
+                area,
+                theme,
+            );
+        }
+        DialogMode::ConfirmOverwriteDownloads { track_count, existing } => {
+            let message = if *track_count == 1 {
+                "This track is already downloaded.\nDownload it again?".to_string()
+            } else {
+                format!("{} of {} tracks are already downloaded.\nDownload them again?", existing, track_count)
+            };
+            render_choice_dialog(
+                f,
+                "Already Downloaded",
+                &message,
+                "Enter/y: re-download | s: skip them | Esc: cancel",
                 area,
                 theme,
             );
@@ -197,12 +258,111 @@ fn render_playlist_selector_dialog(
     f.render_widget(help_text, chunks[1]);
 }
 
+/// Filter line over the matching actions, each with the key bound to it
+fn render_command_palette(
+    f: &mut Frame,
+    query: &str,
+    selected: usize,
+    keymap: &Keymap,
+    area: Rect,
+    theme: &Theme,
+) {
+    let popup_width = 76.min(area.width.saturating_sub(4));
+    let popup_height = 20.min(area.height.saturating_sub(4));
+    let popup_x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Command Palette ")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.primary()));
+
+    f.render_widget(block.clone(), popup_area);
+
+    let inner = block.inner(popup_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    // Filter input with cursor
+    let input_field = Paragraph::new(format!(":{}_", query))
+        .style(Style::default().fg(theme.warning()).add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Style::default().fg(theme.text_disabled())),
+        );
+    f.render_widget(input_field, chunks[0]);
+
+    let actions = matching_actions(query);
+    if actions.is_empty() {
+        let empty_msg = Paragraph::new("No matching actions")
+            .style(Style::default().fg(theme.text_disabled()))
+            .alignment(Alignment::Center);
+        f.render_widget(empty_msg, chunks[1]);
+    } else {
+        let items: Vec<ListItem> = actions
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let (name_style, detail_style) = if i == selected {
+                    let style = Style::default()
+                        .fg(theme.text())
+                        .bg(theme.primary())
+                        .add_modifier(Modifier::BOLD);
+                    (style, style)
+                } else {
+                    (Style::default().fg(theme.text()), Style::default().fg(theme.text_muted()))
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:<26}", action.name), name_style),
+                    Span::styled(format!("{:<9}", keymap.label(action.action).unwrap_or("")), detail_style),
+                    Span::styled(action.description, detail_style),
+                ]))
+            })
+            .collect();
+
+        // Stateful so the list scrolls to keep the selection in view
+        let mut list_state = ListState::default().with_selected(Some(selected));
+        f.render_stateful_widget(List::new(items), chunks[1], &mut list_state);
+    }
+
+    // Help text
+    let help_text = Paragraph::new("type to filter | ↑/↓: select | Enter: run | Esc: cancel")
+        .style(Style::default().fg(theme.text_disabled()))
+        .alignment(Alignment::Center);
+    f.render_widget(help_text, chunks[2]);
+}
+
 fn render_confirm_dialog(
     f: &mut Frame,
     title: &str,
     message: &str,
     area: Rect,
     theme: &Theme,
+) {
+    render_choice_dialog(f, title, message, "Enter/y: confirm | Esc/n: cancel", area, theme);
+}
+
+/// Confirmation popup with its own key hints, for more than yes/no
+fn render_choice_dialog(
+    f: &mut Frame,
+    title: &str,
+    message: &str,
+    help: &str,
+    area: Rect,
+    theme: &Theme,
 ) {
     let popup_width = 50.min(area.width.saturating_sub(4));
     let popup_height = 7;
@@ -238,7 +398,7 @@ fn render_confirm_dialog(
     f.render_widget(msg, chunks[0]);
 
     // Help text
-    let help_text = Paragraph::new("Enter/y: confirm | Esc/n: cancel")
+    let help_text = Paragraph::new(help)
         .style(Style::default().fg(theme.text_disabled()))
         .alignment(Alignment::Center);
     f.render_widget(help_text, chunks[1]);
@@ -1,12 +1,13 @@
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Modifier, Style},
-    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
 use crate::download_db::{DownloadRecord, DownloadStatus};
 use crate::downloads::format_bytes;
+use super::styles::{list_state, ListScroll};
 use super::theme::Theme;
 
 pub struct DownloadsViewState<'a> {
@@ -17,6 +18,8 @@ pub struct DownloadsViewState<'a> {
     pub pending_count: usize,
     pub completed_count: usize,
     pub failed_count: usize,
+    /// `ui.scroll_style` / `ui.scrolloff`
+    pub scroll: ListScroll,
 }
 
 pub fn render_downloads_view(
@@ -48,7 +51,7 @@ pub fn render_downloads_view(
         .enumerate()
         .map(|(i, record)| {
             let style = if i == state.selected_download {
-                Style::default().fg(theme.warning()).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.selection()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             };
@@ -72,11 +75,12 @@ pub fn render_downloads_view(
                 DownloadStatus::Completed => "[OK]",
                 DownloadStatus::Failed => "[X]",
                 DownloadStatus::Paused => "[||]",
+                DownloadStatus::Corrupt => "[!]",
             };
 
             let status_color = match record.status {
                 DownloadStatus::Completed => theme.success(),
-                DownloadStatus::Failed => theme.error(),
+                DownloadStatus::Failed | DownloadStatus::Corrupt => theme.error(),
                 DownloadStatus::Downloading => theme.primary(),
                 DownloadStatus::Paused => theme.warning(),
                 DownloadStatus::Pending => theme.text_disabled(),
@@ -114,7 +118,7 @@ pub fn render_downloads_view(
     f.render_stateful_widget(
         downloads_list,
         area,
-        &mut ListState::default().with_selected(Some(state.selected_download)),
+        &mut list_state(Some(state.selected_download), state.download_records.len(), area, state.scroll),
     );
 
     area
@@ -8,9 +8,12 @@ use ratatui::{
 
 use super::keybindings::KEYBINDING_CATEGORIES;
 use super::theme::Theme;
+use crate::app::ActionKind;
+use crate::keymap::Keymap;
 
-pub struct HelpPanelState {
+pub struct HelpPanelState<'a> {
     pub scroll_offset: usize,
+    pub keymap: &'a Keymap,
 }
 
 pub fn render_help_panel(f: &mut Frame, state: &HelpPanelState, area: Rect, theme: &Theme) {
@@ -47,7 +50,7 @@ pub fn render_help_panel(f: &mut Frame, state: &HelpPanelState, area: Rect, them
         for binding in category.bindings {
             lines.push(Line::from(vec![
                 Span::styled(
-                    format!("  {:16}", binding.keys),
+                    format!("  {:16}", binding.keys_label(state.keymap)),
                     Style::default().fg(theme.success()),
                 ),
                 Span::raw(binding.description),
@@ -62,7 +65,11 @@ pub fn render_help_panel(f: &mut Frame, state: &HelpPanelState, area: Rect, them
     let help_paragraph = Paragraph::new(visible_lines)
         .block(
             Block::default()
-                .title(" Help - Press any key to close (j/k to scroll) ")
+                .title(format!(
+                    " Help - Press any key to close ({}/{} to scroll) ",
+                    state.keymap.label(ActionKind::MoveDown).unwrap_or("-"),
+                    state.keymap.label(ActionKind::MoveUp).unwrap_or("-"),
+                ))
                 .title_alignment(Alignment::Center)
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
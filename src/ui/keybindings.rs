@@ -1,35 +1,91 @@
+use crate::app::ActionKind;
+use crate::keymap::Keymap;
+
+/// Keys a help entry lists
+pub enum Keys {
+    /// Whatever these actions are bound to, joined with '/'
+    Actions(&'static [ActionKind]),
+    /// Keys that can't be rebound
+    Fixed(&'static str),
+}
+
 pub struct Keybinding {
-    pub keys: &'static str,
+    pub keys: Keys,
+    /// Where the keys do this, when it's not everywhere
+    pub context: Option<&'static str>,
     pub description: &'static str,
 }
 
+impl Keybinding {
+    /// Keys as bound in `keymap`, e.g. "h/j/k/l" or "t (Browse)"
+    pub fn keys_label(&self, keymap: &Keymap) -> String {
+        let keys = match self.keys {
+            Keys::Actions(actions) => actions
+                .iter()
+                .map(|&action| keymap.label(action).unwrap_or("-"))
+                .collect::<Vec<_>>()
+                .join("/"),
+            Keys::Fixed(keys) => keys.to_string(),
+        };
+        match self.context {
+            Some(context) => format!("{} ({})", keys, context),
+            None => keys,
+        }
+    }
+}
+
 pub struct KeybindingCategory {
     pub name: &'static str,
     pub bindings: &'static [Keybinding],
 }
 
+/// Panel-title hint like `[p: play | y: queue]`, built from the keys each
+/// action is bound to in `keymap`; unbound actions are left out
+pub fn hints(keymap: &Keymap, items: &[(ActionKind, &str)]) -> String {
+    let parts: Vec<String> = items
+        .iter()
+        .filter_map(|&(action, label)| Some(format!("{}: {}", keymap.label(action)?, label)))
+        .collect();
+    format!("[{}]", parts.join(" | "))
+}
+
 pub const KEYBINDING_CATEGORIES: &[KeybindingCategory] = &[
     KeybindingCategory {
         name: "Navigation",
         bindings: &[
             Keybinding {
-                keys: "h/j/k/l",
+                keys: Keys::Actions(&[ActionKind::MoveLeft, ActionKind::MoveDown, ActionKind::MoveUp, ActionKind::MoveRight]),
+                context: None,
                 description: "Move left/down/up/right",
             },
             Keybinding {
-                keys: "Tab",
-                description: "Cycle tabs/panels",
+                keys: Keys::Actions(&[ActionKind::NextTab, ActionKind::PreviousTab]),
+                context: None,
+                description: "Cycle tabs/panels forward/backward",
             },
             Keybinding {
-                keys: "gg",
+                keys: Keys::Actions(&[ActionKind::AltTab]),
+                context: Some("Search"),
+                description: "Cycle service filter (or result tabs, per search.tab_action)",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::JumpTop]),
+                context: None,
                 description: "Jump to top",
             },
             Keybinding {
-                keys: "ge",
+                keys: Keys::Actions(&[ActionKind::JumpEnd]),
+                context: None,
                 description: "Jump to end",
             },
             Keybinding {
-                keys: "Esc",
+                keys: Keys::Actions(&[ActionKind::Filter]),
+                context: Some("Browse"),
+                description: "Filter the track list as you type (Esc clears)",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::Back]),
+                context: None,
                 description: "Back/cancel",
             },
         ],
@@ -38,28 +94,54 @@ pub const KEYBINDING_CATEGORIES: &[KeybindingCategory] = &[
         name: "Playback",
         bindings: &[
             Keybinding {
-                keys: "Space+p",
+                keys: Keys::Actions(&[ActionKind::TogglePlayback]),
+                context: None,
                 description: "Pause/resume",
             },
             Keybinding {
-                keys: "Space+n",
+                keys: Keys::Actions(&[ActionKind::NextTrack]),
+                context: None,
                 description: "Next track",
             },
             Keybinding {
-                keys: "Space+b",
-                description: "Previous track",
+                keys: Keys::Actions(&[ActionKind::PreviousTrack]),
+                context: None,
+                description: "Previous track (restarts the current one once it has played a while)",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::RestartTrack]),
+                context: None,
+                description: "Replay current track from start",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::PlayRandom]),
+                context: None,
+                description: "Surprise me: play a random track from the list",
             },
             Keybinding {
-                keys: "r",
-                description: "Toggle repeat",
+                keys: Keys::Actions(&[ActionKind::SleepTimer]),
+                context: None,
+                description: "Sleep timer: pause after N minutes",
             },
             Keybinding {
-                keys: "s",
+                keys: Keys::Actions(&[ActionKind::ToggleCrossfade]),
+                context: None,
+                description: "Toggle crossfade (not between different stream formats)",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::CycleRepeat]),
+                context: None,
+                description: "Cycle repeat: off, all, one",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::ToggleShuffle]),
+                context: None,
                 description: "Toggle shuffle",
             },
             Keybinding {
-                keys: "1",
-                description: "Toggle single mode",
+                keys: Keys::Actions(&[ActionKind::StopAfterTrack]),
+                context: None,
+                description: "Toggle stop after current track",
             },
         ],
     },
@@ -67,12 +149,19 @@ pub const KEYBINDING_CATEGORIES: &[KeybindingCategory] = &[
         name: "Volume & Seek",
         bindings: &[
             Keybinding {
-                keys: "+/-",
+                keys: Keys::Actions(&[ActionKind::VolumeUp, ActionKind::VolumeDown]),
+                context: None,
                 description: "Volume up/down",
             },
             Keybinding {
-                keys: "[/] or </> or ,/.",
-                description: "Seek backward/forward",
+                keys: Keys::Actions(&[ActionKind::SeekBackward, ActionKind::SeekForward]),
+                context: None,
+                description: "Seek back/forward 5s",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::SeekBackwardLong, ActionKind::SeekForwardLong]),
+                context: None,
+                description: "Seek back/forward 30s",
             },
         ],
     },
@@ -80,60 +169,88 @@ pub const KEYBINDING_CATEGORIES: &[KeybindingCategory] = &[
         name: "Queue",
         bindings: &[
             Keybinding {
-                keys: "w",
+                keys: Keys::Actions(&[ActionKind::ToggleQueue]),
+                context: None,
                 description: "Toggle queue panel",
             },
             Keybinding {
-                keys: "y",
+                keys: Keys::Actions(&[ActionKind::AddToQueue]),
+                context: None,
                 description: "Add to queue (yank)",
             },
             Keybinding {
-                keys: "Y",
-                description: "Add all to queue",
+                keys: Keys::Actions(&[ActionKind::AddAllToQueue]),
+                context: None,
+                description: "Add all to queue (album: whole album, artist: discography)",
             },
             Keybinding {
-                keys: "d",
+                keys: Keys::Actions(&[ActionKind::RemoveSelected]),
+                context: None,
                 description: "Remove from queue",
             },
             Keybinding {
-                keys: "D",
+                keys: Keys::Actions(&[ActionKind::ClearQueue]),
+                context: None,
                 description: "Clear entire queue",
             },
             Keybinding {
-                keys: "J/K",
+                keys: Keys::Actions(&[ActionKind::MoveQueueDown, ActionKind::MoveQueueUp]),
+                context: None,
                 description: "Move track down/up in queue",
             },
             Keybinding {
-                keys: "Enter/p",
+                keys: Keys::Actions(&[ActionKind::QueuePrevious]),
+                context: None,
+                description: "Play previously played track next",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::Sort]),
+                context: Some("queue shown"),
+                description: "Sort queue (cycles artist/album/title/duration)",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::Activate, ActionKind::PlaySelected]),
+                context: None,
                 description: "Play selected",
             },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::PlaySelected]),
+                context: Some("nothing selected"),
+                description: "Play/pause current track",
+            },
         ],
     },
     KeybindingCategory {
         name: "Views",
         bindings: &[
             Keybinding {
-                keys: "b",
+                keys: Keys::Actions(&[ActionKind::Browse]),
+                context: None,
                 description: "Browse playlists",
             },
             Keybinding {
-                keys: "/",
+                keys: Keys::Actions(&[ActionKind::Search]),
+                context: None,
                 description: "Search",
             },
             Keybinding {
-                keys: "L",
+                keys: Keys::Actions(&[ActionKind::Library]),
+                context: None,
                 description: "Library/Favorites",
             },
             Keybinding {
-                keys: "W",
+                keys: Keys::Actions(&[ActionKind::Downloads]),
+                context: None,
                 description: "Downloads",
             },
             Keybinding {
-                keys: "v",
+                keys: Keys::Actions(&[ActionKind::ViewDetail]),
+                context: None,
                 description: "View artist/album detail",
             },
             Keybinding {
-                keys: "Space+v",
+                keys: Keys::Actions(&[ActionKind::ToggleVisualizer]),
+                context: None,
                 description: "Toggle visualizer",
             },
         ],
@@ -142,11 +259,23 @@ pub const KEYBINDING_CATEGORIES: &[KeybindingCategory] = &[
         name: "Favorites",
         bindings: &[
             Keybinding {
-                keys: "f",
-                description: "Add/remove favorite",
+                keys: Keys::Actions(&[ActionKind::Favorite]),
+                context: None,
+                description: "Favorite track/album/artist (Library: remove)",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::FavoriteAlbum]),
+                context: None,
+                description: "Favorite selected/playing track's album",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::LoveTrack]),
+                context: None,
+                description: "Love (favorite) the playing track",
             },
             Keybinding {
-                keys: "r (Library)",
+                keys: Keys::Actions(&[ActionKind::RefreshFavorites]),
+                context: Some("Library"),
                 description: "Refresh favorites",
             },
         ],
@@ -155,32 +284,43 @@ pub const KEYBINDING_CATEGORIES: &[KeybindingCategory] = &[
         name: "Radio",
         bindings: &[
             Keybinding {
-                keys: "R",
+                keys: Keys::Actions(&[ActionKind::ToggleRadio]),
+                context: None,
                 description: "Toggle radio mode",
             },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::MyStation]),
+                context: None,
+                description: "Toggle My Station (radio from favorites)",
+            },
         ],
     },
     KeybindingCategory {
         name: "Downloads",
         bindings: &[
             Keybinding {
-                keys: "O",
+                keys: Keys::Actions(&[ActionKind::Download]),
+                context: None,
                 description: "Download track",
             },
             Keybinding {
-                keys: "S",
-                description: "Sync playlist",
+                keys: Keys::Actions(&[ActionKind::DownloadAll]),
+                context: None,
+                description: "Sync playlist / download album",
             },
             Keybinding {
-                keys: "o",
+                keys: Keys::Actions(&[ActionKind::ToggleOffline]),
+                context: None,
                 description: "Toggle offline mode",
             },
             Keybinding {
-                keys: "x",
+                keys: Keys::Actions(&[ActionKind::DeleteDownload]),
+                context: None,
                 description: "Delete download",
             },
             Keybinding {
-                keys: "R (Downloads)",
+                keys: Keys::Actions(&[ActionKind::RetryDownload]),
+                context: Some("Downloads"),
                 description: "Retry download",
             },
         ],
@@ -189,40 +329,103 @@ pub const KEYBINDING_CATEGORIES: &[KeybindingCategory] = &[
         name: "Playlists",
         bindings: &[
             Keybinding {
-                keys: "C",
+                keys: Keys::Actions(&[ActionKind::CreatePlaylist]),
+                context: None,
                 description: "Create new playlist",
             },
             Keybinding {
-                keys: "a",
+                keys: Keys::Actions(&[ActionKind::AddToPlaylist]),
+                context: None,
                 description: "Add track to playlist",
             },
             Keybinding {
-                keys: "e (Browse)",
+                keys: Keys::Actions(&[ActionKind::RenamePlaylist]),
+                context: Some("Browse"),
                 description: "Rename playlist",
             },
             Keybinding {
-                keys: "X (Browse)",
+                keys: Keys::Actions(&[ActionKind::DuplicatePlaylist]),
+                context: Some("Browse"),
+                description: "Duplicate playlist",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::DeletePlaylist]),
+                context: Some("Browse"),
                 description: "Delete playlist / remove track",
             },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::RemoveFromSourcePlaylist]),
+                context: None,
+                description: "Remove playing track from its playlist, skip",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::Sort]),
+                context: Some("Browse"),
+                description: "Cycle track sort (kept per playlist)",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::Sort]),
+                context: Some("Artist"),
+                description: "Filter albums by type",
+            },
         ],
     },
     KeybindingCategory {
         name: "System",
         bindings: &[
             Keybinding {
-                keys: "Space+q",
+                keys: Keys::Actions(&[ActionKind::Quit]),
+                context: None,
                 description: "Quit",
             },
             Keybinding {
-                keys: "Space+c",
+                keys: Keys::Actions(&[ActionKind::CopyQueue]),
+                context: None,
+                description: "Copy queue as a text list",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::ReloadPlaylists]),
+                context: None,
+                description: "Reload playlist list",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::ToggleDebug]),
+                context: None,
+                description: "Toggle debug log",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::FocusDebug]),
+                context: None,
+                description: "Focus debug log (j/k scroll, Esc unfocus)",
+            },
+            Keybinding {
+                keys: Keys::Fixed("PgUp/PgDn"),
+                context: None,
+                description: "Scroll debug log",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::ClearDebugLog]),
+                context: None,
                 description: "Clear debug log",
             },
             Keybinding {
-                keys: "Space+e",
+                keys: Keys::Actions(&[ActionKind::ExportDebugLog]),
+                context: None,
                 description: "Export debug log",
             },
             Keybinding {
-                keys: "?",
+                keys: Keys::Actions(&[ActionKind::ReloadConfig]),
+                context: None,
+                description: "Reload config",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::CommandPalette]),
+                context: None,
+                description: "Command palette (run any action by name)",
+            },
+            Keybinding {
+                keys: Keys::Actions(&[ActionKind::Help]),
+                context: None,
                 description: "Show this help",
             },
         ],
@@ -239,3 +442,33 @@ pub fn help_content_height() -> usize {
     }
     count
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keymap::KeyList;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_hints_use_bound_keys() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert("add_to_queue".to_string(), KeyList::One("q".to_string()));
+        overrides.insert("sort".to_string(), KeyList::Many(vec![]));
+        let keymap = Keymap::new(&overrides);
+
+        assert_eq!(
+            hints(&keymap, &[(ActionKind::PlaySelected, "play"), (ActionKind::AddToQueue, "queue"), (ActionKind::Sort, "sort")]),
+            "[p: play | q: queue]"
+        );
+    }
+
+    #[test]
+    fn test_help_keys_follow_keymap() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert("move_left".to_string(), KeyList::One("d".to_string()));
+        let keymap = Keymap::new(&overrides);
+        let binding = &KEYBINDING_CATEGORIES[0].bindings[0];
+
+        assert_eq!(binding.keys_label(&keymap), "d/j/k/l");
+    }
+}
@@ -3,13 +3,16 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
 use crate::history_db::HistoryEntry;
 use crate::service::{Album, Artist, ServiceType, Track};
-use super::styles::{format_track_with_indicator, is_track_playing, service_badge};
+use super::keybindings::hints;
+use crate::app::ActionKind;
+use crate::keymap::Keymap;
+use super::styles::{availability_badge, format_track_with_indicator, is_track_playing, list_state, ListScroll, service_badge};
 use super::theme::Theme;
 
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
@@ -33,6 +36,9 @@ pub struct LibraryViewState<'a> {
     pub selected_history_entry: usize,
     pub current_track_id: Option<&'a str>,
     pub service_filter: Option<ServiceType>,
+    /// `ui.scroll_style` / `ui.scrolloff`
+    pub scroll: ListScroll,
+    pub keymap: &'a Keymap,
 }
 
 fn filter_indicator(filter: Option<ServiceType>) -> String {
@@ -77,7 +83,7 @@ pub fn render_library_view(
         Span::styled(
             " Tracks ",
             if state.library_tab == LibraryTab::Tracks {
-                Style::default().fg(theme.warning()).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.selection()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             },
@@ -86,7 +92,7 @@ pub fn render_library_view(
         Span::styled(
             " Albums ",
             if state.library_tab == LibraryTab::Albums {
-                Style::default().fg(theme.warning()).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.selection()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             },
@@ -95,7 +101,7 @@ pub fn render_library_view(
         Span::styled(
             " Artists ",
             if state.library_tab == LibraryTab::Artists {
-                Style::default().fg(theme.warning()).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.selection()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             },
@@ -104,7 +110,7 @@ pub fn render_library_view(
         Span::styled(
             " History ",
             if state.library_tab == LibraryTab::History {
-                Style::default().fg(theme.warning()).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.selection()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             },
@@ -142,12 +148,13 @@ pub fn render_library_view(
                     let style = theme.track_style(is_selected, is_playing);
 
                     let display = format!(
-                        "{} {} - {} ({}:{:02})",
+                        "{} {} - {} ({}:{:02}){}",
                         service_badge(track.service),
                         track.artist,
                         track.title,
                         track.duration_seconds / 60,
-                        track.duration_seconds % 60
+                        track.duration_seconds % 60,
+                        availability_badge(track)
                     );
                     let display = format_track_with_indicator(display, is_playing);
                     ListItem::new(display).style(style)
@@ -158,7 +165,11 @@ pub fn render_library_view(
             let list = List::new(items)
                 .block(
                     Block::default()
-                        .title(format!("Favorite Tracks ({}) [p: play | y: queue]", count))
+                        .title(format!(
+                            "Favorite Tracks ({}) {}",
+                            count,
+                            hints(state.keymap, &[(ActionKind::PlaySelected, "play"), (ActionKind::AddToQueue, "queue")])
+                        ))
                         .borders(Borders::ALL)
                         .border_style(Style::default().fg(theme.primary())),
                 )
@@ -167,7 +178,7 @@ pub fn render_library_view(
             f.render_stateful_widget(
                 list,
                 content_area,
-                &mut ListState::default().with_selected(Some(state.selected_favorite_track)),
+                &mut list_state(Some(state.selected_favorite_track), count, content_area, state.scroll),
             );
         }
         LibraryTab::Albums => {
@@ -189,7 +200,7 @@ pub fn render_library_view(
             let list = List::new(items)
                 .block(
                     Block::default()
-                        .title(format!("Favorite Albums ({}) [Enter: add to queue]", count))
+                        .title(format!("Favorite Albums ({}) {}", count, hints(state.keymap, &[(ActionKind::Activate, "add to queue"), (ActionKind::Favorite, "remove")])))
                         .borders(Borders::ALL)
                         .border_style(Style::default().fg(theme.primary())),
                 )
@@ -198,7 +209,7 @@ pub fn render_library_view(
             f.render_stateful_widget(
                 list,
                 content_area,
-                &mut ListState::default().with_selected(Some(state.selected_favorite_album)),
+                &mut list_state(Some(state.selected_favorite_album), count, content_area, state.scroll),
             );
         }
         LibraryTab::Artists => {
@@ -219,7 +230,7 @@ pub fn render_library_view(
             let list = List::new(items)
                 .block(
                     Block::default()
-                        .title(format!("Favorite Artists ({}) [Enter: add top tracks]", count))
+                        .title(format!("Favorite Artists ({}) {}", count, hints(state.keymap, &[(ActionKind::Activate, "add top tracks"), (ActionKind::Favorite, "remove")])))
                         .borders(Borders::ALL)
                         .border_style(Style::default().fg(theme.primary())),
                 )
@@ -228,7 +239,7 @@ pub fn render_library_view(
             f.render_stateful_widget(
                 list,
                 content_area,
-                &mut ListState::default().with_selected(Some(state.selected_favorite_artist)),
+                &mut list_state(Some(state.selected_favorite_artist), count, content_area, state.scroll),
             );
         }
         LibraryTab::History => {
@@ -252,7 +263,11 @@ pub fn render_library_view(
             let list = List::new(items)
                 .block(
                     Block::default()
-                        .title(format!("Playback History ({}) [p: play | y: queue | f: favorite]", count))
+                        .title(format!(
+                            "Playback History ({}) {}",
+                            count,
+                            hints(state.keymap, &[(ActionKind::PlaySelected, "play"), (ActionKind::AddToQueue, "queue"), (ActionKind::Favorite, "favorite")])
+                        ))
                         .borders(Borders::ALL)
                         .border_style(Style::default().fg(theme.primary())),
                 )
@@ -261,7 +276,7 @@ pub fn render_library_view(
             f.render_stateful_widget(
                 list,
                 content_area,
-                &mut ListState::default().with_selected(Some(state.selected_history_entry)),
+                &mut list_state(Some(state.selected_history_entry), count, content_area, state.scroll),
             );
         }
     }
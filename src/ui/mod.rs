@@ -15,7 +15,8 @@ pub mod dialog;
 pub mod theme;
 
 pub use now_playing::render_now_playing;
-pub use queue::render_queue;
+pub use visualizer::render_screensaver;
+pub use queue::{queue_as_text, render_queue};
 pub use browse::render_browse_view;
 pub use search::{render_search_view, render_search_preview, SearchTab, SearchPreviewState};
 pub use downloads::render_downloads_view;
@@ -9,25 +9,39 @@ use ratatui::{
 use crate::album_art::AlbumArtCache;
 use crate::app::state::RadioSeed;
 use crate::cava::CavaVisualizer;
-use crate::mpd::CurrentSong;
+use crate::mpd::{CurrentSong, RepeatMode};
 use crate::service::{CoverArt, Track};
 
 use super::styles::service_badge;
 use super::theme::Theme;
 
+/// Shown in place of the total time when the track has no known length
+const LIVE_LABEL: &str = "LIVE";
+
+/// Cells lit in the progress bar's pulse for tracks of unknown length
+const PULSE_WIDTH: usize = 4;
+
+/// Milliseconds of playback per cell the pulse moves
+const PULSE_STEP_MS: u128 = 250;
+
 pub struct NowPlayingState<'a> {
     pub current_track: Option<&'a Track>,
     pub current_song: Option<&'a CurrentSong>,
     pub is_playing: bool,
     pub volume: u8,
-    pub repeat_mode: bool,
+    pub repeat_mode: RepeatMode,
     pub random_mode: bool,
-    pub single_mode: bool,
     pub radio_seed: Option<RadioSeed>,
     pub local_queue_len: usize,
     pub album_art_cache: &'a mut AlbumArtCache,
     pub visualizer: Option<&'a CavaVisualizer>,
+    /// `ui.visualizer_on_pause`
+    pub visualizer_on_pause: &'a str,
     pub video_mode: bool,
+    /// Marquee step for long title/artist/album text, None when `ui.marquee` is off
+    pub marquee_tick: Option<usize>,
+    /// From MusicBrainz, shown after the album
+    pub release_year: Option<u16>,
 }
 
 pub fn render_now_playing(
@@ -122,57 +136,94 @@ pub fn render_now_playing(
             .map(|t| format!("{} ", service_badge(t.service)))
             .unwrap_or_default();
 
+        // Room left on each line after the borders and the line's label
+        let text_width = info_area.width.saturating_sub(2) as usize;
+        let fit = |text: String, label_width: usize| match state.marquee_tick {
+            Some(tick) => marquee(&text, text_width.saturating_sub(label_width), tick),
+            None => text,
+        };
+
         lines.push(Line::from(vec![
             Span::styled(format!(" {} ", status_icon), Style::default().fg(status_color).add_modifier(Modifier::BOLD)),
-            Span::styled(format!("{}{}", service_prefix, &song.title), Style::default().fg(theme.text()).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                fit(format!("{}{}", service_prefix, &song.title), status_icon.len() + 2),
+                Style::default().fg(theme.text()).add_modifier(Modifier::BOLD),
+            ),
         ]));
 
         lines.push(Line::from(vec![
             Span::raw("   Artist: "),
-            Span::styled(&song.artist, Style::default().fg(theme.primary())),
+            Span::styled(fit(song.artist.clone(), 11), Style::default().fg(theme.primary())),
         ]));
 
+        let album = match state.release_year {
+            Some(year) => format!("{} ({})", song.album, year),
+            None => song.album.clone(),
+        };
         lines.push(Line::from(vec![
             Span::raw("   Album:  "),
-            Span::styled(&song.album, Style::default().fg(theme.secondary())),
+            Span::styled(fit(album, 11), Style::default().fg(theme.secondary())),
         ]));
 
         lines.push(Line::from(""));
 
         let elapsed_secs = song.elapsed.as_secs();
         let total_secs = song.duration.as_secs();
-        let progress = if total_secs > 0 {
-            (elapsed_secs as f64 / total_secs as f64).min(1.0)
+        let elapsed_label = format!("{:02}:{:02}", elapsed_secs / 60, elapsed_secs % 60);
+        // "   " + elapsed + " " before the bar
+        let bar_offset = 3 + elapsed_label.len() + 1;
+
+        if total_secs == 0 {
+            // Live streams (and some YouTube items) have no length to show
+            // progress against: pulse instead, and nothing to seek by clicking
+            let bar_width = text_width.saturating_sub(bar_offset + 1 + LIVE_LABEL.len()).max(10);
+            let pulse_width = PULSE_WIDTH.min(bar_width);
+            let step = (song.elapsed.as_millis() / PULSE_STEP_MS) as usize;
+            let before = pulse_offset(step, bar_width - pulse_width);
+
+            lines.push(Line::from(vec![
+                Span::raw("   "),
+                Span::styled(elapsed_label, Style::default().fg(theme.text_muted())),
+                Span::raw(" "),
+                Span::styled("-".repeat(before), Style::default().fg(theme.text_disabled())),
+                Span::styled("=".repeat(pulse_width), Style::default().fg(theme.primary())),
+                Span::styled("-".repeat(bar_width - pulse_width - before), Style::default().fg(theme.text_disabled())),
+                Span::raw(" "),
+                Span::styled(LIVE_LABEL, Style::default().fg(theme.error()).add_modifier(Modifier::BOLD)),
+            ]));
         } else {
-            0.0
-        };
-
-        let bar_width = info_area.width.saturating_sub(20).max(40) as usize;
-        let filled = (progress * bar_width as f64) as usize;
-        let empty = bar_width.saturating_sub(filled);
-
-        let filled_str = "=".repeat(filled);
-        let empty_str = "-".repeat(empty);
-
-        let progress_bar_x = info_area.x + 10;
-        let progress_bar_y = info_area.y + 5;
-        progress_bar_area = Some(Rect::new(
-            progress_bar_x,
-            progress_bar_y,
-            bar_width as u16,
-            1,
-        ));
-
-        lines.push(Line::from(vec![
-            Span::raw("   "),
-            Span::styled(format!("{:02}:{:02}", elapsed_secs / 60, elapsed_secs % 60), Style::default().fg(theme.text_muted())),
-            Span::raw(" "),
-            Span::styled(filled_str, Style::default().fg(theme.primary())),
-            Span::styled(empty_str, Style::default().fg(theme.text_disabled())),
-            Span::raw(" "),
-            Span::styled(format!("{:02}:{:02}", total_secs / 60, total_secs % 60), Style::default().fg(theme.text_muted())),
-            Span::raw(format!(" ({}%)", (progress * 100.0) as u8)),
-        ]));
+            let progress = (elapsed_secs as f64 / total_secs as f64).min(1.0);
+            let total_label = format!("{:02}:{:02}", total_secs / 60, total_secs % 60);
+            let percent_label = format!(" ({}%)", (progress * 100.0) as u8);
+
+            // " " + total + percent after the bar
+            let bar_width = text_width.saturating_sub(bar_offset + 1 + total_label.len() + percent_label.len()).max(10);
+            let filled = (progress * bar_width as f64) as usize;
+            let empty = bar_width.saturating_sub(filled);
+
+            let filled_str = "=".repeat(filled);
+            let empty_str = "-".repeat(empty);
+
+            // Where the bar lands on screen, inside the block's border, so a
+            // click can be turned back into a position in the track
+            progress_bar_area = Some(Rect::new(
+                info_area.x + 1 + bar_offset as u16,
+                info_area.y + 1 + lines.len() as u16,
+                bar_width as u16,
+                1,
+            ).intersection(info_area));
+
+            lines.push(Line::from(vec![
+                Span::raw("   "),
+                Span::styled(elapsed_label, Style::default().fg(theme.text_muted())),
+                Span::raw(" "),
+                Span::styled(filled_str, Style::default().fg(theme.primary())),
+                Span::styled(empty_str, Style::default().fg(theme.text_disabled())),
+                Span::raw(" "),
+                Span::styled(total_label, Style::default().fg(theme.text_muted())),
+                Span::raw(percent_label),
+            ]));
+        }
 
         let queue_info = if state.local_queue_len > 1 {
             format!("{} tracks in queue", state.local_queue_len)
@@ -186,11 +237,8 @@ pub fn render_now_playing(
         if state.video_mode {
             modes.push("VIDEO");
         }
-        if state.repeat_mode {
-            modes.push("repeat");
-        }
-        if state.single_mode {
-            modes.push("single");
+        if let Some(repeat) = state.repeat_mode.label() {
+            modes.push(repeat);
         }
         if state.random_mode {
             modes.push("shuffle");
@@ -200,6 +248,7 @@ pub fn render_now_playing(
             Some(RadioSeed::Playlist(_)) => modes.push("mix"),
             Some(RadioSeed::Artist(_)) => modes.push("artist radio"),
             Some(RadioSeed::Album(_)) => modes.push("album radio"),
+            Some(RadioSeed::Favorites) => modes.push("my station"),
             None => {}
         }
         let modes_str = if modes.is_empty() {
@@ -259,7 +308,7 @@ pub fn render_now_playing(
 
     // Render visualizer if present
     if let (Some(viz), Some(viz_area)) = (state.visualizer, visualizer_area) {
-        let bars = viz.draw_bars();
+        let bars = viz.draw_bars(state.is_playing, state.visualizer_on_pause);
 
         let viz_lines = vec![
             Line::from(vec![
@@ -292,3 +341,55 @@ pub fn render_now_playing(
 
     progress_bar_area
 }
+
+/// Window of `text` that scrolls left by one character per `tick`, wrapping
+/// around with a gap. Text that already fits is returned unchanged.
+fn marquee(text: &str, width: usize, tick: usize) -> String {
+    const GAP: &str = "   ";
+    let len = text.chars().count();
+    if len <= width || width == 0 {
+        return text.to_string();
+    }
+    let cycle = len + GAP.len();
+    text.chars()
+        .chain(GAP.chars())
+        .chain(text.chars())
+        .skip(tick % cycle)
+        .take(width)
+        .collect()
+}
+
+/// Cells before the pulse at `step`, bouncing between 0 and `travel`
+fn pulse_offset(step: usize, travel: usize) -> usize {
+    if travel == 0 {
+        return 0;
+    }
+    let phase = step % (2 * travel);
+    if phase <= travel {
+        phase
+    } else {
+        2 * travel - phase
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{marquee, pulse_offset};
+
+    #[test]
+    fn test_marquee_scrolls_only_long_text() {
+        assert_eq!(marquee("short", 10, 3), "short");
+        assert_eq!(marquee("abcdef", 4, 0), "abcd");
+        assert_eq!(marquee("abcdef", 4, 2), "cdef");
+        assert_eq!(marquee("abcdef", 4, 4), "ef  ");
+        // Wraps back to the start after text plus gap
+        assert_eq!(marquee("abcdef", 4, 9), "abcd");
+    }
+
+    #[test]
+    fn test_pulse_offset_bounces() {
+        let offsets: Vec<usize> = (0..8).map(|step| pulse_offset(step, 3)).collect();
+        assert_eq!(offsets, [0, 1, 2, 3, 2, 1, 0, 1]);
+        assert_eq!(pulse_offset(5, 0), 0);
+    }
+}
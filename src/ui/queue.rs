@@ -1,29 +1,40 @@
 use ratatui::{
     layout::{Alignment, Rect},
     style::Style,
-    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
 use crate::service::Track;
-use super::styles::{format_track_with_indicator, is_track_playing, service_badge};
+use super::keybindings::hints;
+use crate::app::ActionKind;
+use crate::keymap::Keymap;
+use super::styles::{format_track_with_indicator, is_track_playing, list_state, ListScroll, service_badge};
 use super::theme::Theme;
 
-pub fn render_queue(
-    f: &mut Frame,
-    local_queue: &[Track],
-    selected_queue_item: usize,
-    current_track_id: Option<&str>,
-    area: Rect,
-    theme: &Theme,
-) -> Rect {
+pub struct QueueViewState<'a> {
+    pub local_queue: &'a [Track],
+    pub selected_queue_item: usize,
+    pub current_track_id: Option<&'a str>,
+    /// MPD queue position playing and seconds into it, for "in m:ss" times
+    pub playing: Option<(usize, u64)>,
+    /// `ui.scroll_style` / `ui.scrolloff`
+    pub scroll: ListScroll,
+    pub keymap: &'a Keymap,
+}
+
+pub fn render_queue(f: &mut Frame, state: &QueueViewState, area: Rect, theme: &Theme) -> Rect {
+    let local_queue = state.local_queue;
     if local_queue.is_empty() {
         let empty_msg = Paragraph::new("Queue is empty\n\nPress 'y' to add selected track\nPress 'Y' to add all tracks")
             .style(Style::default().fg(theme.text_disabled()))
             .alignment(Alignment::Center)
             .block(
                 Block::default()
-                    .title("Queue (0 tracks) [y: add | Y: add all | w: hide]")
+                    .title(format!(
+                        "Queue (0 tracks) {}",
+                        hints(state.keymap, &[(ActionKind::AddToQueue, "add"), (ActionKind::AddAllToQueue, "add all"), (ActionKind::ToggleQueue, "hide")])
+                    ))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(Style::default().fg(theme.primary())),
@@ -33,15 +44,16 @@ pub fn render_queue(
     }
 
     let mut items = vec![];
+    let starts_in = time_until_play(local_queue, state.playing);
 
     for (i, track) in local_queue.iter().enumerate() {
-        let is_selected = i == selected_queue_item;
-        let is_playing = is_track_playing(&track.id, current_track_id);
+        let is_selected = i == state.selected_queue_item;
+        let is_playing = is_track_playing(&track.id, state.current_track_id);
         let style = theme.track_style(is_selected, is_playing);
 
         let duration_str = format!("{}:{:02}", track.duration_seconds / 60, track.duration_seconds % 60);
 
-        let content = format!(
+        let mut content = format!(
             "{:2}. {} {} - {} [{}]",
             i + 1,
             service_badge(track.service),
@@ -49,6 +61,9 @@ pub fn render_queue(
             track.title,
             duration_str
         );
+        if let Some(secs) = starts_in[i] {
+            content.push_str(&format!(" in {}", format_wait(secs)));
+        }
 
         let display = format_track_with_indicator(content, is_playing);
         items.push(ListItem::new(display).style(style));
@@ -57,7 +72,17 @@ pub fn render_queue(
     let queue_list = List::new(items)
         .block(
             Block::default()
-                .title(format!("Queue ({} tracks) [p/Enter: play | y: add | d: remove | D: clear]", local_queue.len()))
+                .title(format!(
+                    "Queue ({} tracks) {}",
+                    local_queue.len(),
+                    hints(state.keymap, &[
+                        (ActionKind::PlaySelected, "play"),
+                        (ActionKind::AddToQueue, "add"),
+                        (ActionKind::RemoveSelected, "remove"),
+                        (ActionKind::ClearQueue, "clear"),
+                        (ActionKind::Sort, "sort"),
+                    ])
+                ))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(theme.primary())),
@@ -68,8 +93,95 @@ pub fn render_queue(
     f.render_stateful_widget(
         queue_list,
         area,
-        &mut ListState::default().with_selected(Some(selected_queue_item)),
+        &mut list_state(Some(state.selected_queue_item), local_queue.len(), area, state.scroll),
     );
 
     area
 }
+
+/// Seconds until each queued track starts: what's left of the playing one
+/// plus the durations in between. None for the playing track, tracks
+/// already played, and everything when nothing is playing.
+fn time_until_play(queue: &[Track], playing: Option<(usize, u64)>) -> Vec<Option<u64>> {
+    let mut starts_in = vec![None; queue.len()];
+    let Some((position, elapsed)) = playing.filter(|(pos, _)| *pos < queue.len()) else {
+        return starts_in;
+    };
+
+    let mut wait = (queue[position].duration_seconds as u64).saturating_sub(elapsed);
+    for (i, track) in queue.iter().enumerate().skip(position + 1) {
+        starts_in[i] = Some(wait);
+        wait += track.duration_seconds as u64;
+    }
+    starts_in
+}
+
+/// The queue as a numbered "Artist – Title" list with its total length, for
+/// pasting into chats or notes
+pub fn queue_as_text(queue: &[Track]) -> String {
+    let mut text = String::new();
+    for (i, track) in queue.iter().enumerate() {
+        text.push_str(&format!("{}. {} – {}\n", i + 1, track.artist, track.title));
+    }
+    let total: u64 = queue.iter().map(|t| t.duration_seconds as u64).sum();
+    text.push_str(&format!("\n{} tracks, {}\n", queue.len(), format_wait(total)));
+    text
+}
+
+/// "m:ss", or "h:mm:ss" from an hour up
+fn format_wait(secs: u64) -> String {
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::{CoverArt, ServiceType};
+
+    fn track(duration_seconds: u32) -> Track {
+        Track {
+            id: String::new(),
+            title: String::new(),
+            artist: String::new(),
+            album: String::new(),
+            album_id: None,
+            duration_seconds,
+            cover_art: CoverArt::None,
+            service: ServiceType::Tidal,
+            available: true,
+        }
+    }
+
+    #[test]
+    fn test_time_until_play() {
+        let queue = [track(100), track(200), track(300), track(50)];
+        assert_eq!(time_until_play(&queue, None), vec![None; 4]);
+        assert_eq!(
+            time_until_play(&queue, Some((1, 30))),
+            vec![None, None, Some(170), Some(470)]
+        );
+        assert_eq!(time_until_play(&queue, Some((9, 0))), vec![None; 4]);
+
+        assert_eq!(format_wait(750), "12:30");
+        assert_eq!(format_wait(3725), "1:02:05");
+    }
+
+    #[test]
+    fn test_queue_as_text() {
+        let mut first = track(200);
+        first.artist = "Low".to_string();
+        first.title = "Words".to_string();
+        let mut second = track(190);
+        second.artist = "Duster".to_string();
+        second.title = "Echo, Bravo".to_string();
+
+        assert_eq!(
+            queue_as_text(&[first, second]),
+            "1. Low – Words\n2. Duster – Echo, Bravo\n\n2 tracks, 6:30\n"
+        );
+    }
+}
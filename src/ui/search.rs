@@ -9,7 +9,7 @@ use ratatui_image::StatefulImage;
 
 use crate::album_art::AlbumArtCache;
 use crate::service::{SearchResults, ServiceType};
-use super::styles::{format_track_with_indicator, is_track_playing, service_badge};
+use super::styles::{availability_badge, format_track_with_indicator, is_track_playing, list_state, ListScroll, service_badge};
 use super::theme::Theme;
 
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
@@ -20,6 +20,17 @@ pub enum SearchTab {
     Artists,
 }
 
+impl SearchTab {
+    /// Parse `search.default_tab` ("tracks", "albums", "artists")
+    pub fn from_config(name: &str) -> Self {
+        match name {
+            "albums" => SearchTab::Albums,
+            "artists" => SearchTab::Artists,
+            _ => SearchTab::Tracks,
+        }
+    }
+}
+
 pub struct SearchViewState<'a> {
     pub search_query: &'a str,
     pub search_results: Option<&'a SearchResults>,
@@ -28,6 +39,8 @@ pub struct SearchViewState<'a> {
     pub selected_search_album: usize,
     pub selected_search_artist: usize,
     pub is_searching: bool,
+    /// A search request is in flight
+    pub search_pending: bool,
     pub current_track_id: Option<&'a str>,
     /// Filter query for fuzzy filtering results (Ctrl+F)
     pub filter_query: &'a str,
@@ -45,6 +58,12 @@ pub struct SearchViewState<'a> {
     pub has_more: bool,
     /// Service filter (None = all, Some = specific service)
     pub service_filter: Option<ServiceType>,
+    /// Tab cycles the service filter ('`' cycles result tabs)
+    pub tab_cycles_filter: bool,
+    /// Results are trending tracks shown for an empty query
+    pub trending: bool,
+    /// `ui.scroll_style` / `ui.scrolloff`
+    pub scroll: ListScroll,
 }
 
 /// State for the standalone search preview panel
@@ -85,13 +104,22 @@ pub fn render_search_view(
     // Search input box with enhanced hints
     let title = if state.is_searching {
         "Search (Enter: search | Up/Down: history | Esc: cancel)"
+    } else if state.search_pending {
+        "Searching... (Esc: cancel)"
+    } else if state.tab_cycles_filter {
+        "Search (/: search | Ctrl+F: filter | Tab: cycle service)"
     } else {
         "Search (/: search | Ctrl+F: filter | Tab: cycle results)"
     };
+    let list_hints = if state.tab_cycles_filter {
+        "[Tab/1/2/3: service | `: cycle | Ctrl+F: filter]"
+    } else {
+        "[Tab: cycle | `/1/2/3: service | Ctrl+F: filter]"
+    };
 
     let search_input = Paragraph::new(state.search_query)
         .style(if state.is_searching {
-            Style::default().fg(theme.warning())
+            Style::default().fg(theme.selection())
         } else {
             Style::default()
         })
@@ -100,7 +128,7 @@ pub fn render_search_view(
                 .borders(Borders::ALL)
                 .title(title)
                 .border_style(if state.is_searching {
-                    Style::default().fg(theme.warning())
+                    Style::default().fg(theme.selection())
                 } else {
                     Style::default().fg(theme.border_normal())
                 }),
@@ -157,12 +185,13 @@ pub fn render_search_view(
                         let style = theme.track_style(is_selected, is_playing);
 
                         let display = format!(
-                            "{} {} - {} ({}:{:02})",
+                            "{} {} - {} ({}:{:02}){}",
                             service_badge(track.service),
                             track.artist,
                             track.title,
                             track.duration_seconds / 60,
-                            track.duration_seconds % 60
+                            track.duration_seconds % 60,
+                            availability_badge(track)
                         );
                         let display = format_track_with_indicator(display, is_playing);
                         ListItem::new(display).style(style)
@@ -170,10 +199,12 @@ pub fn render_search_view(
                     .collect();
 
                 let title = format!(
-                    "Tracks ({}){}{} [Tab: cycle | 1/2/3: service | Ctrl+F: filter]",
+                    "{} ({}){}{} {}",
+                    if state.trending { "Trending" } else { "Tracks" },
                     filtered_tracks.len(),
                     service_indicator,
-                    page_indicator
+                    page_indicator,
+                    list_hints
                 );
 
                 let list = List::new(items)
@@ -188,7 +219,7 @@ pub fn render_search_view(
                 f.render_stateful_widget(
                     list,
                     results_area,
-                    &mut ListState::default().with_selected(Some(state.selected_search_track)),
+                    &mut list_state(Some(state.selected_search_track), filtered_tracks.len(), results_area, state.scroll),
                 );
             }
             SearchTab::Albums => {
@@ -215,10 +246,11 @@ pub fn render_search_view(
                     .collect();
 
                 let title = format!(
-                    "Albums ({}){}{} [Tab: cycle | 1/2/3: service | Ctrl+F: filter]",
+                    "Albums ({}){}{} {}",
                     filtered_albums.len(),
                     service_indicator,
-                    page_indicator
+                    page_indicator,
+                    list_hints
                 );
 
                 let list = List::new(items)
@@ -233,7 +265,7 @@ pub fn render_search_view(
                 f.render_stateful_widget(
                     list,
                     results_area,
-                    &mut ListState::default().with_selected(Some(state.selected_search_album)),
+                    &mut list_state(Some(state.selected_search_album), filtered_albums.len(), results_area, state.scroll),
                 );
             }
             SearchTab::Artists => {
@@ -260,10 +292,11 @@ pub fn render_search_view(
                     .collect();
 
                 let title = format!(
-                    "Artists ({}){}{} [Tab: cycle | 1/2/3: service | Ctrl+F: filter]",
+                    "Artists ({}){}{} {}",
                     filtered_artists.len(),
                     service_indicator,
-                    page_indicator
+                    page_indicator,
+                    list_hints
                 );
 
                 let list = List::new(items)
@@ -278,7 +311,7 @@ pub fn render_search_view(
                 f.render_stateful_widget(
                     list,
                     results_area,
-                    &mut ListState::default().with_selected(Some(state.selected_search_artist)),
+                    &mut list_state(Some(state.selected_search_artist), filtered_artists.len(), results_area, state.scroll),
                 );
             }
         }
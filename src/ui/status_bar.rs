@@ -6,15 +6,90 @@ use ratatui::{
 };
 
 use super::theme::Theme;
+use crate::app::ActionKind;
+use crate::keymap::{Keymap, Prefix};
 
-pub struct StatusBarState {
+pub struct StatusBarState<'a> {
     pub is_searching: bool,
     pub space_pressed: bool,
     pub pending_key: Option<char>,
     pub status_message: Option<(String, bool)>, // (message, is_error)
     pub backend_name: Option<String>,
+    /// Header text shown as the bar's title when `ui.show_header` is off
+    pub header: Option<String>,
+    /// Running background tasks, oldest first, shown on the right of the border
+    pub tasks: Vec<String>,
+    /// Time left on the sleep timer ("mm:ss"), shown bottom right
+    pub sleep_timer: Option<String>,
+    pub keymap: &'a Keymap,
 }
 
+/// Space commands hinted while Space is held
+const SPACE_HINTS: &[(ActionKind, &str)] = &[
+    (ActionKind::Quit, "quit"),
+    (ActionKind::TogglePlayback, "pause"),
+    (ActionKind::NextTrack, "next"),
+    (ActionKind::PreviousTrack, "prev"),
+    (ActionKind::RestartTrack, "replay"),
+    (ActionKind::PlayRandom, "surprise"),
+    (ActionKind::SleepTimer, "sleep"),
+    (ActionKind::LoveTrack, "love"),
+    (ActionKind::RemoveFromSourcePlaylist, "drop from playlist"),
+    (ActionKind::ToggleVisualizer, "visualizer"),
+    (ActionKind::ToggleDebug, "debug"),
+    (ActionKind::ClearDebugLog, "clear log"),
+    (ActionKind::ExportDebugLog, "export"),
+    (ActionKind::ReloadConfig, "reload config"),
+];
+
+/// g commands hinted after g
+const G_HINTS: &[(ActionKind, &str)] = &[(ActionKind::JumpTop, "top"), (ActionKind::JumpEnd, "end")];
+
+/// `key: label` pairs separated by " | ", keys in bold
+fn hint_spans(hints: Vec<(String, &'static str)>) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    for (i, (key, label)) in hints.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(" | "));
+        }
+        spans.push(Span::styled(key, Style::default().add_modifier(Modifier::BOLD)));
+        spans.push(Span::raw(format!(": {}", label)));
+    }
+    spans
+}
+
+/// Hints for the keys bound to `actions` after `prefix`
+fn prefix_hints(keymap: &Keymap, prefix: Prefix, actions: &[(ActionKind, &'static str)]) -> Vec<Span<'static>> {
+    hint_spans(
+        actions
+            .iter()
+            .filter_map(|&(action, label)| Some((keymap.key_after(prefix, action)?, label)))
+            .collect(),
+    )
+}
+
+/// Main-mode hints; several actions share one hint, like "hjkl: move"
+fn normal_hints(keymap: &Keymap) -> Vec<Span<'static>> {
+    let keys = |actions: &[ActionKind], separator: &str| -> Option<String> {
+        let labels: Option<Vec<&str>> = actions.iter().map(|&action| keymap.label(action)).collect();
+        labels.map(|labels| labels.join(separator))
+    };
+    let hints = [
+        (keys(&[ActionKind::MoveLeft, ActionKind::MoveDown, ActionKind::MoveUp, ActionKind::MoveRight], ""), "move"),
+        (keys(&[ActionKind::VolumeUp, ActionKind::VolumeDown], "/"), "vol"),
+        (keys(&[ActionKind::SeekBackward, ActionKind::SeekForward], "/"), "seek"),
+        (keys(&[ActionKind::CycleRepeat], ""), "repeat"),
+        (keys(&[ActionKind::ToggleShuffle], ""), "shuffle"),
+        (Some("Space".to_string()), "cmd"),
+        (keys(&[ActionKind::CommandPalette], ""), "palette"),
+        (keys(&[ActionKind::Help], ""), "help"),
+    ];
+    hint_spans(hints.into_iter().filter_map(|(keys, label)| Some((keys?, label))).collect())
+}
+
+/// Most tasks named on the border; the rest are counted
+const MAX_TASKS_SHOWN: usize = 2;
+
 pub fn render_status_bar(
     f: &mut Frame,
     state: &StatusBarState,
@@ -44,76 +119,69 @@ pub fn render_status_bar(
             Span::raw(": cancel"),
         ]))
     } else if state.space_pressed {
-        Paragraph::new(Line::from(vec![
+        let mut spans = vec![
             Span::styled(
                 "SPACE",
                 Style::default().fg(theme.primary()).add_modifier(Modifier::BOLD),
             ),
             Span::raw(" + "),
-            Span::styled("q", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": quit | "),
-            Span::styled("p", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": pause | "),
-            Span::styled("n", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": next | "),
-            Span::styled("b", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": prev | "),
-            Span::styled("v", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": visualizer | "),
-            Span::styled("c", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": clear log | "),
-            Span::styled("e", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": export"),
-        ]))
+        ];
+        spans.extend(prefix_hints(state.keymap, Prefix::Space, SPACE_HINTS));
+        Paragraph::new(Line::from(spans))
     } else if state.pending_key == Some('g') {
-        Paragraph::new(Line::from(vec![
+        let mut spans = vec![
             Span::styled(
                 "g",
                 Style::default().fg(theme.primary()).add_modifier(Modifier::BOLD),
             ),
             Span::raw(" + "),
-            Span::styled("g", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": top | "),
-            Span::styled("e", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": end"),
-        ]))
+        ];
+        spans.extend(prefix_hints(state.keymap, Prefix::G, G_HINTS));
+        Paragraph::new(Line::from(spans))
     } else {
-        Paragraph::new(Line::from(vec![
+        let mut spans = vec![
             Span::styled(
                 "NORMAL",
                 Style::default().fg(theme.success()).add_modifier(Modifier::BOLD),
             ),
             Span::raw(" | "),
-            Span::styled("hjkl", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": move | "),
-            Span::styled("+/-", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": vol | "),
-            Span::styled("</>", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": seek | "),
-            Span::styled("r", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": repeat | "),
-            Span::styled("s", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": shuffle | "),
-            Span::styled("Space", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": cmd | "),
-            Span::styled("?", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(": help"),
-            // Backend indicator (e.g., " [local]" or " [aspen]")
-            if let Some(ref name) = state.backend_name {
-                Span::styled(
-                    format!(" [{}]", name),
-                    Style::default().fg(theme.text_muted()),
-                )
-            } else {
-                Span::raw("")
-            },
-        ]))
+        ];
+        spans.extend(normal_hints(state.keymap));
+        // Backend indicator (e.g., " [local]" or " [aspen]")
+        if let Some(ref name) = state.backend_name {
+            spans.push(Span::styled(
+                format!(" [{}]", name),
+                Style::default().fg(theme.text_muted()),
+            ));
+        }
+        Paragraph::new(Line::from(spans))
     };
 
-    let status_bar = status_bar.block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded),
-    );
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded);
+    if let Some(ref header) = state.header {
+        block = block.title(Span::styled(
+            format!(" {} ", header),
+            Style::default().fg(theme.primary()).add_modifier(Modifier::BOLD),
+        ));
+    }
+    if !state.tasks.is_empty() {
+        let mut text = state.tasks.iter().take(MAX_TASKS_SHOWN).cloned().collect::<Vec<_>>().join(" · ");
+        if state.tasks.len() > MAX_TASKS_SHOWN {
+            text.push_str(&format!(" (+{} more)", state.tasks.len() - MAX_TASKS_SHOWN));
+        }
+        block = block.title_top(
+            Line::from(Span::styled(format!(" {} ", text), Style::default().fg(theme.text_muted())))
+                .right_aligned(),
+        );
+    }
+    if let Some(ref left) = state.sleep_timer {
+        block = block.title_bottom(
+            Line::from(Span::styled(format!(" sleep {} ", left), Style::default().fg(theme.warning())))
+                .right_aligned(),
+        );
+    }
+    let status_bar = status_bar.block(block);
     f.render_widget(status_bar, area);
 }
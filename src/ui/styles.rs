@@ -1,6 +1,8 @@
+use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::ListState;
 
-use crate::service::ServiceType;
+use crate::service::{ServiceType, Track};
 
 /// Indicator prefix for currently playing track
 pub const PLAYING_INDICATOR: &str = ">> ";
@@ -16,6 +18,45 @@ pub fn service_badge(service: ServiceType) -> &'static str {
     }
 }
 
+/// How lists keep the selection in view (`ui.scroll_style`, `ui.scrolloff`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ListScroll {
+    /// Keep the selection mid-panel
+    pub centered: bool,
+    /// Rows kept visible below the selection before the list scrolls
+    pub scrolloff: usize,
+}
+
+/// `ListState` selecting `selected` in a bordered list of `len` items drawn
+/// in `area`. Centered scrolling keeps the selection mid-panel; otherwise
+/// the list scrolls once the selection comes within `scrolloff` rows of the
+/// bottom, like Vim's `scrolloff`.
+pub fn list_state(selected: Option<usize>, len: usize, area: Rect, scroll: ListScroll) -> ListState {
+    let state = ListState::default().with_selected(selected);
+    let Some(selected) = selected else {
+        return state;
+    };
+    let rows = area.height.saturating_sub(2) as usize;
+    let last_page = len.saturating_sub(rows);
+    let offset = if scroll.centered {
+        selected.saturating_sub(rows / 2)
+    } else {
+        // Leave at least the selected row itself on screen
+        let margin = scroll.scrolloff.min(rows.saturating_sub(1) / 2);
+        (selected + margin + 1).saturating_sub(rows)
+    };
+    state.with_offset(offset.min(last_page))
+}
+
+/// Suffix marking tracks the service says can't be streamed
+pub fn availability_badge(track: &Track) -> &'static str {
+    if track.available {
+        ""
+    } else {
+        " [unavailable]"
+    }
+}
+
 /// Get the color associated with a service
 #[allow(dead_code)]
 pub fn service_color(service: ServiceType) -> Color {
@@ -54,3 +95,26 @@ pub fn format_track_with_indicator(display: String, is_playing: bool) -> String
         format!("{}{}", PLAYING_PADDING, display)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_state_offset() {
+        // 10 visible rows inside the borders
+        let area = Rect::new(0, 0, 40, 12);
+        let centered = ListScroll { centered: true, scrolloff: 0 };
+        assert_eq!(list_state(Some(3), 100, area, centered).offset(), 0);
+        assert_eq!(list_state(Some(50), 100, area, centered).offset(), 45);
+        // Doesn't scroll past the last page
+        assert_eq!(list_state(Some(98), 100, area, centered).offset(), 90);
+
+        // Edge scrolling puts the selection on the last row, or scrolloff above it
+        assert_eq!(list_state(Some(50), 100, area, ListScroll::default()).offset(), 41);
+        let scrolloff = ListScroll { centered: false, scrolloff: 3 };
+        assert_eq!(list_state(Some(5), 100, area, scrolloff).offset(), 0);
+        assert_eq!(list_state(Some(50), 100, area, scrolloff).offset(), 44);
+        assert_eq!(list_state(Some(99), 100, area, scrolloff).offset(), 90);
+    }
+}
@@ -37,6 +37,13 @@ pub struct Theme {
 
     /// Border color for normal elements
     pub border_normal: String,
+
+    /// Single accent that replaces primary and border_focused when set
+    pub accent: Option<String>,
+
+    /// Selected items, tabs and the focused panel; falls back to accent,
+    /// then warning
+    pub selection: Option<String>,
 }
 
 impl Default for Theme {
@@ -53,6 +60,8 @@ impl Default for Theme {
             text_disabled: "DarkGray".to_string(),
             border_focused: "Cyan".to_string(),
             border_normal: "DarkGray".to_string(),
+            accent: None,
+            selection: None,
         }
     }
 }
@@ -95,9 +104,48 @@ impl Theme {
         }
     }
 
+    /// Whether `parse_color` recognises a color string (anything else renders as Reset)
+    pub fn is_valid_color(color_str: &str) -> bool {
+        let s = color_str.trim();
+        s == "Reset"
+            || !matches!(Self::parse_color(s), Color::Reset)
+    }
+
+    /// Replace unrecognised colors with the default theme's, returning a
+    /// warning for each one.
+    pub fn validate(&mut self) -> Vec<String> {
+        let defaults = Theme::default();
+        let fields: [(&str, &mut String, String); 10] = [
+            ("primary", &mut self.primary, defaults.primary),
+            ("secondary", &mut self.secondary, defaults.secondary),
+            ("success", &mut self.success, defaults.success),
+            ("warning", &mut self.warning, defaults.warning),
+            ("error", &mut self.error, defaults.error),
+            ("text", &mut self.text, defaults.text),
+            ("text_muted", &mut self.text_muted, defaults.text_muted),
+            ("text_disabled", &mut self.text_disabled, defaults.text_disabled),
+            ("border_focused", &mut self.border_focused, defaults.border_focused),
+            ("border_normal", &mut self.border_normal, defaults.border_normal),
+        ];
+
+        let mut warnings = Vec::new();
+        for (name, value, default) in fields {
+            if !Self::is_valid_color(value) {
+                warnings.push(format!("unknown theme.{} color '{}', using '{}'", name, value, default));
+                *value = default;
+            }
+        }
+        for (name, value) in [("accent", &mut self.accent), ("selection", &mut self.selection)] {
+            if value.as_deref().is_some_and(|v| !Self::is_valid_color(v)) {
+                warnings.push(format!("unknown theme.{} color '{}', ignoring it", name, value.take().unwrap_or_default()));
+            }
+        }
+        warnings
+    }
+
     // Color accessors
     pub fn primary(&self) -> Color {
-        Self::parse_color(&self.primary)
+        Self::parse_color(self.accent.as_deref().unwrap_or(&self.primary))
     }
 
     pub fn secondary(&self) -> Color {
@@ -130,7 +178,7 @@ impl Theme {
 
     #[allow(dead_code)]
     pub fn border_focused(&self) -> Color {
-        Self::parse_color(&self.border_focused)
+        Self::parse_color(self.accent.as_deref().unwrap_or(&self.border_focused))
     }
 
     pub fn border_normal(&self) -> Color {
@@ -139,7 +187,13 @@ impl Theme {
 
     /// Border highlight color (alias for border_focused)
     pub fn border_highlight(&self) -> Color {
-        Self::parse_color(&self.border_focused)
+        self.border_focused()
+    }
+
+    /// Selected items, tabs and the focused panel
+    pub fn selection(&self) -> Color {
+        let color = self.selection.as_deref().or(self.accent.as_deref()).unwrap_or(&self.warning);
+        Self::parse_color(color)
     }
 
     /// Background color (dark terminals default to black)
@@ -154,7 +208,7 @@ impl Theme {
                 .fg(self.success())
                 .add_modifier(Modifier::BOLD),
             (true, false) => Style::default()
-                .fg(self.warning())
+                .fg(self.selection())
                 .add_modifier(Modifier::BOLD),
             (false, true) => Style::default().fg(self.success()),
             (false, false) => Style::default(),
@@ -163,7 +217,7 @@ impl Theme {
 
     pub fn highlight_style(&self) -> Style {
         Style::default()
-            .fg(self.warning())
+            .fg(self.selection())
             .add_modifier(Modifier::BOLD)
     }
 
@@ -191,6 +245,8 @@ impl Theme {
             text_disabled: "#6c7086".to_string(), // Overlay0
             border_focused: "#89b4fa".to_string(),
             border_normal: "#585b70".to_string(), // Surface2
+            accent: None,
+            selection: None,
         }
     }
 
@@ -208,6 +264,8 @@ impl Theme {
             text_disabled: "#44475a".to_string(), // Current Line
             border_focused: "#bd93f9".to_string(), // Purple
             border_normal: "#44475a".to_string(),
+            accent: None,
+            selection: None,
         }
     }
 
@@ -225,6 +283,8 @@ impl Theme {
             text_disabled: "#4c566a".to_string(), // Nord3
             border_focused: "#81a1c1".to_string(), // Nord9
             border_normal: "#3b4252".to_string(), // Nord1
+            accent: None,
+            selection: None,
         }
     }
 
@@ -242,6 +302,8 @@ impl Theme {
             text_disabled: "#665c54".to_string(), // bg2
             border_focused: "#fe8019".to_string(), // Orange
             border_normal: "#504945".to_string(), // bg1
+            accent: None,
+            selection: None,
         }
     }
 
@@ -259,6 +321,8 @@ impl Theme {
             text_disabled: "#414868".to_string(), // Terminal black
             border_focused: "#7dcfff".to_string(), // Cyan
             border_normal: "#3b4261".to_string(),
+            accent: None,
+            selection: None,
         }
     }
 
@@ -317,6 +381,42 @@ mod tests {
         assert!(matches!(Theme::parse_color("42"), Color::Indexed(42)));
     }
 
+    #[test]
+    fn test_validate_replaces_unknown_colors() {
+        let mut theme = Theme {
+            primary: "Cyna".to_string(),
+            error: "#ff00".to_string(),
+            ..Theme::default()
+        };
+        let warnings = theme.validate();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("theme.primary"));
+        assert_eq!(theme.primary, "Cyan");
+        assert_eq!(theme.error, "Red");
+
+        assert!(Theme::catppuccin_mocha().validate().is_empty());
+        assert!(Theme::is_valid_color("Reset"));
+        assert!(Theme::is_valid_color("42"));
+    }
+
+    #[test]
+    fn test_accent_and_selection_override() {
+        let mut theme = Theme::default();
+        assert!(matches!(theme.selection(), Color::Yellow));
+
+        theme.accent = Some("#ff8800".to_string());
+        assert!(matches!(theme.primary(), Color::Rgb(255, 136, 0)));
+        assert!(matches!(theme.border_focused(), Color::Rgb(255, 136, 0)));
+        assert!(matches!(theme.selection(), Color::Rgb(255, 136, 0)));
+
+        theme.selection = Some("Magenta".to_string());
+        assert!(matches!(theme.selection(), Color::Magenta));
+
+        theme.accent = Some("Orang".to_string());
+        assert_eq!(theme.validate().len(), 1);
+        assert!(theme.accent.is_none());
+    }
+
     #[test]
     fn test_theme_presets() {
         assert!(Theme::from_preset("catppuccin").is_some());
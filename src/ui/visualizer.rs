@@ -1,8 +1,8 @@
 use ratatui::{
-    layout::Alignment,
-    style::Style,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Paragraph},
+    widgets::{Bar, BarChart, BarGroup, Block, BorderType, Borders, Paragraph},
     Frame,
 };
 
@@ -14,11 +14,12 @@ pub fn render_visualizer(
     f: &mut Frame,
     visualizer: Option<&CavaVisualizer>,
     is_playing: bool,
+    on_pause: &str,
     area: ratatui::layout::Rect,
     theme: &Theme,
 ) {
     if let Some(viz) = visualizer {
-        let bars = viz.draw_bars();
+        let bars = viz.draw_bars(is_playing, on_pause);
 
         let lines = vec![
             Line::from(vec![
@@ -49,3 +50,41 @@ pub fn render_visualizer(
         f.render_widget(visualizer_widget, area);
     }
 }
+
+/// Idle screensaver: the visualizer stretched over the whole screen, with
+/// one line of now playing underneath
+pub fn render_screensaver(
+    f: &mut Frame,
+    heights: &[u8],
+    now_playing: &str,
+    area: Rect,
+    theme: &Theme,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+
+    // Spread the bars across the width, one column of gap between them
+    let count = heights.len().max(1) as u16;
+    let bar_width = (chunks[0].width.saturating_sub(count - 1) / count).max(1);
+    let bars: Vec<Bar> = heights
+        .iter()
+        .map(|&height| Bar::default().value(height as u64).text_value(String::new()))
+        .collect();
+    let chart = BarChart::default()
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .max(7)
+        .bar_style(Style::default().fg(theme.primary()));
+    f.render_widget(chart, chunks[0]);
+
+    let line = Paragraph::new(Line::from(Span::styled(
+        now_playing,
+        Style::default().fg(theme.text_muted()).add_modifier(Modifier::BOLD),
+    )))
+    .alignment(Alignment::Center);
+    f.render_widget(line, chunks[1]);
+}
@@ -13,9 +13,11 @@ fn create_test_track(id: &str, title: &str, artist: &str) -> Track {
         title: title.to_string(),
         artist: artist.to_string(),
         album: "Test Album".to_string(),
+        album_id: None,
         duration_seconds: 180,
         cover_art: CoverArt::tidal("cover-123".to_string()),
         service: ServiceType::Tidal,
+        available: true,
     }
 }
 
@@ -420,9 +422,11 @@ fn test_unicode_metadata() -> Result<()> {
         title: "日本語タイトル".to_string(),
         artist: "アーティスト".to_string(),
         album: "Альбом".to_string(),
+        album_id: None,
         duration_seconds: 180,
         cover_art: CoverArt::None,
         service: ServiceType::Tidal,
+        available: true,
     };
 
     db.queue_download(&track)?;
@@ -14,9 +14,11 @@ fn create_test_track(id: &str, title: &str, artist: &str, service: ServiceType)
         title: title.to_string(),
         artist: artist.to_string(),
         album: "Test Album".to_string(),
+        album_id: None,
         duration_seconds: 180,
         cover_art: CoverArt::tidal("cover-123".to_string()),
         service,
+        available: true,
     }
 }
 
@@ -174,9 +176,11 @@ fn test_unicode_metadata() -> Result<()> {
         title: "日本語タイトル".to_string(),
         artist: "アーティスト名".to_string(),
         album: "Альбом на русском".to_string(),
+        album_id: None,
         duration_seconds: 200,
         cover_art: CoverArt::None,
         service: ServiceType::Tidal,
+        available: true,
     };
 
     db.record_play(&track)?;
@@ -209,9 +213,11 @@ fn test_cover_art_preservation() -> Result<()> {
         title: "With Cover".to_string(),
         artist: "Artist".to_string(),
         album: "Album".to_string(),
+        album_id: None,
         duration_seconds: 180,
         cover_art: CoverArt::tidal("cover-abc".to_string()),
         service: ServiceType::Tidal,
+        available: true,
     };
 
     let track_without_cover = Track {
@@ -219,9 +225,11 @@ fn test_cover_art_preservation() -> Result<()> {
         title: "Without Cover".to_string(),
         artist: "Artist".to_string(),
         album: "Album".to_string(),
+        album_id: None,
         duration_seconds: 180,
         cover_art: CoverArt::None,
         service: ServiceType::YouTube,
+        available: true,
     };
 
     db.record_play(&track_with_cover)?;
@@ -303,9 +311,11 @@ fn test_special_characters_in_metadata() -> Result<()> {
         title: "Track with \"quotes\" and 'apostrophes'".to_string(),
         artist: "Artist with\nnewline".to_string(),
         album: "Album with\ttab".to_string(),
+        album_id: None,
         duration_seconds: 180,
         cover_art: CoverArt::None,
         service: ServiceType::Tidal,
+        available: true,
     };
 
     db.record_play(&track)?;
@@ -382,9 +392,11 @@ fn test_very_long_metadata() -> Result<()> {
         title: long_title.clone(),
         artist: "B".repeat(1000),
         album: "C".repeat(1000),
+        album_id: None,
         duration_seconds: 180,
         cover_art: CoverArt::None,
         service: ServiceType::Tidal,
+        available: true,
     };
 
     db.record_play(&track)?;
@@ -5,7 +5,7 @@
 //! use global config paths and are tested via unit tests instead.
 
 use anyhow::Result;
-use drift::service::{Album, Artist, CoverArt, SearchResults, ServiceType, Track};
+use drift::service::{Album, AlbumType, Artist, CoverArt, SearchResults, ServiceType, Track};
 use drift::storage::local::LocalStorage;
 use drift::storage::DriftStorage;
 use std::time::Duration;
@@ -16,9 +16,11 @@ fn create_test_track(id: &str, title: &str, artist: &str, service: ServiceType)
         title: title.to_string(),
         artist: artist.to_string(),
         album: "Test Album".to_string(),
+        album_id: None,
         duration_seconds: 180,
         cover_art: CoverArt::tidal("cover-123".to_string()),
         service,
+        available: true,
     }
 }
 
@@ -46,6 +48,7 @@ fn create_search_results(
             num_tracks: 10,
             cover_art: CoverArt::tidal("cover-123".to_string()),
             service: ServiceType::Tidal,
+            album_type: AlbumType::default(),
         })
         .collect();
 
@@ -176,9 +179,11 @@ async fn test_unicode_in_track_metadata() -> Result<()> {
         title: "日本語タイトル".to_string(),
         artist: "アーティスト名".to_string(),
         album: "Альбом на русском".to_string(),
+        album_id: None,
         duration_seconds: 200,
         cover_art: CoverArt::None,
         service: ServiceType::Tidal,
+        available: true,
     };
 
     storage.record_play(&track).await?;